@@ -2,7 +2,7 @@
  * 1024 Exchange Listing Program - Register Phase 1 Assets
  * 
  * This script registers the 12 Phase 1 assets using the full PLP flow:
- * 1. ProposeToken (instruction 10) - Submit token proposal
+ * 1. ProposeSyntheticToken (instruction 18) - Submit synthetic asset proposal
  * 2. ApproveToken (instruction 12) - Admin approves proposal
  * 3. ProposePerpMarket (instruction 30) - Submit perp market proposal
  * 4. ApprovePerpMarket (instruction 32) - Admin approves perp market
@@ -41,8 +41,11 @@ const LISTING_CONFIG_SEED = Buffer.from('listing_config');
 const LISTING_TREASURY_SEED = Buffer.from('listing_treasury');
 const TOKEN_PROPOSAL_SEED = Buffer.from('token_proposal');
 const TOKEN_REGISTRY_SEED = Buffer.from('token');  // b"token" in Rust
+const STAKE_PRICING_SEED = Buffer.from('stake_pricing');
+const SYMBOL_INDEX_SEED = Buffer.from('symbol_index');
 const PERP_PROPOSAL_SEED = Buffer.from('perp_proposal');
 const PERP_MARKET_SEED = Buffer.from('perp_market');
+const MARKET_TYPE_PERP = 1;
 
 // 1024Chain timing
 const MAX_RETRIES = 5;
@@ -56,8 +59,8 @@ const CONFIRMATION_TIMEOUT_MS = 45000;
 /**
  * Phase 1 Token Registry
  * 
- * Note: These are "virtual" tokens for price tracking only.
- * They don't have real SPL Token mints.
+ * Note: These are synthetic assets (AssetKind::Synthetic) for price tracking only.
+ * They don't have real SPL Token mints and can only be used as perp bases.
  */
 const PHASE1_TOKENS = [
     // === MAG7 Stocks ===
@@ -186,7 +189,7 @@ async function main() {
     // Step 1: Register Tokens via ProposeToken + ApproveToken
     // ========================================================================
     console.log('='.repeat(60));
-    console.log('Step 1: Register Tokens (ProposeToken + ApproveToken)');
+    console.log('Step 1: Register Tokens (ProposeSyntheticToken + ApproveToken)');
     console.log('='.repeat(60) + '\n');
 
     // First, check how many tokens are already registered
@@ -305,8 +308,8 @@ async function main() {
 async function registerTokenPLP(connection, admin, listingConfigPda, treasuryPda, tokenConfig, nonce) {
     console.log(`\nRegistering token: ${tokenConfig.symbol}...`);
 
-    // Step 1: ProposeToken (instruction 10)
-    console.log('  1. Submitting ProposeToken...');
+    // Step 1: ProposeSyntheticToken (instruction 18)
+    console.log('  1. Submitting ProposeSyntheticToken...');
     
     // Derive TokenProposal PDA
     const nonceBuffer = Buffer.alloc(8);
@@ -317,26 +320,34 @@ async function registerTokenPLP(connection, admin, listingConfigPda, treasuryPda
         LISTING_PROGRAM_ID
     );
 
+    // Synthetic assets have no mint; they track the 1024 push oracle for their Pyth feed
+    const oracle = derivePushOraclePda(tokenConfig.symbol);
+    const [stakePricingPda] = PublicKey.findProgramAddressSync(
+        [STAKE_PRICING_SEED],
+        LISTING_PROGRAM_ID
+    );
+    const [symbolIndexPda] = PublicKey.findProgramAddressSync(
+        [SYMBOL_INDEX_SEED, Buffer.from(tokenConfig.symbol)],
+        LISTING_PROGRAM_ID
+    );
+
     // Check if proposal exists
     const proposalInfo = await connection.getAccountInfo(proposalPda);
     let proposalExists = proposalInfo !== null;
 
     if (!proposalExists) {
-        // Build ProposeToken instruction
-        // Index 10 in enum = ProposeToken
-        const instructionData = buildProposeTokenData(nonce, tokenConfig);
+        // Build ProposeSyntheticToken instruction
+        const instructionData = buildProposeSyntheticTokenData(nonce, tokenConfig, oracle);
 
-        // Use a dummy mint for virtual tokens (system program address)
-        const dummyMint = new PublicKey('11111111111111111111111111111111');
-        
-        // ProposeToken accounts (from processor.rs):
+        // ProposeSyntheticToken accounts (from instruction.rs):
         // 0. proposer (signer, writable)
         // 1. proposal_account (writable)
-        // 2. config_account
-        // 3. treasury_account (writable)
-        // 4. token_mint
-        // 5. oracle_account (optional - we still need to pass something)
-        // 6. system_program
+        // 2. config_account (writable)
+        // 3. treasury_account
+        // 4. oracle_account
+        // 5. system_program
+        // 6. stake_pricing (writable)
+        // 7. symbol_index
 
         const proposeTx = new Transaction().add(
             new TransactionInstruction({
@@ -346,9 +357,10 @@ async function registerTokenPLP(connection, admin, listingConfigPda, treasuryPda
                     { pubkey: proposalPda, isSigner: false, isWritable: true },
                     { pubkey: listingConfigPda, isSigner: false, isWritable: true },  // Needs write for total_proposals counter
                     { pubkey: treasuryPda, isSigner: false, isWritable: true },
-                    { pubkey: dummyMint, isSigner: false, isWritable: false },  // token_mint
-                    { pubkey: dummyMint, isSigner: false, isWritable: false },  // oracle (optional, but need to pass)
+                    { pubkey: oracle, isSigner: false, isWritable: false },
                     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+                    { pubkey: stakePricingPda, isSigner: false, isWritable: true },
+                    { pubkey: symbolIndexPda, isSigner: false, isWritable: false },
                 ],
                 data: instructionData,
             })
@@ -356,10 +368,10 @@ async function registerTokenPLP(connection, admin, listingConfigPda, treasuryPda
 
         const proposeSig = await sendAndConfirmTransaction(connection, proposeTx, admin);
         if (!proposeSig) {
-            console.log('  ❌ ProposeToken failed');
+            console.log('  ❌ ProposeSyntheticToken failed');
             return null;
         }
-        console.log(`  ✅ ProposeToken: ${proposeSig.substring(0, 16)}...`);
+        console.log(`  ✅ ProposeSyntheticToken: ${proposeSig.substring(0, 16)}...`);
         proposalExists = true;
     } else {
         console.log('  ⏭️ Proposal already exists');
@@ -429,6 +441,8 @@ async function registerTokenPLP(connection, admin, listingConfigPda, treasuryPda
                 { pubkey: registryPda, isSigner: false, isWritable: true },
                 { pubkey: listingConfigPda, isSigner: false, isWritable: true },
                 { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+                // Synthetic assets have no MintIndex
+                { pubkey: symbolIndexPda, isSigner: false, isWritable: true },
             ],
             data: approveData,
         })
@@ -443,53 +457,19 @@ async function registerTokenPLP(connection, admin, listingConfigPda, treasuryPda
     return tokenIndex;
 }
 
-function buildProposeTokenData(nonce, tokenConfig) {
-    // ProposeToken (instruction 10) - Borsh enum variant index
-    // ProposeToken { nonce: u64, symbol: [u8; 8], mint: Pubkey, decimals: u8, oracle: Option<Pubkey> }
+function buildProposeSyntheticTokenData(nonce, tokenConfig, oracle) {
+    // ProposeSyntheticToken { nonce: u64, symbol: [u8; 8], decimals: u8, oracle: Pubkey }
     //
-    // Borsh enum serialization: 1 byte variant index + variant fields
-    // Looking at instruction.rs, the enum variants are:
-    //   0: Initialize
-    //   1: UpdateAdmin
-    //   2: UpdateStakeConfig
-    //   3: UpdateReviewPeriods
-    //   4: SetPaused
-    //   5: ProposeToken  <-- NOT 10! Borsh uses sequential index
-    //   ...
-    // Wait, let me re-check: the comments say [10], [11], etc. but those are documentation IDs.
-    // The actual Borsh variant index is the position in the enum definition.
-    //
-    // Actually: Initialize=0, UpdateAdmin=1, UpdateStakeConfig=2, UpdateReviewPeriods=3, SetPaused=4
-    //           ProposeToken=5, ObjectToken=6, ApproveToken=7, RejectToken=8, ...
-    // But the comments say [10] ProposeToken. Let me count the variants:
-    // 0-4: Admin (5 variants)
-    // 5-12: PLP-1 Token (8 variants: indices 5-12 in enum)
-    // Wait, let me just count properly...
-    
-    // From instruction.rs enum order:
-    // 0: Initialize
-    // 1: UpdateAdmin
-    // 2: UpdateStakeConfig
-    // 3: UpdateReviewPeriods
-    // 4: SetPaused
-    // 5: ProposeToken
-    // 6: ObjectToken
-    // 7: ApproveToken
-    // 8: RejectToken
-    // 9: CancelTokenProposal
-    // 10: FinalizeToken
-    // 11: ClaimTokenStake
-    // 12: UpdateTokenStatus
-    // 13: ProposeSpotMarket
-    // ... and so on
-    
-    const PROPOSE_TOKEN_INDEX = 5;  // Actual enum variant index
-    
-    const buffer = Buffer.alloc(1 + 8 + 8 + 32 + 1 + 1); // index + nonce + symbol + mint + decimals + option_tag
+    // Borsh enum serialization: 1 byte variant index + variant fields.
+    // The variant index is the position in the instruction.rs enum,
+    // not the [18] documentation id.
+    const PROPOSE_SYNTHETIC_TOKEN_INDEX = 74;
+
+    const buffer = Buffer.alloc(1 + 8 + 8 + 1 + 32); // index + nonce + symbol + decimals + oracle
     let offset = 0;
 
     // Instruction variant index
-    buffer.writeUInt8(PROPOSE_TOKEN_INDEX, offset); offset += 1;
+    buffer.writeUInt8(PROPOSE_SYNTHETIC_TOKEN_INDEX, offset); offset += 1;
 
     // nonce: u64
     buffer.writeBigUInt64LE(nonce, offset); offset += 8;
@@ -499,15 +479,11 @@ function buildProposeTokenData(nonce, tokenConfig) {
     symbolBuffer.write(tokenConfig.symbol);
     symbolBuffer.copy(buffer, offset); offset += 8;
 
-    // mint: Pubkey (use system program as dummy for virtual tokens)
-    const dummyMint = new PublicKey('11111111111111111111111111111111');
-    dummyMint.toBuffer().copy(buffer, offset); offset += 32;
-
     // decimals: u8
     buffer.writeUInt8(tokenConfig.decimals, offset); offset += 1;
 
-    // oracle: Option<Pubkey> = None
-    buffer.writeUInt8(0, offset); offset += 1;
+    // oracle: Pubkey
+    oracle.toBuffer().copy(buffer, offset); offset += 32;
 
     return buffer.slice(0, offset);
 }
@@ -616,16 +592,11 @@ async function listPerpMarketPLP(connection, admin, listingConfigPda, treasuryPd
             LISTING_PROGRAM_ID
         );
 
-        // For testing on 1024Chain, use a deterministic placeholder oracle address
-        // In production, this should be a real Pyth Oracle account
-        // We'll derive a PDA from the market symbol to ensure consistency
-        const [oracleAccount] = PublicKey.findProgramAddressSync(
-            [Buffer.from('test_oracle'), Buffer.from(marketSymbol)],
-            LISTING_PROGRAM_ID
-        );
-        
+        // 1024 push oracle for the base asset's Pyth feed
+        const oracleAccount = derivePushOraclePda(symbol);
+
         // Build ProposePerpMarket instruction
-        // Pass the oracle placeholder address as both the account and in instruction data
+        // Pass the oracle address as both the account and in instruction data
         const instructionData = buildProposePerpMarketData(nonce, marketSymbol, baseTokenIndex, quoteTokenIndex, config, symbol, oracleAccount);
 
         const proposeTx = new Transaction().add(
@@ -698,6 +669,18 @@ async function listPerpMarketPLP(connection, admin, listingConfigPda, treasuryPd
     // Build ApprovePerpMarket instruction (enum index 24, no data)
     const APPROVE_PERP_MARKET_INDEX = 24;
     const approveData = Buffer.from([APPROVE_PERP_MARKET_INDEX]);
+
+    // New pair: PairIndex is uninitialized, so no current market account follows it.
+    // The push oracle must hold a fresh price; it becomes the market's reference price.
+    // The base token registry supplies the oracle risk config (phase 1 tokens have no fallback oracle).
+    const pairIndexPda = derivePairIndexPda(MARKET_TYPE_PERP, baseTokenIndex, quoteTokenIndex);
+    const oracleAccount = derivePushOraclePda(symbol);
+    const baseIndexBuffer = Buffer.alloc(2);
+    baseIndexBuffer.writeUInt16LE(baseTokenIndex);
+    const [baseRegistryPda] = PublicKey.findProgramAddressSync(
+        [TOKEN_REGISTRY_SEED, baseIndexBuffer],
+        LISTING_PROGRAM_ID
+    );
     
     const approveTx = new Transaction().add(
         new TransactionInstruction({
@@ -708,6 +691,9 @@ async function listPerpMarketPLP(connection, admin, listingConfigPda, treasuryPd
                 { pubkey: marketPda, isSigner: false, isWritable: true },
                 { pubkey: listingConfigPda, isSigner: false, isWritable: true },
                 { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+                { pubkey: pairIndexPda, isSigner: false, isWritable: true },
+                { pubkey: oracleAccount, isSigner: false, isWritable: false },
+                { pubkey: baseRegistryPda, isSigner: false, isWritable: false },
             ],
             data: approveData,
         })
@@ -792,6 +778,34 @@ function buildProposePerpMarketData(nonce, marketSymbol, baseTokenIndex, quoteTo
     return buffer.slice(0, offset);
 }
 
+/**
+ * PushOracle PDA for a Phase 1 asset, keyed by its Pyth feed id.
+ * The oracle must be created (CreatePushOracle) and have a price pushed
+ * (UpdatePushOraclePrice) before proposing; empty accounts are rejected.
+ */
+function derivePushOraclePda(symbol) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('push_oracle'), Buffer.from(PYTH_FEED_IDS[symbol].slice(2), 'hex')],
+        LISTING_PROGRAM_ID
+    );
+    return pda;
+}
+
+/**
+ * PairIndex PDA: ["pair_index", market_type, base_token_index, quote_token_index]
+ */
+function derivePairIndexPda(marketType, baseTokenIndex, quoteTokenIndex) {
+    const baseIndexBuffer = Buffer.alloc(2);
+    baseIndexBuffer.writeUInt16LE(baseTokenIndex);
+    const quoteIndexBuffer = Buffer.alloc(2);
+    quoteIndexBuffer.writeUInt16LE(quoteTokenIndex);
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('pair_index'), Buffer.from([marketType]), baseIndexBuffer, quoteIndexBuffer],
+        LISTING_PROGRAM_ID
+    );
+    return pda;
+}
+
 function hexToBytes(hex) {
     const bytes = [];
     for (let i = 0; i < hex.length; i += 2) {
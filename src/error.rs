@@ -196,6 +196,134 @@ pub enum ListingError {
     /// Pool has remaining funds
     #[error("Pool has remaining funds - withdraw first")]
     PoolHasRemainingFunds,
+
+    /// Proposal not resolved yet
+    #[error("Proposal not resolved")]
+    ProposalNotResolved,
+
+    /// No pending admin transfer
+    #[error("No pending admin transfer")]
+    NoPendingAdmin,
+
+    /// Signer is not a council member
+    #[error("Not a council member")]
+    NotCouncilMember,
+
+    /// Invalid council members or threshold
+    #[error("Invalid council configuration")]
+    InvalidCouncilConfig,
+
+    /// Council approvals below threshold
+    #[error("Insufficient council approvals")]
+    InsufficientApprovals,
+
+    /// Pending action already executed or stale
+    #[error("Pending action not executable")]
+    PendingActionNotExecutable,
+
+    /// Risk parameter change on a live market must be scheduled
+    #[error("Risk parameter change must be scheduled")]
+    ParamChangeTimelocked,
+
+    /// A parameter change is already scheduled for this market
+    #[error("Parameter change already scheduled")]
+    ParamChangeAlreadyScheduled,
+
+    /// No parameter change scheduled
+    #[error("No parameter change scheduled")]
+    NoScheduledParamChange,
+
+    /// Timelock has not elapsed
+    #[error("Parameter change not yet effective")]
+    ParamChangeNotEffective,
+
+    /// Invalid pause scope bits
+    #[error("Invalid pause scope")]
+    InvalidPauseScope,
+
+    /// Proposal is not contested
+    #[error("Proposal not contested")]
+    ProposalNotContested,
+
+    /// Voting window is not open
+    #[error("Voting not open")]
+    VotingNotOpen,
+
+    /// Voting window has not ended
+    #[error("Voting not ended")]
+    VotingNotEnded,
+
+    /// Cannot change vote side
+    #[error("Vote side mismatch")]
+    VoteSideMismatch,
+
+    /// Proposal cannot be appealed
+    #[error("Appeal not allowed")]
+    AppealNotAllowed,
+
+    /// Appeal window is still open
+    #[error("Appeal window open")]
+    AppealWindowOpen,
+
+    /// Proposal is not under appeal
+    #[error("Proposal not appealed")]
+    ProposalNotAppealed,
+
+    /// Treasury balance does not cover outstanding stakes and rent
+    #[error("Treasury insolvent")]
+    TreasuryInsolvent,
+
+    /// Reason code is not defined in the slash schedule
+    #[error("Invalid reason code")]
+    InvalidReasonCode,
+
+    /// Slash schedule entries out of range
+    #[error("Invalid slash schedule")]
+    InvalidSlashSchedule,
+
+    /// Stake mint differs from the treasury stake token account mint
+    #[error("Invalid stake mint")]
+    InvalidStakeMint,
+
+    /// Stake pricing parameters out of range
+    #[error("Invalid stake pricing")]
+    InvalidStakePricing,
+
+    /// Account is not an initialized SPL Token mint
+    #[error("Invalid mint")]
+    InvalidMint,
+
+    /// Decimals argument does not match the mint
+    #[error("Decimals mismatch")]
+    DecimalsMismatch,
+
+    /// Mint uses a Token-2022 extension that cannot be listed
+    #[error("Unsupported mint extension")]
+    UnsupportedMintExtension,
+
+    /// Token must be approved by a Reviewer (no auto-approval)
+    #[error("Manual review required")]
+    ManualReviewRequired,
+
+    /// Mint or symbol is already used by a registered token
+    #[error("Duplicate token")]
+    DuplicateToken,
+
+    /// Synthetic assets can only be used as perp bases
+    #[error("Synthetic asset not allowed")]
+    SyntheticAssetNotAllowed,
+
+    /// Token name or metadata URI is malformed
+    #[error("Invalid token metadata")]
+    InvalidTokenMetadata,
+
+    /// Oracle staleness / confidence limits or fallback oracle are invalid
+    #[error("Invalid oracle config")]
+    InvalidOracleConfig,
+
+    /// Appeal was not resolved before the resolution deadline
+    #[error("Appeal expired")]
+    AppealExpired,
 }
 
 impl From<ListingError> for ProgramError {
@@ -8,10 +8,29 @@
 //! - **PLP-2**: Spot 市场上架指令
 //! - **PLP-3**: Perp 市场上架指令
 //! - **PLP-4**: 初始流动性池指令
+//! - **Council**: Admin 多签委员会指令
+//! - **Vote**: 争议提案社区投票指令
+//! - **Appeal**: 被拒绝提案申诉指令
+//!
+//! ## SPL 质押账户
+//! 提案 `stake_mode = Spl` 时，存入/退还/罚没质押的指令在固定账户之后追加：
+//! 1. `[]` Token Program
+//! 2. `[]` Stake Authority PDA (["stake_authority"])
+//! 3. `[writable]` Proposal Stake Escrow Token Account (["stake_escrow", proposal])
+//! 4. `[writable]` Treasury Stake Token Account (["treasury_stake"])
+//! 5. `[writable]` 存入方/接收方 Stake Token Account（仅罚没的指令不传）
+//!
+//! Propose* 额外追加 `[]` Stake Mint，托管 token 账户由 Proposer 支付租金创建。
+//! 原生模式提案不传这些账户。
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
+use crate::state::{
+    MarketType, ProposalType, Role, StakeMode, TokenCategory, TreasuryDestination,
+    MAX_TOKEN_NAME_LEN, MAX_TOKEN_URI_LEN,
+};
+
 /// Listing Program 指令
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub enum ListingInstruction {
@@ -37,7 +56,9 @@ pub enum ListingInstruction {
         ledger_program: Pubkey,
     },
 
-    /// [1] 更新 Admin
+    /// [1] 更新 Admin（已弃用，等同于 ProposeAdmin）
+    ///
+    /// 仅记录 pending_admin，新 Admin 需调用 AcceptAdmin 后生效。
     ///
     /// Accounts:
     /// 0. `[signer]` Current Admin
@@ -77,10 +98,12 @@ pub enum ListingInstruction {
         stake_lock_period: Option<u32>,
     },
 
-    /// [4] 暂停/恢复上架
+    /// [4] 全局暂停/恢复（覆盖所有暂停范围）
+    ///
+    /// Guardian 只能暂停；恢复需 Pauser 或 Admin。
     ///
     /// Accounts:
-    /// 0. `[signer]` Admin
+    /// 0. `[signer]` Pauser / Guardian (或 Admin)
     /// 1. `[writable]` ListingConfig PDA
     SetPaused {
         /// 是否暂停
@@ -93,16 +116,24 @@ pub enum ListingInstruction {
 
     /// [10] 提交 Token 注册申请
     ///
-    /// 使用原生 N1024 (lamports) 进行质押。
+    /// 使用原生 N1024 (lamports) 进行质押，质押托管在 TokenProposal 账户中。
+    /// Mint 的增发/冻结权限记录在提案账户尾部（见 `MintAuthorities`）。
+    /// Token-2022 Mint 的扩展记录为 `risk_flags`：不可转让直接拒绝，
+    /// 永久委托人/转账钩子/默认冻结只能由 Reviewer 批准。
+    /// Mint 或 Symbol 已被注册 Token 占用时失败（`DuplicateToken`）。
     ///
     /// Accounts:
     /// 0. `[signer, writable]` Proposer (payer, 扣除 N1024 质押)
-    /// 1. `[writable]` TokenProposal PDA
-    /// 2. `[]` ListingConfig PDA
-    /// 3. `[writable]` Treasury PDA (接收 N1024 质押)
-    /// 4. `[]` Token Mint (验证存在)
-    /// 5. `[]` Oracle (可选)
+    /// 1. `[writable]` TokenProposal PDA (托管 N1024 质押)
+    /// 2. `[writable]` ListingConfig PDA
+    /// 3. `[]` Treasury PDA
+    /// 4. `[]` Token Mint (SPL Token 或 Token-2022 Mint，校验 decimals)
+    /// 5. `[]` Oracle (可选，提供时验证并记录数据源)
     /// 6. `[]` System Program
+    /// 7. `[writable]` StakePricing PDA (动态质押定价)
+    /// 8. `[]` MintIndex PDA (须未初始化)
+    /// 9. `[]` SymbolIndex PDA (须未初始化)
+    /// 10. SPL 质押账户，见模块文档（仅 SPL 模式，含 Stake Mint）
     ProposeToken {
         /// 唯一序号
         nonce: u64,
@@ -118,13 +149,16 @@ pub enum ListingInstruction {
 
     /// [11] 反对 Token 注册
     ///
+    /// 反对质押记录在 Objection PDA 中，提案结束后通过 ClaimObjectionStake 取回。
+    ///
     /// Accounts:
     /// 0. `[signer, writable]` Objector (payer)
     /// 1. `[writable]` TokenProposal PDA
-    /// 2. `[]` ListingConfig PDA
-    /// 3. `[writable]` Objector Stake Token Account
-    /// 4. `[writable]` Treasury Stake Token Account
-    /// 5. `[]` Token Program
+    /// 2. `[writable]` Objection PDA (["objection", proposal, objector])
+    /// 3. `[writable]` ListingConfig PDA
+    /// 4. `[writable]` Treasury PDA (旧版提案接收 N1024 质押)
+    /// 5. `[]` System Program
+    /// 6. SPL 质押账户，见模块文档（仅 SPL 提案）
     ObjectToken {
         /// 反对质押金额
         stake_amount: u64,
@@ -133,46 +167,59 @@ pub enum ListingInstruction {
     /// [12] Admin 批准 Token 注册
     ///
     /// Accounts:
-    /// 0. `[signer]` Admin
+    /// 0. `[signer]` Reviewer (或 Admin)
     /// 1. `[writable]` TokenProposal PDA
     /// 2. `[writable]` TokenRegistry PDA
     /// 3. `[writable]` ListingConfig PDA
     /// 4. `[]` System Program
+    /// 5. `[signer, writable]` Payer（仅 Reviewer 为多签委员会 PDA 时）
+    /// 6. `[writable]` MintIndex PDA（Synthetic 资产不传，后续账户前移）
+    /// 7. `[writable]` SymbolIndex PDA
     ApproveToken,
 
     /// [13] Admin 拒绝 Token 注册
     ///
+    /// 剩余质押由 Proposer 通过 ClaimRejectedStake 取回。罚没资金在申诉结束前留在提案托管中，
+    /// 申诉被驳回或取回质押时才转入 Treasury 并计入 total_slashed_lamports。
+    /// Proposer 可在 appeal_window_seconds 内通过 FileAppeal 申诉。
+    ///
     /// Accounts:
-    /// 0. `[signer]` Admin
+    /// 0. `[signer]` Reviewer (或 Admin)
     /// 1. `[writable]` TokenProposal PDA
-    /// 2. `[]` ListingConfig PDA
-    /// 3. `[writable]` Treasury Stake Token Account (罚没)
+    /// 2. `[writable]` ListingConfig PDA
+    /// 3. `[writable]` Treasury PDA (接收罚没)
+    /// 4. `[]` SlashSchedule PDA
+    /// 5. SPL 质押账户，见模块文档（仅 SPL 提案，不含用户 token 账户）
     RejectToken {
-        /// 拒绝原因代码
+        /// 拒绝原因代码（罚没比例由 SlashSchedule 决定）
         reason_code: u8,
-        /// 罚没比例 (0-100)
-        slash_percentage: u8,
     },
 
     /// [14] Proposer 取消 Token 提案
     ///
+    /// 按 SlashSchedule.cancel_slash_bps 罚没，剩余质押立即退还。
+    ///
     /// Accounts:
-    /// 0. `[signer]` Proposer
+    /// 0. `[signer, writable]` Proposer
     /// 1. `[writable]` TokenProposal PDA
-    /// 2. `[]` ListingConfig PDA
-    /// 3. `[writable]` Proposer Stake Token Account
-    /// 4. `[writable]` Treasury Stake Token Account
-    /// 5. `[]` Token Program
+    /// 2. `[writable]` ListingConfig PDA
+    /// 3. `[writable]` Treasury PDA (接收罚没)
+    /// 4. `[]` SlashSchedule PDA
+    /// 5. SPL 质押账户，见模块文档（仅 SPL 提案）
     CancelTokenProposal,
 
     /// [15] 超时自动批准 Token
     ///
+    /// 需 Reviewer 批准的 Token-2022 提案（`TOKEN_RISK_REVIEW_REQUIRED`）不可自动批准。
+    ///
     /// Accounts:
     /// 0. `[signer, writable]` Caller (payer)
     /// 1. `[writable]` TokenProposal PDA
     /// 2. `[writable]` TokenRegistry PDA
     /// 3. `[writable]` ListingConfig PDA
     /// 4. `[]` System Program
+    /// 5. `[writable]` MintIndex PDA（Synthetic 资产不传，后续账户前移）
+    /// 6. `[writable]` SymbolIndex PDA
     FinalizeToken,
 
     /// [16] Proposer 取回 Token 质押
@@ -181,16 +228,15 @@ pub enum ListingInstruction {
     /// 0. `[signer]` Proposer
     /// 1. `[writable]` TokenProposal PDA
     /// 2. `[]` TokenRegistry PDA (验证已批准)
-    /// 3. `[]` ListingConfig PDA
-    /// 4. `[writable]` Proposer Stake Token Account
-    /// 5. `[writable]` Treasury Stake Token Account
-    /// 6. `[]` Token Program
+    /// 3. `[writable]` ListingConfig PDA
+    /// 4. `[writable]` Treasury PDA (旧版提案托管)
+    /// 5. SPL 质押账户，见模块文档（仅 SPL 提案）
     ClaimTokenStake,
 
     /// [17] Admin 更新 Token 状态
     ///
     /// Accounts:
-    /// 0. `[signer]` Admin
+    /// 0. `[signer]` Pauser (或 Admin)
     /// 1. `[writable]` TokenRegistry PDA
     /// 2. `[]` ListingConfig PDA
     UpdateTokenStatus {
@@ -204,16 +250,20 @@ pub enum ListingInstruction {
 
     /// [20] 提交 Spot 市场上架申请
     ///
+    /// 质押托管在 SpotMarketProposal 账户中。
+    ///
     /// Accounts:
     /// 0. `[signer, writable]` Proposer (payer)
-    /// 1. `[writable]` SpotMarketProposal PDA
-    /// 2. `[]` ListingConfig PDA
-    /// 3. `[]` Base TokenRegistry PDA
-    /// 4. `[]` Quote TokenRegistry PDA
-    /// 5. `[writable]` Proposer Stake Token Account
-    /// 6. `[writable]` Treasury Stake Token Account
-    /// 7. `[]` Token Program
-    /// 8. `[]` System Program
+    /// 1. `[writable]` SpotMarketProposal PDA (托管 N1024 质押)
+    /// 2. `[writable]` ListingConfig PDA
+    /// 3. `[]` Base TokenRegistry PDA（base_token_index）
+    /// 4. `[]` Quote TokenRegistry PDA（quote_token_index）
+    /// 5. `[]` Treasury PDA
+    /// 6. `[]` System Program
+    /// 7. `[writable]` StakePricing PDA (动态质押定价)
+    /// 8. `[]` PairIndex PDA
+    /// 9. `[]` 交易对当前市场（仅 PairIndex 已初始化时）
+    /// 10. SPL 质押账户，见模块文档（仅 SPL 模式，含 Stake Mint）
     ProposeSpotMarket {
         /// 唯一序号
         nonce: u64,
@@ -239,13 +289,16 @@ pub enum ListingInstruction {
 
     /// [21] 反对 Spot 市场上架
     ///
+    /// 反对质押记录在 Objection PDA 中，提案结束后通过 ClaimObjectionStake 取回。
+    ///
     /// Accounts:
-    /// 0. `[signer, writable]` Objector
+    /// 0. `[signer, writable]` Objector (payer)
     /// 1. `[writable]` SpotMarketProposal PDA
-    /// 2. `[]` ListingConfig PDA
-    /// 3. `[writable]` Objector Stake Token Account
-    /// 4. `[writable]` Treasury Stake Token Account
-    /// 5. `[]` Token Program
+    /// 2. `[writable]` Objection PDA (["objection", proposal, objector])
+    /// 3. `[writable]` ListingConfig PDA
+    /// 4. `[writable]` Treasury PDA (旧版提案接收 N1024 质押)
+    /// 5. `[]` System Program
+    /// 6. SPL 质押账户，见模块文档（仅 SPL 提案）
     ObjectSpotMarket {
         /// 反对质押金额
         stake_amount: u64,
@@ -254,35 +307,45 @@ pub enum ListingInstruction {
     /// [22] Admin 批准 Spot 市场
     ///
     /// Accounts:
-    /// 0. `[signer]` Admin
+    /// 0. `[signer]` Reviewer (或 Admin)
     /// 1. `[writable]` SpotMarketProposal PDA
     /// 2. `[writable]` SpotMarket PDA
     /// 3. `[writable]` ListingConfig PDA
     /// 4. `[]` System Program
+    /// 5. `[signer, writable]` Payer（仅 Reviewer 为多签委员会 PDA 时）
+    /// 6. `[writable]` PairIndex PDA
+    /// 7. `[writable]` 交易对当前市场（仅 PairIndex 已初始化时，替换时被停用）
     ApproveSpotMarket,
 
     /// [23] Admin 拒绝 Spot 市场
     ///
+    /// 剩余质押由 Proposer 通过 ClaimRejectedStake 取回。罚没资金在申诉结束前留在提案托管中，
+    /// 申诉被驳回或取回质押时才转入 Treasury 并计入 total_slashed_lamports。
+    /// Proposer 可在 appeal_window_seconds 内通过 FileAppeal 申诉。
+    ///
     /// Accounts:
-    /// 0. `[signer]` Admin
+    /// 0. `[signer]` Reviewer (或 Admin)
     /// 1. `[writable]` SpotMarketProposal PDA
-    /// 2. `[]` ListingConfig PDA
+    /// 2. `[writable]` ListingConfig PDA
+    /// 3. `[writable]` Treasury PDA (接收罚没)
+    /// 4. `[]` SlashSchedule PDA
+    /// 5. SPL 质押账户，见模块文档（仅 SPL 提案，不含用户 token 账户）
     RejectSpotMarket {
-        /// 拒绝原因代码
+        /// 拒绝原因代码（罚没比例由 SlashSchedule 决定）
         reason_code: u8,
-        /// 罚没比例 (0-100)
-        slash_percentage: u8,
     },
 
     /// [24] Proposer 取消 Spot 提案
     ///
+    /// 按 SlashSchedule.cancel_slash_bps 罚没，剩余质押立即退还。
+    ///
     /// Accounts:
-    /// 0. `[signer]` Proposer
+    /// 0. `[signer, writable]` Proposer
     /// 1. `[writable]` SpotMarketProposal PDA
-    /// 2. `[]` ListingConfig PDA
-    /// 3. `[writable]` Proposer Stake Token Account
-    /// 4. `[writable]` Treasury Stake Token Account
-    /// 5. `[]` Token Program
+    /// 2. `[writable]` ListingConfig PDA
+    /// 3. `[writable]` Treasury PDA (接收罚没)
+    /// 4. `[]` SlashSchedule PDA
+    /// 5. SPL 质押账户，见模块文档（仅 SPL 提案）
     CancelSpotMarketProposal,
 
     /// [25] 超时自动批准 Spot 市场
@@ -293,6 +356,8 @@ pub enum ListingInstruction {
     /// 2. `[writable]` SpotMarket PDA
     /// 3. `[writable]` ListingConfig PDA
     /// 4. `[]` System Program
+    /// 5. `[writable]` PairIndex PDA
+    /// 6. `[writable]` 交易对当前市场（仅 PairIndex 已初始化时，替换时被停用）
     FinalizeSpotMarket,
 
     /// [26] Proposer 取回 Spot 质押
@@ -301,16 +366,15 @@ pub enum ListingInstruction {
     /// 0. `[signer]` Proposer
     /// 1. `[writable]` SpotMarketProposal PDA
     /// 2. `[]` SpotMarket PDA
-    /// 3. `[]` ListingConfig PDA
-    /// 4. `[writable]` Proposer Stake Token Account
-    /// 5. `[writable]` Treasury Stake Token Account
-    /// 6. `[]` Token Program
+    /// 3. `[writable]` ListingConfig PDA
+    /// 4. `[writable]` Treasury PDA (旧版提案托管)
+    /// 5. SPL 质押账户，见模块文档（仅 SPL 提案）
     ClaimSpotMarketStake,
 
     /// [27] Admin 更新 Spot 市场状态
     ///
     /// Accounts:
-    /// 0. `[signer]` Admin
+    /// 0. `[signer]` Pauser (或 Admin)
     /// 1. `[writable]` SpotMarket PDA
     /// 2. `[]` ListingConfig PDA
     UpdateSpotMarketStatus {
@@ -323,7 +387,7 @@ pub enum ListingInstruction {
     /// [28] Admin 更新 Spot 市场参数
     ///
     /// Accounts:
-    /// 0. `[signer]` Admin
+    /// 0. `[signer]` RiskManager (或 Admin)
     /// 1. `[writable]` SpotMarket PDA
     /// 2. `[]` ListingConfig PDA
     UpdateSpotMarketParams {
@@ -343,17 +407,22 @@ pub enum ListingInstruction {
 
     /// [30] 提交 Perp 市场上架申请
     ///
+    /// 质押托管在 PerpMarketProposal 账户中。
+    /// Oracle 数据源按账户 owner 识别（Pyth / Pyth Pull / Switchboard / PushOracle）。
+    ///
     /// Accounts:
     /// 0. `[signer, writable]` Proposer (payer)
-    /// 1. `[writable]` PerpMarketProposal PDA
-    /// 2. `[]` ListingConfig PDA
-    /// 3. `[]` Base TokenRegistry PDA
-    /// 4. `[]` Quote TokenRegistry PDA
+    /// 1. `[writable]` PerpMarketProposal PDA (托管 N1024 质押)
+    /// 2. `[writable]` ListingConfig PDA
+    /// 3. `[]` Base TokenRegistry PDA（base_token_index）
+    /// 4. `[]` Quote TokenRegistry PDA（quote_token_index）
     /// 5. `[]` Oracle Account (验证)
-    /// 6. `[writable]` Proposer Stake Token Account
-    /// 7. `[writable]` Treasury Stake Token Account
-    /// 8. `[]` Token Program
-    /// 9. `[]` System Program
+    /// 6. `[]` Treasury PDA
+    /// 7. `[]` System Program
+    /// 8. `[writable]` StakePricing PDA (动态质押定价)
+    /// 9. `[]` PairIndex PDA
+    /// 10. `[]` 交易对当前市场（仅 PairIndex 已初始化时）
+    /// 11. SPL 质押账户，见模块文档（仅 SPL 模式，含 Stake Mint）
     ProposePerpMarket {
         /// 唯一序号
         nonce: u64,
@@ -391,13 +460,16 @@ pub enum ListingInstruction {
 
     /// [31] 反对 Perp 市场上架
     ///
+    /// 反对质押记录在 Objection PDA 中，提案结束后通过 ClaimObjectionStake 取回。
+    ///
     /// Accounts:
-    /// 0. `[signer, writable]` Objector
+    /// 0. `[signer, writable]` Objector (payer)
     /// 1. `[writable]` PerpMarketProposal PDA
-    /// 2. `[]` ListingConfig PDA
-    /// 3. `[writable]` Objector Stake Token Account
-    /// 4. `[writable]` Treasury Stake Token Account
-    /// 5. `[]` Token Program
+    /// 2. `[writable]` Objection PDA (["objection", proposal, objector])
+    /// 3. `[writable]` ListingConfig PDA
+    /// 4. `[writable]` Treasury PDA (旧版提案接收 N1024 质押)
+    /// 5. `[]` System Program
+    /// 6. SPL 质押账户，见模块文档（仅 SPL 提案）
     ObjectPerpMarket {
         /// 反对质押金额
         stake_amount: u64,
@@ -405,54 +477,69 @@ pub enum ListingInstruction {
 
     /// [32] Admin 批准 Perp 市场
     ///
+    /// 上线时按基础 Token 的 Oracle 风险配置验证价格（主 Oracle 失效时使用备用 Oracle），
+    /// 该价格记录为 `PerpMarket.reference_price`，PLP-4 永续池的价格区间须包含它。
+    ///
     /// Accounts:
-    /// 0. `[signer]` Admin
+    /// 0. `[signer]` Reviewer (或 Admin)
     /// 1. `[writable]` PerpMarketProposal PDA
     /// 2. `[writable]` PerpMarket PDA
     /// 3. `[writable]` ListingConfig PDA
-    /// 4. `[]` Fund Program (CPI 存入保险金)
-    /// 5. `[writable]` Proposer USDC Token Account
-    /// 6. `[writable]` Insurance Fund Token Account
-    /// 7. `[]` Token Program
-    /// 8. `[]` System Program
+    /// 4. `[]` System Program
+    /// 5. `[signer, writable]` Payer（仅 Reviewer 为多签委员会 PDA 时）
+    /// 6. `[writable]` PairIndex PDA
+    /// 7. `[writable]` 交易对当前市场（仅 PairIndex 已初始化时，替换时被停用）
+    /// 8. `[]` Oracle 账户（PerpMarketProposal.oracle）
+    /// 9. `[]` 基础 Token Registry PDA（base_token_index）
+    /// 10. `[]` 备用 Oracle（仅 Registry 配置了备用 Oracle 时）
     ApprovePerpMarket,
 
     /// [33] Admin 拒绝 Perp 市场
     ///
+    /// 剩余质押由 Proposer 通过 ClaimRejectedStake 取回。罚没资金在申诉结束前留在提案托管中，
+    /// 申诉被驳回或取回质押时才转入 Treasury 并计入 total_slashed_lamports。
+    /// Proposer 可在 appeal_window_seconds 内通过 FileAppeal 申诉。
+    ///
     /// Accounts:
-    /// 0. `[signer]` Admin
+    /// 0. `[signer]` Reviewer (或 Admin)
     /// 1. `[writable]` PerpMarketProposal PDA
-    /// 2. `[]` ListingConfig PDA
+    /// 2. `[writable]` ListingConfig PDA
+    /// 3. `[writable]` Treasury PDA (接收罚没)
+    /// 4. `[]` SlashSchedule PDA
+    /// 5. SPL 质押账户，见模块文档（仅 SPL 提案，不含用户 token 账户）
     RejectPerpMarket {
-        /// 拒绝原因代码
+        /// 拒绝原因代码（罚没比例由 SlashSchedule 决定）
         reason_code: u8,
-        /// 罚没比例 (0-100)
-        slash_percentage: u8,
     },
 
     /// [34] Proposer 取消 Perp 提案
     ///
+    /// 按 SlashSchedule.cancel_slash_bps 罚没，剩余质押立即退还。
+    ///
     /// Accounts:
-    /// 0. `[signer]` Proposer
+    /// 0. `[signer, writable]` Proposer
     /// 1. `[writable]` PerpMarketProposal PDA
-    /// 2. `[]` ListingConfig PDA
-    /// 3. `[writable]` Proposer Stake Token Account
-    /// 4. `[writable]` Treasury Stake Token Account
-    /// 5. `[]` Token Program
+    /// 2. `[writable]` ListingConfig PDA
+    /// 3. `[writable]` Treasury PDA (接收罚没)
+    /// 4. `[]` SlashSchedule PDA
+    /// 5. SPL 质押账户，见模块文档（仅 SPL 提案）
     CancelPerpMarketProposal,
 
     /// [35] 超时自动批准 Perp 市场
     ///
+    /// Oracle 价格要求同 ApprovePerpMarket。
+    ///
     /// Accounts:
     /// 0. `[signer, writable]` Caller
     /// 1. `[writable]` PerpMarketProposal PDA
     /// 2. `[writable]` PerpMarket PDA
     /// 3. `[writable]` ListingConfig PDA
-    /// 4. `[]` Fund Program (CPI)
-    /// 5. `[writable]` Proposer USDC Token Account
-    /// 6. `[writable]` Insurance Fund Token Account
-    /// 7. `[]` Token Program
-    /// 8. `[]` System Program
+    /// 4. `[]` System Program
+    /// 5. `[writable]` PairIndex PDA
+    /// 6. `[writable]` 交易对当前市场（仅 PairIndex 已初始化时，替换时被停用）
+    /// 7. `[]` Oracle 账户（PerpMarketProposal.oracle）
+    /// 8. `[]` 基础 Token Registry PDA（base_token_index）
+    /// 9. `[]` 备用 Oracle（仅 Registry 配置了备用 Oracle 时）
     FinalizePerpMarket,
 
     /// [36] Proposer 取回 Perp 质押
@@ -461,16 +548,15 @@ pub enum ListingInstruction {
     /// 0. `[signer]` Proposer
     /// 1. `[writable]` PerpMarketProposal PDA
     /// 2. `[]` PerpMarket PDA
-    /// 3. `[]` ListingConfig PDA
-    /// 4. `[writable]` Proposer Stake Token Account
-    /// 5. `[writable]` Treasury Stake Token Account
-    /// 6. `[]` Token Program
+    /// 3. `[writable]` ListingConfig PDA
+    /// 4. `[writable]` Treasury PDA (旧版提案托管)
+    /// 5. SPL 质押账户，见模块文档（仅 SPL 提案）
     ClaimPerpMarketStake,
 
     /// [37] Admin 更新 Perp 市场状态
     ///
     /// Accounts:
-    /// 0. `[signer]` Admin
+    /// 0. `[signer]` Pauser (或 Admin)
     /// 1. `[writable]` PerpMarket PDA
     /// 2. `[]` ListingConfig PDA
     UpdatePerpMarketStatus {
@@ -482,8 +568,11 @@ pub enum ListingInstruction {
 
     /// [38] Admin 更新 Perp 市场参数
     ///
+    /// 已上线市场（含被停用的）的杠杆、保证金率和 OI 上限上调须通过 SchedulePerpParamChange
+    /// 时间锁变更；下调 OI 上限和费率可立即生效。
+    ///
     /// Accounts:
-    /// 0. `[signer]` Admin
+    /// 0. `[signer]` RiskManager (或 Admin)
     /// 1. `[writable]` PerpMarket PDA
     /// 2. `[]` ListingConfig PDA
     UpdatePerpMarketParams {
@@ -508,6 +597,7 @@ pub enum ListingInstruction {
     /// [40] 初始化 PLP-4 流动性池
     ///
     /// 市场批准后由 Admin 或 Proposer 调用
+    /// 永续市场的价格区间须包含上线时记录的 Oracle 参考价
     ///
     /// Accounts:
     /// 0. `[signer, writable]` Initializer (payer)
@@ -549,7 +639,7 @@ pub enum ListingInstruction {
     /// [42] 调整流动性池参数
     ///
     /// Accounts:
-    /// 0. `[signer]` Admin
+    /// 0. `[signer]` Pool Creator 或 PoolOperator (或 Admin)
     /// 1. `[writable]` LiquidityPool PDA
     /// 2. `[]` ListingConfig PDA
     AdjustLiquidityPoolParams {
@@ -566,16 +656,17 @@ pub enum ListingInstruction {
     /// [43] 刷新流动性池订单 (由 Relayer 调用)
     ///
     /// Accounts:
-    /// 0. `[signer]` Relayer
+    /// 0. `[signer]` Pool Creator 或 PoolOperator (或 Admin)
     /// 1. `[]` LiquidityPool PDA
     /// 2. `[]` Market PDA
     /// 3. `[]` ListingConfig PDA
+    /// 4. `[]` Oracle
     RefreshLiquidityPoolOrders,
 
     /// [44] 提取流动性池收益
     ///
     /// Accounts:
-    /// 0. `[signer]` Admin
+    /// 0. `[signer]` Pool Creator 或 PoolOperator (或 Admin)
     /// 1. `[writable]` LiquidityPool PDA
     /// 2. `[]` ListingConfig PDA
     /// 3. `[writable]` Pool Base Token Account
@@ -593,7 +684,7 @@ pub enum ListingInstruction {
     /// [45] 退休流动性池
     ///
     /// Accounts:
-    /// 0. `[signer]` Admin
+    /// 0. `[signer]` Pool Creator 或 PoolOperator (或 Admin)
     /// 1. `[writable]` LiquidityPool PDA
     /// 2. `[]` ListingConfig PDA
     RetireLiquidityPool,
@@ -619,5 +710,709 @@ pub enum ListingInstruction {
         /// 市场索引
         market_index: u16,
     },
+
+    // =========================================================================
+    // 质押结算指令 (60-69)
+    // =========================================================================
+
+    /// [60] 反对者取回反对质押
+    ///
+    /// - 提案被拒绝: 全额退还 + 按比例分得罚没奖励（申诉窗口关闭后）
+    /// - 提案被取消: 全额退还
+    /// - 提案被批准: 扣除 objection_forfeit_bps 后退还
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Objector
+    /// 1. `[writable]` Objection PDA
+    /// 2. `[writable]` Proposal PDA (Token/Spot/Perp)
+    /// 3. `[writable]` ListingConfig PDA
+    /// 4. `[writable]` Treasury PDA
+    /// 5. SPL 质押账户，见模块文档（仅 SPL 提案）
+    ClaimObjectionStake,
+
+    /// [62] Proposer 取回被拒绝提案的剩余质押 (stake_amount - slash_amount)
+    ///
+    /// 留在提案托管中的罚没同时转入 Treasury。申诉窗口内须 Proposer 签名（视为放弃申诉）；
+    /// 窗口结束后任何人可触发，剩余质押仍退还给 Proposer。
+    /// 申诉逾期未裁决（见 ResolveAppeal）时维持拒绝，申诉保证金随剩余质押一并退还。
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Proposer（申诉窗口结束后可不签名）
+    /// 1. `[writable]` Proposal PDA (Token/Spot/Perp)
+    /// 2. `[writable]` ListingConfig PDA
+    /// 3. `[writable]` Treasury PDA
+    /// 4. SPL 质押账户，见模块文档（仅 SPL 提案）
+    ClaimRejectedStake {
+        /// 提案类型
+        proposal_type: ProposalType,
+    },
+
+    /// [63] Admin 提取 Treasury 盈余（罚没/没收资金）
+    ///
+    /// 只能提取 treasury 余额中超出 total_staked_lamports + 租金豁免余额的部分。
+    ///
+    /// Accounts:
+    /// 0. `[signer]` TreasuryManager (或 Admin)
+    /// 1. `[]` ListingConfig PDA
+    /// 2. `[writable]` Treasury PDA
+    /// 3. `[writable]` Destination (insurance_vault 或 fee_recipient)
+    WithdrawTreasury {
+        /// 提取金额 (lamports)
+        amount: u64,
+        /// 提取目标
+        destination: TreasuryDestination,
+    },
+
+    /// [64] Admin 设置罚没资金接收地址
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Admin
+    /// 1. `[writable]` ListingConfig PDA
+    SetFeeRecipient {
+        /// 接收地址
+        fee_recipient: Pubkey,
+    },
+
+    // =========================================================================
+    // Admin 权限管理指令 (70-79)
+    // =========================================================================
+
+    /// [70] 发起 Admin 转移（两步转移第一步）
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Current Admin
+    /// 1. `[writable]` ListingConfig PDA
+    ProposeAdmin {
+        /// 新管理员
+        new_admin: Pubkey,
+    },
+
+    /// [71] 新 Admin 接受转移
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Pending Admin
+    /// 1. `[writable]` ListingConfig PDA
+    AcceptAdmin,
+
+    /// [72] 取消待处理的 Admin 转移
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Current Admin
+    /// 1. `[writable]` ListingConfig PDA
+    CancelAdminTransfer,
+
+    /// [73] 设置角色授权地址
+    ///
+    /// Pubkey::default() 表示撤销该角色。Admin 始终拥有全部角色权限。
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Admin
+    /// 1. `[writable]` ListingConfig PDA
+    SetRole {
+        /// 角色
+        role: Role,
+        /// 授权地址
+        authority: Pubkey,
+    },
+
+    /// [61] 更新反对质押结算配置
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Admin
+    /// 1. `[writable]` ListingConfig PDA
+    UpdateObjectionConfig {
+        /// 提案被批准时反对者被没收的比例 (bps)
+        objection_forfeit_bps: Option<u16>,
+        /// 提案被拒绝时罚没金额分给反对者的比例 (bps)
+        objector_reward_bps: Option<u16>,
+    },
+
+    // =========================================================================
+    // 多签委员会指令 (80-89)
+    // =========================================================================
+    //
+    // 启用委员会后 ListingConfig.admin = AdminCouncil PDA。所有 Admin 指令的
+    // Admin 账户改为传入委员会 PDA（不签名），并以下任一方式授权：
+    // - 在指令账户末尾附加至少 threshold 个成员签名账户
+    // - 通过 CreatePendingAction / ApprovePendingAction / ExecutePendingAction
+    //   收集批准后，由委员会 PDA 签名执行
+
+    /// [80] 创建 Admin 多签委员会，并将 Admin 转移给委员会 PDA
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Admin (payer)
+    /// 1. `[writable]` ListingConfig PDA
+    /// 2. `[writable]` AdminCouncil PDA
+    /// 3. `[]` System Program
+    InitializeAdminCouncil {
+        /// 成员列表
+        members: Vec<Pubkey>,
+        /// 授权所需签名数
+        threshold: u8,
+    },
+
+    /// [81] 更新委员会成员和阈值（需委员会授权，旧的 PendingAction 失效）
+    ///
+    /// Accounts:
+    /// 0. `[writable]` AdminCouncil PDA
+    /// 1. `[]` ListingConfig PDA
+    UpdateAdminCouncil {
+        /// 成员列表
+        members: Vec<Pubkey>,
+        /// 授权所需签名数
+        threshold: u8,
+    },
+
+    /// [82] 成员发起待执行操作（发起者自动批准）
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Council Member (payer)
+    /// 1. `[writable]` AdminCouncil PDA
+    /// 2. `[writable]` PendingAction PDA
+    /// 3. `[]` System Program
+    CreatePendingAction {
+        /// 目标指令数据（Borsh 序列化的 ListingInstruction）
+        instruction_data: Vec<u8>,
+        /// 目标指令账户列表
+        account_keys: Vec<Pubkey>,
+    },
+
+    /// [83] 成员批准待执行操作
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Council Member
+    /// 1. `[]` AdminCouncil PDA
+    /// 2. `[writable]` PendingAction PDA
+    ApprovePendingAction,
+
+    /// [84] 执行已达到阈值的操作（委员会 PDA 签名调用本程序）
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Council Member
+    /// 1. `[]` AdminCouncil PDA
+    /// 2. `[writable]` PendingAction PDA
+    /// 3. `[..]` 目标指令账户（3 起，顺序与 CreatePendingAction 的 account_keys 一致）
+    ExecutePendingAction {
+        /// 目标指令数据（哈希须与 PendingAction 一致）
+        instruction_data: Vec<u8>,
+    },
+
+    // =========================================================================
+    // Perp 参数时间锁指令 (90-99)
+    // =========================================================================
+
+    /// [90] 计划 Perp 风险参数变更（param_change_delay_seconds 后生效）
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` RiskManager (或 Admin, payer)
+    /// 1. `[]` PerpMarket PDA
+    /// 2. `[]` ListingConfig PDA
+    /// 3. `[writable]` ScheduledParamChange PDA
+    /// 4. `[]` System Program
+    /// 5. `[signer, writable]` Payer（仅 RiskManager 为多签委员会 PDA 时）
+    SchedulePerpParamChange {
+        /// 最大杠杆
+        max_leverage: Option<u8>,
+        /// 初始保证金率 (e6)
+        initial_margin_rate_e6: Option<u32>,
+        /// 维持保证金率 (e6)
+        maintenance_margin_rate_e6: Option<u32>,
+        /// 最大持仓量 (e6)
+        max_open_interest_e6: Option<u64>,
+    },
+
+    /// [91] 执行已到生效时间的参数变更（任何人可调用）
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Caller
+    /// 1. `[writable]` PerpMarket PDA
+    /// 2. `[writable]` ScheduledParamChange PDA
+    ExecuteParamChange,
+
+    /// [92] 取消待生效的参数变更
+    ///
+    /// Accounts:
+    /// 0. `[signer]` RiskManager (或 Admin)
+    /// 1. `[]` ListingConfig PDA
+    /// 2. `[writable]` ScheduledParamChange PDA
+    CancelParamChange,
+
+    /// [93] Admin 设置参数变更时间锁
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Admin
+    /// 1. `[writable]` ListingConfig PDA
+    SetParamChangeDelay {
+        /// 时间锁（秒）
+        delay_seconds: u32,
+    },
+
+    /// [74] 设置紧急暂停 Guardian（Pubkey::default() 表示撤销）
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Admin
+    /// 1. `[writable]` ListingConfig PDA
+    SetGuardian {
+        /// Guardian 地址
+        guardian: Pubkey,
+    },
+
+    /// [75] 按范围暂停/恢复 (PAUSE_* 位)
+    ///
+    /// Guardian 只能暂停；恢复需 Pauser 或 Admin。
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Pauser / Guardian (或 Admin)
+    /// 1. `[writable]` ListingConfig PDA
+    SetPauseScope {
+        /// 暂停范围位 (PAUSE_*)
+        scopes: u8,
+        /// 是否暂停
+        paused: bool,
+    },
+
+    // =========================================================================
+    // 社区投票指令 (100-109)
+    // =========================================================================
+
+    /// [100] 对争议提案投票（锁定原生 N1024）
+    ///
+    /// 审核期结束后、voting_deadline 之前可投票；同一投票者可追加但不能改变立场。
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Voter
+    /// 1. `[writable]` Proposal PDA (Token/Spot/Perp)
+    /// 2. `[writable]` Vote PDA
+    /// 3. `[writable]` ListingConfig PDA
+    /// 4. `[writable]` Treasury PDA
+    /// 5. `[]` System Program
+    /// 6. SPL 质押账户，见模块文档（仅 SPL 提案）
+    CastVote {
+        /// 提案类型
+        proposal_type: ProposalType,
+        /// true = 支持上架, false = 反对上架
+        support: bool,
+        /// 锁定质押金额 (lamports)
+        amount: u64,
+    },
+
+    /// [101] 投票期结束后按票数批准或拒绝争议提案（任何人可调用）
+    ///
+    /// 支持票多于反对票则批准（创建 Registry/Market），否则按
+    /// SlashSchedule.vote_rejection_slash_bps 罚没后拒绝。
+    /// 需 Reviewer 批准的 Token-2022 提案不能经投票上架，保持待审核。
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Caller (payer)
+    /// 1. `[writable]` Proposal PDA (Token/Spot/Perp)
+    /// 2. `[writable]` TokenRegistry / SpotMarket / PerpMarket PDA
+    /// 3. `[writable]` ListingConfig PDA
+    /// 4. `[]` System Program
+    /// 5. `[writable]` Treasury PDA (接收罚没)
+    /// 6. `[]` SlashSchedule PDA
+    /// 7. `[writable]` MintIndex PDA（仅 Token 提案，Synthetic 资产不传）；Spot/Perp 提案为 PairIndex PDA
+    /// 8. `[writable]` SymbolIndex PDA（仅 Token 提案）；Spot/Perp 提案为交易对当前市场（仅 PairIndex 已初始化时）
+    /// 9. `[]` Oracle 账户（仅 Perp 提案投票批准时，后接基础 Token Registry PDA 和可选备用 Oracle，要求同 ApprovePerpMarket）
+    /// 10. SPL 质押账户，见模块文档（仅 SPL 提案且投票否决时，不含用户 token 账户）
+    ResolveProposal {
+        /// 提案类型
+        proposal_type: ProposalType,
+    },
+
+    /// [102] 提案结束后投票者取回投票质押
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Voter
+    /// 1. `[writable]` Vote PDA
+    /// 2. `[writable]` Proposal PDA (Token/Spot/Perp)
+    /// 3. `[writable]` ListingConfig PDA
+    /// 4. `[writable]` Treasury PDA
+    /// 5. SPL 质押账户，见模块文档（仅 SPL 提案）
+    ClaimVoteStake,
+
+    /// [103] Admin 设置争议提案投票期
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Admin
+    /// 1. `[writable]` ListingConfig PDA
+    SetVotingPeriod {
+        /// 投票期（秒）
+        voting_period_seconds: u32,
+    },
+
+    // =========================================================================
+    // 申诉指令 (110-119)
+    // =========================================================================
+
+    /// [110] Proposer 对 Reject* 拒绝决定提出申诉
+    ///
+    /// 须在 rejected_at + appeal_window_seconds 之前、取回被拒绝质押之前提交，
+    /// 锁定 stake_amount * appeal_bond_percentage% 的原生 N1024 作为保证金。
+    /// 每个提案只能申诉一次，提案进入 Appealed 状态。
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Proposer
+    /// 1. `[writable]` Proposal PDA (Token/Spot/Perp)
+    /// 2. `[writable]` ListingConfig PDA
+    /// 3. `[writable]` Treasury PDA
+    /// 4. `[]` System Program
+    /// 5. SPL 质押账户，见模块文档（仅 SPL 提案）
+    FileAppeal {
+        /// 提案类型
+        proposal_type: ProposalType,
+    },
+
+    /// [111] Admin（或多签委员会）裁决申诉
+    ///
+    /// - 维持 (overturn = false): 恢复 Rejected，保证金计入罚没
+    /// - 推翻 (overturn = true): 撤销罚没并批准上架，保证金退还 Proposer
+    ///
+    /// 须在申诉窗口结束后 `APPEAL_RESOLUTION_PERIOD`（14 天）内裁决，逾期后由
+    /// ClaimRejectedStake 结算。
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Admin
+    /// 1. `[writable]` Proposal PDA (Token/Spot/Perp)
+    /// 2. `[writable]` TokenRegistry / SpotMarket / PerpMarket PDA（仅推翻时创建）
+    /// 3. `[writable]` ListingConfig PDA
+    /// 4. `[writable]` Treasury PDA
+    /// 5. `[writable]` Proposer（接收退还的保证金）
+    /// 6. `[]` System Program
+    /// 7. `[signer, writable]` Payer（仅 Admin 为多签委员会 PDA 时）
+    /// 8. `[writable]` MintIndex PDA（仅 Token 提案，Synthetic 资产不传）；Spot/Perp 提案为 PairIndex PDA
+    /// 9. `[writable]` SymbolIndex PDA（仅 Token 提案）；Spot/Perp 提案为交易对当前市场（仅 PairIndex 已初始化时）
+    /// 10. `[]` Oracle 账户（仅 Perp 提案推翻时，后接基础 Token Registry PDA 和可选备用 Oracle，要求同 ApprovePerpMarket）
+    /// 11. SPL 质押账户，见模块文档（仅 SPL 提案，维持拒绝时不含用户 token 账户）
+    ResolveAppeal {
+        /// 提案类型
+        proposal_type: ProposalType,
+        /// 是否推翻拒绝决定
+        overturn: bool,
+    },
+
+    /// [112] Admin 更新申诉配置
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Admin
+    /// 1. `[writable]` ListingConfig PDA
+    UpdateAppealConfig {
+        /// 申诉窗口（秒）
+        appeal_window_seconds: Option<u32>,
+        /// 申诉保证金比例 (0-100)
+        appeal_bond_percentage: Option<u8>,
+    },
+
+    /// [65] 审计 Treasury 偿付能力（任何人可调用）
+    ///
+    /// 比较 Treasury 余额与 total_staked_lamports + 租金豁免最低余额，
+    /// 通过 return data 返回 borsh 序列化的 TreasuryAudit。资不抵债时不失败，仅报告差额。
+    ///
+    /// Accounts:
+    /// 0. `[]` ListingConfig PDA
+    /// 1. `[]` Treasury PDA
+    AuditTreasury,
+
+    /// [66] Admin 更新罚没比例表（首次调用时创建 SlashSchedule PDA）
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Admin (payer)
+    /// 1. `[]` ListingConfig PDA
+    /// 2. `[writable]` SlashSchedule PDA
+    /// 3. `[]` System Program
+    /// 4. `[signer, writable]` Payer（仅 Admin 为多签委员会 PDA 时）
+    UpdateSlashSchedule {
+        /// Proposer 自行取消的罚没比例 (bps)
+        cancel_slash_bps: u16,
+        /// 社区投票否决的罚没比例 (bps)
+        vote_rejection_slash_bps: u16,
+        /// Reject* 罚没比例 (bps)，索引为 reason_code
+        reason_slash_bps: Vec<u16>,
+    },
+
+    /// [67] Admin 切换新提案的质押资产（原生 N1024 / SPL 质押代币）
+    ///
+    /// 切换到 SPL 时首次创建 Treasury 质押 token 账户，其 mint 即质押 mint，
+    /// 之后不可更换。已有提案保持创建时的质押资产。
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Admin (payer)
+    /// 1. `[writable]` ListingConfig PDA
+    ///
+    /// 仅 stake_mode = Spl:
+    /// 2. `[]` Stake Mint
+    /// 3. `[writable]` Treasury Stake Token Account PDA
+    /// 4. `[]` Stake Authority PDA
+    /// 5. `[]` Token Program
+    /// 6. `[]` System Program
+    /// 7. `[signer, writable]` Payer（仅 Admin 为多签委员会 PDA 时）
+    SetStakeMode {
+        /// 新提案的质押资产
+        stake_mode: StakeMode,
+    },
+
+    /// [68] Admin 更新动态质押定价（首次调用时创建 StakePricing PDA）
+    ///
+    /// 启用后新提案质押 = 基础质押 × (1 + 加价)，加价随待审提案数增长、随时间线性衰减。
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Admin (payer)
+    /// 1. `[]` ListingConfig PDA
+    /// 2. `[writable]` StakePricing PDA
+    /// 3. `[]` System Program
+    /// 4. `[signer, writable]` Payer（仅 Admin 为多签委员会 PDA 时）
+    UpdateStakePricing {
+        /// 是否启用动态定价
+        enabled: bool,
+        /// 不加价的待审提案数
+        free_pending: u16,
+        /// 每个超出的待审提案加价 (bps)
+        per_pending_bps: u16,
+        /// 质押倍数上限 (bps，>= 10000)
+        max_multiplier_bps: u32,
+        /// 加价线性衰减到 0 的时间（秒，> 0）
+        decay_seconds: u32,
+    },
+
+    /// [69] 为唯一性索引上线前注册的 Token 补建 MintIndex / SymbolIndex（任何人可调用）
+    ///
+    /// 已存在的索引跳过。
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Payer
+    /// 1. `[]` TokenRegistry PDA
+    /// 2. `[writable]` MintIndex PDA（Synthetic 资产不传，后续账户前移）
+    /// 3. `[writable]` SymbolIndex PDA
+    /// 4. `[]` System Program
+    IndexRegisteredToken,
+
+    /// [120] Admin 允许（或撤销）新提案替换交易对当前仍激活的市场
+    ///
+    /// 允许后该交易对可再次提交 Propose*；替换提案批准时停用旧市场，
+    /// PairIndex 指向新市场并清除该标记。
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Admin
+    /// 1. `[]` ListingConfig PDA
+    /// 2. `[writable]` PairIndex PDA
+    AllowMarketReplacement {
+        /// 市场类型
+        market_type: MarketType,
+        /// Base Token 索引
+        base_token_index: u16,
+        /// Quote Token 索引
+        quote_token_index: u16,
+        /// 是否允许替换
+        allowed: bool,
+    },
+
+    /// [121] 为交易对索引上线前上架的市场补建 PairIndex（任何人可调用）
+    ///
+    /// 已存在的索引跳过。
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Payer
+    /// 1. `[]` SpotMarket / PerpMarket PDA
+    /// 2. `[writable]` PairIndex PDA
+    /// 3. `[]` System Program
+    IndexListedMarket {
+        /// 市场类型
+        market_type: MarketType,
+    },
+
+    /// [18] 提交 Synthetic 资产注册申请（股票、指数、贵金属等，无链上 Mint）
+    ///
+    /// 跳过 Mint 校验，Oracle 必须有效；注册后只能作为 Perp 市场的 Base。
+    /// 其余流程（质押、审核、批准）与 ProposeToken 相同。
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Proposer (payer, 扣除 N1024 质押)
+    /// 1. `[writable]` TokenProposal PDA (托管 N1024 质押)
+    /// 2. `[writable]` ListingConfig PDA
+    /// 3. `[]` Treasury PDA
+    /// 4. `[]` Oracle Account (验证)
+    /// 5. `[]` System Program
+    /// 6. `[writable]` StakePricing PDA (动态质押定价)
+    /// 7. `[]` SymbolIndex PDA (须未初始化)
+    /// 8. SPL 质押账户，见模块文档（仅 SPL 模式，含 Stake Mint）
+    ProposeSyntheticToken {
+        /// 唯一序号
+        nonce: u64,
+        /// Symbol (2-8 字符)
+        symbol: [u8; 8],
+        /// 价格精度
+        decimals: u8,
+        /// Oracle 地址
+        oracle: Pubkey,
+    },
+
+    /// [130] 提案者为 Token 提案设置描述性元数据（仅 Pending 提案，只能设置一次）
+    ///
+    /// 之后的修改需通过 ScheduleTokenMetadataUpdate 时间锁。
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Proposer (payer)
+    /// 1. `[]` TokenProposal PDA
+    /// 2. `[writable]` TokenMetadata PDA (须未初始化)
+    /// 3. `[]` System Program
+    SetTokenMetadata {
+        /// 名称 (UTF-8，尾部 0 填充)
+        name: [u8; MAX_TOKEN_NAME_LEN],
+        /// 元数据 URI (UTF-8，尾部 0 填充，可为空)
+        uri: [u8; MAX_TOKEN_URI_LEN],
+        /// 分类
+        category: TokenCategory,
+        /// URI 指向内容的哈希，全 0 表示未提供
+        metadata_hash: [u8; 32],
+    },
+
+    /// [131] Admin 或提案者发起 Token 元数据修改（时间锁）
+    ///
+    /// 经过 `param_change_delay_seconds` 后任何人可执行；
+    /// 同一元数据同时只允许一个待生效的修改。
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Admin 或 Proposer
+    /// 1. `[]` ListingConfig PDA
+    /// 2. `[writable]` TokenMetadata PDA
+    ScheduleTokenMetadataUpdate {
+        /// 新名称
+        name: [u8; MAX_TOKEN_NAME_LEN],
+        /// 新元数据 URI
+        uri: [u8; MAX_TOKEN_URI_LEN],
+        /// 新分类
+        category: TokenCategory,
+        /// 新元数据哈希
+        metadata_hash: [u8; 32],
+    },
+
+    /// [132] 执行已过时间锁的 Token 元数据修改（任何人可调用）
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Caller
+    /// 1. `[writable]` TokenMetadata PDA
+    ExecuteTokenMetadataUpdate,
+
+    /// [133] Admin 或提案者取消待生效的 Token 元数据修改
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Admin 或 Proposer
+    /// 1. `[]` ListingConfig PDA
+    /// 2. `[writable]` TokenMetadata PDA
+    CancelTokenMetadataUpdate,
+
+    /// [140] Admin 创建 1024 推送预言机 (OracleSource::Push1024)
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Admin (payer)
+    /// 1. `[]` ListingConfig PDA
+    /// 2. `[writable]` PushOracle PDA (须未初始化)
+    /// 3. `[]` System Program
+    /// 4. `[signer, writable]` Payer（仅 Admin 为多签委员会 PDA 时）
+    CreatePushOracle {
+        /// 价格源 ID
+        feed_id: [u8; 32],
+        /// 价格指数
+        expo: i32,
+        /// 价格推送者
+        publisher: Pubkey,
+    },
+
+    /// [141] Publisher 推送价格（发布时间取当前时间戳）
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Publisher
+    /// 1. `[writable]` PushOracle PDA
+    UpdatePushOraclePrice {
+        /// 价格 (price x 10^expo)
+        price: i64,
+        /// 置信区间
+        conf: u64,
+    },
+
+    /// [142] Admin 更换推送预言机的 Publisher
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Admin
+    /// 1. `[]` ListingConfig PDA
+    /// 2. `[writable]` PushOracle PDA
+    SetPushOraclePublisher {
+        /// 新价格推送者
+        publisher: Pubkey,
+    },
+
+    /// [143] RiskManager 设置永续市场的 Oracle 风险配置
+    ///
+    /// Accounts:
+    /// 0. `[signer]` RiskManager
+    /// 1. `[writable]` PerpMarket PDA
+    /// 2. `[]` ListingConfig PDA
+    /// 3. `[]` 备用 Oracle 账户（仅 fallback_oracle 为 Some 时）
+    UpdatePerpOracleConfig {
+        /// 最大陈旧时间（秒，0 = 默认 60 秒）
+        max_staleness_seconds: u32,
+        /// 最大置信区间占价格比例（bps，0 = 默认 500）
+        max_confidence_bps: u16,
+        /// 备用 Oracle（None = 清除）
+        fallback_oracle: Option<Pubkey>,
+    },
+
+    /// [144] RiskManager 设置 Token 的 Oracle 风险配置（旧 Registry 账户自动扩容）
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` RiskManager (payer)
+    /// 1. `[writable]` TokenRegistry PDA
+    /// 2. `[]` ListingConfig PDA
+    /// 3. `[]` System Program
+    /// 4. `[]` 备用 Oracle 账户（仅 fallback_oracle 为 Some 时）
+    /// 5. `[signer, writable]` Payer（仅 RiskManager 为多签委员会 PDA 时）
+    UpdateTokenOracleConfig {
+        /// 最大陈旧时间（秒，0 = 默认 60 秒）
+        max_staleness_seconds: u32,
+        /// 最大置信区间占价格比例（bps，0 = 默认 500）
+        max_confidence_bps: u16,
+        /// 备用 Oracle（None = 清除）
+        fallback_oracle: Option<Pubkey>,
+    },
+
+    /// [5] 将旧版 ListingConfig 扩容到当前布局，新增字段写入默认值
+    ///
+    /// 程序升级后须先执行此指令，旧版配置才能被写回。
+    ///
+    /// Accounts:
+    /// 0. `[signer, writable]` Admin (payer，补足扩容租金)
+    /// 1. `[writable]` ListingConfig PDA
+    /// 2. `[]` System Program
+    MigrateListingConfig,
+
+    /// [76] TreasuryManager 提取 Treasury 质押 token 账户中的 SPL 罚没
+    ///
+    /// SPL 提案的质押托管在各自的 Stake Escrow 中，Treasury 质押 token 账户余额均可提取。
+    ///
+    /// Accounts:
+    /// 0. `[signer]` TreasuryManager (或 Admin)
+    /// 1. `[]` ListingConfig PDA
+    /// 2. `[writable]` Treasury 质押 token 账户 PDA
+    /// 3. `[]` Stake Authority PDA
+    /// 4. `[]` Token Program
+    /// 5. `[writable]` Destination token 账户（owner 为接收方）
+    /// 6. `[]` 接收方（insurance_vault 或 fee_recipient）
+    WithdrawTreasuryToken {
+        /// 提取数量（质押代币最小单位）
+        amount: u64,
+        /// 提取目标
+        destination: TreasuryDestination,
+    },
+
+    /// [77] Admin 设置 Treasury 提取到保险金的接收账户
+    ///
+    /// 接收账户 owner 须为 fund_program，在提取时校验。
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Admin
+    /// 1. `[writable]` ListingConfig PDA
+    SetInsuranceVault {
+        /// 保险金接收账户（Pubkey::default() = 清除）
+        insurance_vault: Pubkey,
+    },
 }
 
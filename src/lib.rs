@@ -24,6 +24,7 @@ use solana_program::{
 
 pub mod error;
 pub mod instruction;
+pub mod oracle;
 pub mod processor;
 pub mod state;
 pub mod utils;
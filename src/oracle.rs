@@ -0,0 +1,806 @@
+//! Oracle 适配器
+//!
+//! 数据源 (`OracleSource`) 按 Oracle 账户 owner 识别，各适配器把自己的账户格式
+//! 归一化为同一个 `OraclePrice { price, conf, expo, publish_time }` 读数：
+//! - `PythV2`: Pyth 推送价格账户
+//! - `PythPull`: Pyth Solana Receiver `PriceUpdateV2`（须完全验证，且为 Pyth Push Oracle
+//!   维护的喂价账户）
+//! - `Switchboard`: Switchboard On-Demand `PullFeedAccountData`
+//! - `Push1024`: 本程序托管的 `PushOracle` PDA
+//! - `Mock`: 本地测试用 `MockOracle`，仅 `test-oracle` 构建接受
+//!
+//! 提案和 Registry 记录 Oracle 的喂价 ID (`OracleFeed`)，每次读取都与账户中的 feed_id 比对。
+
+use borsh::BorshDeserialize;
+use pyth_sdk_solana::state::SolanaPriceAccount;
+use solana_program::{
+    account_info::AccountInfo,
+    msg,
+    program_error::ProgramError,
+    pubkey,
+    pubkey::Pubkey,
+};
+
+use crate::error::ListingError;
+use crate::state::{OracleFeed, OracleRiskConfig, OracleSource, PushOracle};
+#[cfg(feature = "test-oracle")]
+use crate::state::MockOracle;
+
+// =============================================================================
+// 常量
+// =============================================================================
+
+/// Pyth Program ID on Mainnet
+pub const PYTH_MAINNET_PROGRAM_ID: Pubkey = pubkey!("FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH");
+/// Pyth Program ID on Devnet
+pub const PYTH_DEVNET_PROGRAM_ID: Pubkey = pubkey!("gSbePebfvPy7tRqimPoVecS2UsBvYv46ynrzWocc92s");
+/// Pyth Solana Receiver Program ID (Mainnet / Devnet)
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+/// Pyth Push Oracle Program ID，喂价账户 = PDA [shard_id (u16 LE), feed_id]
+pub const PYTH_PUSH_ORACLE_PROGRAM_ID: Pubkey = pubkey!("pythWSnswVUd12oZpeFP8e9CVaEqJg25g1Vtc2biRsT");
+/// 接受的 Pyth Push Oracle shard
+pub const PYTH_PUSH_ORACLE_SHARD_ID: u16 = 0;
+/// Switchboard On-Demand Program ID on Mainnet
+pub const SWITCHBOARD_MAINNET_PROGRAM_ID: Pubkey = pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+/// Switchboard On-Demand Program ID on Devnet
+pub const SWITCHBOARD_DEVNET_PROGRAM_ID: Pubkey = pubkey!("Aio4gaXjXzJNVLtzwtNVmSqGKpANtXhybbkhtAC94ji2");
+
+/// Oracle 验证默认值（`OracleRiskConfig` 字段为 0 时使用）
+pub const DEFAULT_ORACLE_MAX_CONFIDENCE_BPS: u16 = 500; // 5% 最大置信区间比率
+pub const DEFAULT_ORACLE_MAX_STALENESS_SECONDS: u32 = 60; // 60秒最大陈旧时间
+/// 可配置的最大陈旧时间上限（7 天，覆盖休市资产）
+pub const MAX_ORACLE_STALENESS_SECONDS: u32 = 7 * 24 * 3600;
+
+/// `PriceUpdateV2` 账户 discriminator (sha256("account:PriceUpdateV2")[..8])
+const PYTH_PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+/// `PullFeedAccountData` 账户 discriminator
+const SWITCHBOARD_PULL_FEED_DISCRIMINATOR: [u8; 8] = [196, 27, 108, 196, 10, 215, 219, 40];
+
+/// `PullFeedAccountData` 字段偏移（含 8 字节 discriminator，repr(C) 布局）
+const SB_RESULT_VALUE_OFFSET: usize = 8 + 2256;
+const SB_RESULT_STD_DEV_OFFSET: usize = SB_RESULT_VALUE_OFFSET + 16;
+const SB_RESULT_SUBMISSION_IDX_OFFSET: usize = SB_RESULT_VALUE_OFFSET + 97;
+const SB_RESULT_SLOT_OFFSET: usize = SB_RESULT_VALUE_OFFSET + 104;
+const SB_SUBMISSION_TIMESTAMPS_OFFSET: usize = 8 + 2944;
+const SB_MAX_SUBMISSIONS: usize = 32;
+const SB_PULL_FEED_ACCOUNT_SIZE: usize = 8 + 3200;
+/// Switchboard 结果固定 18 位小数
+const SB_PRECISION_EXPO: i32 = -18;
+
+// =============================================================================
+// 适配器
+// =============================================================================
+
+/// 归一化的 Oracle 价格读数 (price x 10^expo)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OraclePrice {
+    /// 价格
+    pub price: i64,
+    /// 置信区间
+    pub conf: u64,
+    /// 价格指数
+    pub expo: i32,
+    /// 发布时间戳
+    pub publish_time: i64,
+    /// 喂价 ID（Pyth Pull / Push1024，其他数据源为全 0）
+    pub feed_id: [u8; 32],
+}
+
+/// Oracle 数据源适配器
+pub trait OracleAdapter {
+    /// 对应的数据源
+    const SOURCE: OracleSource;
+
+    /// 账户 owner 是否属于该数据源
+    fn is_owner(owner: &Pubkey, program_id: &Pubkey) -> bool;
+
+    /// 解析账户并返回归一化读数（不检查新鲜度和置信区间）
+    fn read_price(account: &AccountInfo) -> Result<OraclePrice, ProgramError>;
+}
+
+/// Pyth 推送价格账户
+pub struct PythV2Adapter;
+
+impl OracleAdapter for PythV2Adapter {
+    const SOURCE: OracleSource = OracleSource::PythV2;
+
+    fn is_owner(owner: &Pubkey, _program_id: &Pubkey) -> bool {
+        owner == &PYTH_MAINNET_PROGRAM_ID || owner == &PYTH_DEVNET_PROGRAM_ID
+    }
+
+    fn read_price(account: &AccountInfo) -> Result<OraclePrice, ProgramError> {
+        let feed = SolanaPriceAccount::account_info_to_feed(account).map_err(|e| {
+            msg!("Failed to parse Pyth price account: {:?}", e);
+            ListingError::InvalidOracle
+        })?;
+        let p = feed.get_price_unchecked();
+        Ok(OraclePrice {
+            price: p.price,
+            conf: p.conf,
+            expo: p.expo,
+            publish_time: p.publish_time,
+            feed_id: [0u8; 32],
+        })
+    }
+}
+
+/// Pyth `PriceUpdateV2` 中的价格消息
+#[derive(BorshDeserialize)]
+struct PythPriceFeedMessage {
+    feed_id: [u8; 32],
+    price: i64,
+    conf: u64,
+    exponent: i32,
+    publish_time: i64,
+    _prev_publish_time: i64,
+    _ema_price: i64,
+    _ema_conf: u64,
+}
+
+/// Pyth Solana Receiver `PriceUpdateV2` 账户
+pub struct PythPullAdapter;
+
+impl OracleAdapter for PythPullAdapter {
+    const SOURCE: OracleSource = OracleSource::PythPull;
+
+    fn is_owner(owner: &Pubkey, _program_id: &Pubkey) -> bool {
+        owner == &PYTH_RECEIVER_PROGRAM_ID
+    }
+
+    fn read_price(account: &AccountInfo) -> Result<OraclePrice, ProgramError> {
+        let data = account.data.borrow();
+        if data.len() < 8 || data[..8] != PYTH_PRICE_UPDATE_V2_DISCRIMINATOR {
+            msg!("Not a Pyth PriceUpdateV2 account");
+            return Err(ListingError::InvalidOracle.into());
+        }
+
+        // write_authority (32) + verification_level (Partial = 0 + u8, Full = 1)
+        let write_authority = Pubkey::new_from_array(le_bytes(&data, 8)?);
+        let verification_level = data.get(40).copied();
+        if verification_level != Some(1) {
+            msg!("Pyth price update is not fully verified");
+            return Err(ListingError::InvalidOracle.into());
+        }
+
+        let message = PythPriceFeedMessage::deserialize(&mut &data[41..])
+            .map_err(|_| ListingError::InvalidOracle)?;
+
+        // 只接受 Pyth Push Oracle 维护的喂价账户：由账户自身（PDA）写入，地址由 feed_id 派生，
+        // 任意 write_authority 创建的 PriceUpdateV2 可被改写为其他喂价
+        let (expected, _) = Pubkey::find_program_address(
+            &[&PYTH_PUSH_ORACLE_SHARD_ID.to_le_bytes(), &message.feed_id],
+            &PYTH_PUSH_ORACLE_PROGRAM_ID,
+        );
+        if account.key != &expected || write_authority != expected {
+            msg!("Pyth price update is not a push oracle feed account");
+            msg!("Write authority: {}, expected: {}", write_authority, expected);
+            return Err(ListingError::InvalidOracle.into());
+        }
+
+        Ok(OraclePrice {
+            price: message.price,
+            conf: message.conf,
+            expo: message.exponent,
+            publish_time: message.publish_time,
+            feed_id: message.feed_id,
+        })
+    }
+}
+
+/// Switchboard On-Demand `PullFeedAccountData`
+pub struct SwitchboardAdapter;
+
+impl OracleAdapter for SwitchboardAdapter {
+    const SOURCE: OracleSource = OracleSource::Switchboard;
+
+    fn is_owner(owner: &Pubkey, _program_id: &Pubkey) -> bool {
+        owner == &SWITCHBOARD_MAINNET_PROGRAM_ID || owner == &SWITCHBOARD_DEVNET_PROGRAM_ID
+    }
+
+    fn read_price(account: &AccountInfo) -> Result<OraclePrice, ProgramError> {
+        let data = account.data.borrow();
+        if data.len() < SB_PULL_FEED_ACCOUNT_SIZE || data[..8] != SWITCHBOARD_PULL_FEED_DISCRIMINATOR {
+            msg!("Not a Switchboard pull feed account");
+            return Err(ListingError::InvalidOracle.into());
+        }
+
+        // slot = 0 表示尚无结果
+        let slot = u64::from_le_bytes(le_bytes(&data, SB_RESULT_SLOT_OFFSET)?);
+        let submission_idx = data[SB_RESULT_SUBMISSION_IDX_OFFSET] as usize;
+        if slot == 0 || submission_idx >= SB_MAX_SUBMISSIONS {
+            msg!("Switchboard feed has no result");
+            return Err(ListingError::InvalidOracle.into());
+        }
+
+        let value = i128::from_le_bytes(le_bytes(&data, SB_RESULT_VALUE_OFFSET)?);
+        let std_dev = i128::from_le_bytes(le_bytes(&data, SB_RESULT_STD_DEV_OFFSET)?);
+        let publish_time = i64::from_le_bytes(le_bytes(
+            &data,
+            SB_SUBMISSION_TIMESTAMPS_OFFSET + submission_idx * 8,
+        )?);
+
+        let (price, conf, expo) = normalize_decimal(value, std_dev.unsigned_abs(), SB_PRECISION_EXPO);
+        Ok(OraclePrice { price, conf, expo, publish_time, feed_id: [0u8; 32] })
+    }
+}
+
+/// 本程序托管的 1024 推送预言机
+pub struct Push1024Adapter;
+
+impl OracleAdapter for Push1024Adapter {
+    const SOURCE: OracleSource = OracleSource::Push1024;
+
+    fn is_owner(owner: &Pubkey, program_id: &Pubkey) -> bool {
+        owner == program_id
+    }
+
+    fn read_price(account: &AccountInfo) -> Result<OraclePrice, ProgramError> {
+        let oracle = PushOracle::try_from_slice(&account.data.borrow())
+            .map_err(|_| ListingError::InvalidOracle)?;
+        if oracle.discriminator != PushOracle::DISCRIMINATOR {
+            msg!("Not a push oracle account");
+            return Err(ListingError::InvalidOracle.into());
+        }
+        if oracle.publish_time == 0 {
+            msg!("Push oracle has no price yet");
+            return Err(ListingError::InvalidOracle.into());
+        }
+        Ok(OraclePrice {
+            price: oracle.price,
+            conf: oracle.conf,
+            expo: oracle.expo,
+            publish_time: oracle.publish_time,
+            feed_id: oracle.feed_id,
+        })
+    }
+}
+
+/// 本地测试用 Mock Oracle（owner 不限，按 discriminator 识别）
+#[cfg(feature = "test-oracle")]
+pub struct MockAdapter;
+
+#[cfg(feature = "test-oracle")]
+impl MockAdapter {
+    /// 账户数据是否为 MockOracle
+    fn is_mock(account: &AccountInfo) -> bool {
+        account.data.borrow().get(..8) == Some(&MockOracle::DISCRIMINATOR.to_le_bytes()[..])
+    }
+}
+
+#[cfg(feature = "test-oracle")]
+impl OracleAdapter for MockAdapter {
+    const SOURCE: OracleSource = OracleSource::Mock;
+
+    fn is_owner(_owner: &Pubkey, _program_id: &Pubkey) -> bool {
+        true
+    }
+
+    fn read_price(account: &AccountInfo) -> Result<OraclePrice, ProgramError> {
+        let oracle = MockOracle::try_from_slice(&account.data.borrow())
+            .map_err(|_| ListingError::InvalidOracle)?;
+        if oracle.discriminator != MockOracle::DISCRIMINATOR {
+            msg!("Not a mock oracle account");
+            return Err(ListingError::InvalidOracle.into());
+        }
+        Ok(OraclePrice {
+            price: oracle.price,
+            conf: oracle.conf,
+            expo: oracle.expo,
+            publish_time: oracle.publish_time,
+            feed_id: [0u8; 32],
+        })
+    }
+}
+
+/// 读取定长小端字节
+fn le_bytes<const N: usize>(data: &[u8], offset: usize) -> Result<[u8; N], ProgramError> {
+    data.get(offset..offset + N)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| ListingError::InvalidOracle.into())
+}
+
+/// 将 i128 定点数降低精度，直到价格和置信区间可以用 i64 / u64 表示
+fn normalize_decimal(mut value: i128, mut conf: u128, mut expo: i32) -> (i64, u64, i32) {
+    loop {
+        if let (Ok(price), Ok(conf)) = (i64::try_from(value), u64::try_from(conf)) {
+            return (price, conf, expo);
+        }
+        value /= 10;
+        conf /= 10;
+        expo += 1;
+    }
+}
+
+// =============================================================================
+// 验证
+// =============================================================================
+
+fn read_with<A: OracleAdapter>(
+    oracle_account: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<OraclePrice, ProgramError> {
+    if !A::is_owner(oracle_account.owner, program_id) {
+        msg!("Oracle account owner does not match source {:?}", A::SOURCE);
+        msg!("Got: {}", oracle_account.owner);
+        return Err(ListingError::InvalidOracle.into());
+    }
+    A::read_price(oracle_account)
+}
+
+/// 按账户 owner 识别 Oracle 数据源
+pub fn detect_oracle_source(
+    oracle_account: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<OracleSource, ProgramError> {
+    #[cfg(feature = "test-oracle")]
+    if MockAdapter::is_mock(oracle_account) {
+        return Ok(OracleSource::Mock);
+    }
+
+    let owner = oracle_account.owner;
+    if PythV2Adapter::is_owner(owner, program_id) {
+        Ok(OracleSource::PythV2)
+    } else if PythPullAdapter::is_owner(owner, program_id) {
+        Ok(OracleSource::PythPull)
+    } else if SwitchboardAdapter::is_owner(owner, program_id) {
+        Ok(OracleSource::Switchboard)
+    } else if Push1024Adapter::is_owner(owner, program_id) {
+        Ok(OracleSource::Push1024)
+    } else {
+        msg!("Unsupported Oracle account owner: {}", owner);
+        Err(ListingError::InvalidOracle.into())
+    }
+}
+
+/// 按指定数据源读取归一化价格（验证 owner 和账户格式）
+///
+/// `feed` 为提案 / Registry 记录的喂价 ID，已记录时必须与账户中的 feed_id 一致
+pub fn read_oracle_price(
+    oracle_account: &AccountInfo,
+    source: OracleSource,
+    feed: &OracleFeed,
+    program_id: &Pubkey,
+) -> Result<OraclePrice, ProgramError> {
+    let p = read_unbound_oracle_price(oracle_account, source, program_id)?;
+    if feed.is_set() && p.feed_id != feed.feed_id {
+        msg!("Oracle feed id mismatch");
+        return Err(ListingError::InvalidOracle.into());
+    }
+    Ok(p)
+}
+
+/// 按数据源分派到适配器
+fn read_unbound_oracle_price(
+    oracle_account: &AccountInfo,
+    source: OracleSource,
+    program_id: &Pubkey,
+) -> Result<OraclePrice, ProgramError> {
+    match source {
+        OracleSource::PythV2 => read_with::<PythV2Adapter>(oracle_account, program_id),
+        OracleSource::PythPull => read_with::<PythPullAdapter>(oracle_account, program_id),
+        OracleSource::Switchboard => read_with::<SwitchboardAdapter>(oracle_account, program_id),
+        OracleSource::Push1024 => read_with::<Push1024Adapter>(oracle_account, program_id),
+        #[cfg(feature = "test-oracle")]
+        OracleSource::Mock => read_with::<MockAdapter>(oracle_account, program_id),
+        #[cfg(not(feature = "test-oracle"))]
+        OracleSource::Mock => {
+            msg!("Mock oracle is only accepted by test-oracle builds");
+            Err(ListingError::InvalidOracle.into())
+        }
+    }
+}
+
+/// 生效的最大陈旧时间（秒）
+pub fn effective_max_staleness(config: &OracleRiskConfig) -> i64 {
+    match config.max_staleness_seconds {
+        0 => DEFAULT_ORACLE_MAX_STALENESS_SECONDS as i64,
+        s => s as i64,
+    }
+}
+
+/// 生效的最大置信区间比率（bps）
+pub fn effective_max_confidence_bps(config: &OracleRiskConfig) -> u16 {
+    match config.max_confidence_bps {
+        0 => DEFAULT_ORACLE_MAX_CONFIDENCE_BPS,
+        bps => bps,
+    }
+}
+
+/// 验证 Oracle 风险配置参数（0 = 默认值）
+pub fn validate_oracle_risk_limits(
+    max_staleness_seconds: u32,
+    max_confidence_bps: u16,
+) -> Result<(), ProgramError> {
+    if max_staleness_seconds > MAX_ORACLE_STALENESS_SECONDS {
+        msg!("Max staleness too large: {} (max {})", max_staleness_seconds, MAX_ORACLE_STALENESS_SECONDS);
+        return Err(ListingError::InvalidOracleConfig.into());
+    }
+    if max_confidence_bps > 10_000 {
+        msg!("Max confidence too large: {} bps (max 10000)", max_confidence_bps);
+        return Err(ListingError::InvalidOracleConfig.into());
+    }
+    Ok(())
+}
+
+/// 验证 Oracle 价格
+///
+/// 验证流程:
+/// 1. 按数据源验证账户 owner 并解析价格，比对记录的喂价 ID
+/// 2. 验证价格有效性 (价格 > 0, 置信区间合理, 时间戳新鲜)，阈值取自 `config`
+pub fn validate_oracle_price(
+    oracle_account: &AccountInfo,
+    source: OracleSource,
+    feed: &OracleFeed,
+    program_id: &Pubkey,
+    config: &OracleRiskConfig,
+    current_timestamp: i64,
+) -> Result<OraclePrice, ProgramError> {
+    let p = read_oracle_price(oracle_account, source, feed, program_id)?;
+
+    // 验证时间戳新鲜
+    if current_timestamp.saturating_sub(p.publish_time) > effective_max_staleness(config) {
+        msg!("Oracle price is stale: published at {}, now {}", p.publish_time, current_timestamp);
+        return Err(ListingError::InvalidOracle.into());
+    }
+
+    // 验证价格 > 0
+    if p.price <= 0 {
+        msg!("Oracle price is non-positive: {}", p.price);
+        return Err(ListingError::InvalidOracle.into());
+    }
+
+    // 验证置信区间不能太大 (默认 < 5% of price)
+    let max_conf = (p.price.unsigned_abs() as u128
+        * effective_max_confidence_bps(config) as u128
+        / 10_000) as u64;
+    if p.conf > max_conf {
+        msg!("Oracle confidence interval too large: {} (max: {})", p.conf, max_conf);
+        return Err(ListingError::InvalidOracle.into());
+    }
+
+    // 验证 exponent 在合理范围内
+    if p.expo < -18 || p.expo > 18 {
+        msg!("Oracle price exponent out of range: {}", p.expo);
+        return Err(ListingError::InvalidOracle.into());
+    }
+
+    msg!("Oracle validated successfully ({:?})", source);
+    msg!("Price: {} x 10^{}", p.price, p.expo);
+    msg!("Confidence: {}", p.conf);
+
+    Ok(p)
+}
+
+/// 验证 Oracle 价格，主 Oracle 无效时回退到配置的备用 Oracle
+///
+/// `fallback_account` 必须与 `config.fallback_oracle` 一致；未配置备用 Oracle
+/// 或未传入备用账户时直接返回主 Oracle 的错误。`feed` 只约束主 Oracle，
+/// 备用 Oracle 由地址绑定（Pyth Pull 地址由 feed_id 派生）。
+pub fn validate_oracle_with_fallback(
+    oracle_account: &AccountInfo,
+    fallback_account: Option<&AccountInfo>,
+    source: OracleSource,
+    feed: &OracleFeed,
+    config: &OracleRiskConfig,
+    program_id: &Pubkey,
+    current_timestamp: i64,
+) -> Result<OraclePrice, ProgramError> {
+    let primary_err = match validate_oracle_price(
+        oracle_account,
+        source,
+        feed,
+        program_id,
+        config,
+        current_timestamp,
+    ) {
+        Ok(p) => return Ok(p),
+        Err(e) => e,
+    };
+
+    let fallback_account = match fallback_account {
+        Some(account) if config.has_fallback() => account,
+        _ => return Err(primary_err),
+    };
+    if fallback_account.key != &config.fallback_oracle {
+        msg!("Fallback oracle mismatch: expected {}", config.fallback_oracle);
+        return Err(ListingError::InvalidOracle.into());
+    }
+
+    msg!("Primary oracle invalid, using fallback oracle");
+    validate_oracle_price(
+        fallback_account,
+        config.fallback_source,
+        &OracleFeed::default(),
+        program_id,
+        config,
+        current_timestamp,
+    )
+}
+
+/// 简化的 Oracle 存在性验证（不验证价格），返回识别出的数据源和喂价 ID
+/// 用于市场上架时仅验证 Oracle 账户有效，喂价 ID 记录到提案中
+pub fn validate_oracle_exists(
+    oracle_account: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<(OracleSource, OracleFeed), ProgramError> {
+    // 识别数据源并尝试解析验证格式
+    let source = detect_oracle_source(oracle_account, program_id)?;
+    let p = read_unbound_oracle_price(oracle_account, source, program_id)?;
+
+    msg!("Oracle account verified ({:?})", source);
+    Ok((source, OracleFeed { feed_id: p.feed_id }))
+}
+
+// =============================================================================
+// 测试
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_account<R>(owner: &Pubkey, data: &mut [u8], f: impl FnOnce(&AccountInfo) -> R) -> R {
+        with_keyed_account(&Pubkey::new_unique(), owner, data, f)
+    }
+
+    fn with_keyed_account<R>(
+        key: &Pubkey,
+        owner: &Pubkey,
+        data: &mut [u8],
+        f: impl FnOnce(&AccountInfo) -> R,
+    ) -> R {
+        let mut lamports = 0u64;
+        let account = AccountInfo::new(key, false, false, &mut lamports, data, owner, false, 0);
+        f(&account)
+    }
+
+    #[test]
+    fn test_normalize_decimal() {
+        // 100_000.5 x 10^18 超出 i64，降低精度直到可表示
+        let value = 100_000_500_000_000_000_000_000i128;
+        let (price, conf, expo) = normalize_decimal(value, 10u128.pow(18), -18);
+        assert_eq!((price, expo), (1_000_005_000_000_000_000, -13));
+        assert_eq!(conf, 10_000_000_000_000);
+
+        assert_eq!(normalize_decimal(42, 1, -18), (42, 1, -18));
+    }
+
+    #[test]
+    fn test_pyth_pull_adapter() {
+        let feed_id = [9u8; 32];
+        let (feed_account, _) = Pubkey::find_program_address(
+            &[&PYTH_PUSH_ORACLE_SHARD_ID.to_le_bytes(), &feed_id],
+            &PYTH_PUSH_ORACLE_PROGRAM_ID,
+        );
+
+        let mut data = vec![0u8; 134];
+        data[..8].copy_from_slice(&PYTH_PRICE_UPDATE_V2_DISCRIMINATOR);
+        data[8..40].copy_from_slice(feed_account.as_ref()); // write_authority
+        data[40] = 1; // Full
+        data[41..73].copy_from_slice(&feed_id);
+        let message = 41 + 32;
+        data[message..message + 8].copy_from_slice(&6_500_000_000_000i64.to_le_bytes());
+        data[message + 8..message + 16].copy_from_slice(&1_000_000u64.to_le_bytes());
+        data[message + 16..message + 20].copy_from_slice(&(-8i32).to_le_bytes());
+        data[message + 20..message + 28].copy_from_slice(&1_700_000_000i64.to_le_bytes());
+
+        let program_id = Pubkey::new_unique();
+        let feed = OracleFeed { feed_id };
+        let read = |key: &Pubkey, data: &mut [u8], feed: &OracleFeed| {
+            with_keyed_account(key, &PYTH_RECEIVER_PROGRAM_ID, data, |account| {
+                read_oracle_price(account, OracleSource::PythPull, feed, &program_id)
+            })
+        };
+        with_keyed_account(&feed_account, &PYTH_RECEIVER_PROGRAM_ID, &mut data, |account| {
+            assert_eq!(detect_oracle_source(account, &program_id).unwrap(), OracleSource::PythPull);
+            assert_eq!(validate_oracle_exists(account, &program_id).unwrap(), (OracleSource::PythPull, feed));
+        });
+        assert_eq!(read(&feed_account, &mut data, &feed).unwrap(), OraclePrice {
+            price: 6_500_000_000_000,
+            conf: 1_000_000,
+            expo: -8,
+            publish_time: 1_700_000_000,
+            feed_id,
+        });
+
+        // 与记录的喂价 ID 不一致
+        assert!(read(&feed_account, &mut data, &OracleFeed { feed_id: [1u8; 32] }).is_err());
+
+        // 非 Push Oracle 维护的 PriceUpdateV2：地址或 write_authority 不是 feed_id 派生的 PDA
+        assert!(read(&Pubkey::new_unique(), &mut data, &feed).is_err());
+        data[8..40].copy_from_slice(Pubkey::new_unique().as_ref());
+        assert!(read(&feed_account, &mut data, &feed).is_err());
+        data[8..40].copy_from_slice(feed_account.as_ref());
+
+        // 部分验证 (Partial { num_signatures }) 拒绝
+        data[40] = 0;
+        assert!(read(&feed_account, &mut data, &feed).is_err());
+    }
+
+    #[test]
+    fn test_switchboard_adapter() {
+        let mut data = vec![0u8; SB_PULL_FEED_ACCOUNT_SIZE];
+        data[..8].copy_from_slice(&SWITCHBOARD_PULL_FEED_DISCRIMINATOR);
+        let program_id = Pubkey::new_unique();
+
+        // 尚无结果
+        with_account(&SWITCHBOARD_MAINNET_PROGRAM_ID, &mut data, |account| {
+            assert!(read_oracle_price(account, OracleSource::Switchboard, &OracleFeed::default(), &program_id).is_err());
+        });
+
+        data[SB_RESULT_VALUE_OFFSET..SB_RESULT_VALUE_OFFSET + 16]
+            .copy_from_slice(&(2_500i128 * 10i128.pow(18)).to_le_bytes());
+        data[SB_RESULT_STD_DEV_OFFSET..SB_RESULT_STD_DEV_OFFSET + 16]
+            .copy_from_slice(&(10i128.pow(18)).to_le_bytes());
+        data[SB_RESULT_SUBMISSION_IDX_OFFSET] = 2;
+        data[SB_RESULT_SLOT_OFFSET..SB_RESULT_SLOT_OFFSET + 8].copy_from_slice(&1u64.to_le_bytes());
+        let ts_offset = SB_SUBMISSION_TIMESTAMPS_OFFSET + 2 * 8;
+        data[ts_offset..ts_offset + 8].copy_from_slice(&1_700_000_000i64.to_le_bytes());
+
+        let price = with_account(&SWITCHBOARD_DEVNET_PROGRAM_ID, &mut data, |account| {
+            read_oracle_price(account, OracleSource::Switchboard, &OracleFeed::default(), &program_id).unwrap()
+        });
+        assert_eq!(price.publish_time, 1_700_000_000);
+        assert_eq!(price.price as i128 * 10i128.pow((price.expo + 18) as u32), 2_500 * 10i128.pow(18));
+    }
+
+    #[test]
+    fn test_push_oracle_adapter() {
+        use borsh::BorshSerialize;
+        use crate::state::PUSH_ORACLE_SIZE;
+
+        let mut oracle = PushOracle {
+            discriminator: PushOracle::DISCRIMINATOR,
+            version: 1,
+            feed_id: [7u8; 32],
+            publisher: Pubkey::new_unique(),
+            price: 0,
+            conf: 0,
+            expo: -6,
+            publish_time: 0,
+            bump: 255,
+            reserved: [0u8; 32],
+        };
+        let program_id = Pubkey::new_unique();
+
+        // 尚未推送价格
+        let mut data = oracle.try_to_vec().unwrap();
+        assert_eq!(data.len(), PUSH_ORACLE_SIZE);
+        with_account(&program_id, &mut data, |account| {
+            assert!(read_oracle_price(account, OracleSource::Push1024, &OracleFeed::default(), &program_id).is_err());
+        });
+
+        oracle.price = 185_250_000;
+        oracle.conf = 50_000;
+        oracle.publish_time = 1_700_000_000;
+        let mut data = oracle.try_to_vec().unwrap();
+        let price = with_account(&program_id, &mut data, |account| {
+            assert_eq!(detect_oracle_source(account, &program_id).unwrap(), OracleSource::Push1024);
+            read_oracle_price(account, OracleSource::Push1024, &OracleFeed::default(), &program_id).unwrap()
+        });
+        assert_eq!(price, OraclePrice {
+            price: 185_250_000,
+            conf: 50_000,
+            expo: -6,
+            publish_time: 1_700_000_000,
+            feed_id: [7u8; 32],
+        });
+
+        // 与记录的喂价 ID 不一致
+        with_account(&program_id, &mut data, |account| {
+            let feed = OracleFeed { feed_id: [8u8; 32] };
+            assert!(read_oracle_price(account, OracleSource::Push1024, &feed, &program_id).is_err());
+        });
+    }
+
+    #[test]
+    fn test_oracle_risk_config_fallback() {
+        use borsh::BorshSerialize;
+
+        let program_id = Pubkey::new_unique();
+        let push_oracle = |price: i64, conf: u64, publish_time: i64| {
+            PushOracle {
+                discriminator: PushOracle::DISCRIMINATOR,
+                version: 1,
+                feed_id: [1u8; 32],
+                publisher: Pubkey::new_unique(),
+                price,
+                conf,
+                expo: -6,
+                publish_time,
+                bump: 255,
+                reserved: [0u8; 32],
+            }
+            .try_to_vec()
+            .unwrap()
+        };
+        let now = 1_700_000_100;
+        let feed = OracleFeed { feed_id: [1u8; 32] };
+
+        // 100 秒前的价格：默认 60 秒陈旧，放宽到 120 秒后通过
+        let mut stale = push_oracle(1_000_000, 10_000, 1_700_000_000);
+        let mut config = OracleRiskConfig::default();
+        with_account(&program_id, &mut stale, |account| {
+            assert!(validate_oracle_price(account, OracleSource::Push1024, &feed, &program_id, &config, now).is_err());
+        });
+        config.max_staleness_seconds = 120;
+        with_account(&program_id, &mut stale, |account| {
+            assert!(validate_oracle_price(account, OracleSource::Push1024, &feed, &program_id, &config, now).is_ok());
+        });
+
+        // 1% 置信区间：收紧到 50 bps 后拒绝
+        config.max_confidence_bps = 50;
+        with_account(&program_id, &mut stale, |account| {
+            assert!(validate_oracle_price(account, OracleSource::Push1024, &feed, &program_id, &config, now).is_err());
+        });
+
+        // 主 Oracle 无效时回退到备用 Oracle
+        let mut fresh = push_oracle(1_000_100, 1_000, now);
+        let fallback_key = Pubkey::new_unique();
+        config.fallback_oracle = fallback_key;
+        config.fallback_source = OracleSource::Push1024;
+        let primary_key = Pubkey::new_unique();
+        let (mut l1, mut l2) = (0u64, 0u64);
+        let primary = AccountInfo::new(&primary_key, false, false, &mut l1, &mut stale, &program_id, false, 0);
+        let fallback = AccountInfo::new(&fallback_key, false, false, &mut l2, &mut fresh, &program_id, false, 0);
+        let price = validate_oracle_with_fallback(
+            &primary, Some(&fallback), OracleSource::Push1024, &feed, &config, &program_id, now,
+        ).unwrap();
+        assert_eq!(price.price, 1_000_100);
+        assert!(validate_oracle_with_fallback(
+            &primary, None, OracleSource::Push1024, &feed, &config, &program_id, now,
+        ).is_err());
+        // 备用账户与配置不一致
+        assert!(validate_oracle_with_fallback(
+            &primary, Some(&primary), OracleSource::Push1024, &feed, &config, &program_id, now,
+        ).is_err());
+
+        assert!(validate_oracle_risk_limits(MAX_ORACLE_STALENESS_SECONDS + 1, 0).is_err());
+        assert!(validate_oracle_risk_limits(0, 10_001).is_err());
+        assert!(validate_oracle_risk_limits(3600, 200).is_ok());
+    }
+
+    #[test]
+    fn test_system_account_rejected() {
+        // 空的 System Program 账户不是 Oracle
+        let program_id = Pubkey::new_unique();
+        let system_program = solana_program::system_program::id();
+        with_account(&system_program, &mut [], |account| {
+            assert!(validate_oracle_exists(account, &program_id).is_err());
+        });
+    }
+
+    #[cfg(feature = "test-oracle")]
+    #[test]
+    fn test_mock_oracle_adapter() {
+        use borsh::BorshSerialize;
+        use crate::state::MOCK_ORACLE_SIZE;
+
+        let oracle = MockOracle {
+            discriminator: MockOracle::DISCRIMINATOR,
+            price: 5_000_000,
+            conf: 1_000,
+            expo: -6,
+            publish_time: 1_700_000_000,
+        };
+        let mut data = oracle.try_to_vec().unwrap();
+        assert_eq!(data.len(), MOCK_ORACLE_SIZE);
+
+        let program_id = Pubkey::new_unique();
+        let system_program = solana_program::system_program::id();
+        with_account(&system_program, &mut data, |account| {
+            assert_eq!(validate_oracle_exists(account, &program_id).unwrap().0, OracleSource::Mock);
+        });
+    }
+
+    #[test]
+    fn test_oracle_source_owner_mismatch() {
+        let mut data = vec![0u8; 134];
+        data[..8].copy_from_slice(&PYTH_PRICE_UPDATE_V2_DISCRIMINATOR);
+        let program_id = Pubkey::new_unique();
+        with_account(&Pubkey::new_unique(), &mut data, |account| {
+            assert!(detect_oracle_source(account, &program_id).is_err());
+            assert!(read_oracle_price(account, OracleSource::PythPull, &OracleFeed::default(), &program_id).is_err());
+        });
+    }
+}
@@ -6,7 +6,10 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
     msg,
+    program::{invoke_signed, set_return_data},
     program_error::ProgramError,
     pubkey::Pubkey,
     sysvar::{rent::Rent, Sysvar},
@@ -14,6 +17,7 @@ use solana_program::{
 
 use crate::error::ListingError;
 use crate::instruction::ListingInstruction;
+use crate::oracle::*;
 use crate::state::*;
 use crate::utils::*;
 
@@ -41,7 +45,7 @@ pub fn process_instruction(
 
         ListingInstruction::UpdateAdmin { new_admin } => {
             msg!("Instruction: UpdateAdmin");
-            process_update_admin(program_id, accounts, new_admin)
+            process_propose_admin(program_id, accounts, new_admin)
         }
 
         ListingInstruction::UpdateStakeConfig {
@@ -92,7 +96,7 @@ pub fn process_instruction(
             oracle,
         } => {
             msg!("Instruction: ProposeToken");
-            process_propose_token(program_id, accounts, nonce, symbol, mint, decimals, oracle)
+            process_propose_token(program_id, accounts, nonce, symbol, Some(mint), decimals, oracle)
         }
 
         ListingInstruction::ObjectToken { stake_amount } => {
@@ -105,12 +109,9 @@ pub fn process_instruction(
             process_approve_token(program_id, accounts)
         }
 
-        ListingInstruction::RejectToken {
-            reason_code,
-            slash_percentage,
-        } => {
+        ListingInstruction::RejectToken { reason_code } => {
             msg!("Instruction: RejectToken");
-            process_reject_token(program_id, accounts, reason_code, slash_percentage)
+            process_reject_token(program_id, accounts, reason_code)
         }
 
         ListingInstruction::CancelTokenProposal => {
@@ -175,12 +176,9 @@ pub fn process_instruction(
             process_approve_spot_market(program_id, accounts)
         }
 
-        ListingInstruction::RejectSpotMarket {
-            reason_code,
-            slash_percentage,
-        } => {
+        ListingInstruction::RejectSpotMarket { reason_code } => {
             msg!("Instruction: RejectSpotMarket");
-            process_reject_spot_market(program_id, accounts, reason_code, slash_percentage)
+            process_reject_spot_market(program_id, accounts, reason_code)
         }
 
         ListingInstruction::CancelSpotMarketProposal => {
@@ -274,12 +272,9 @@ pub fn process_instruction(
             process_approve_perp_market(program_id, accounts)
         }
 
-        ListingInstruction::RejectPerpMarket {
-            reason_code,
-            slash_percentage,
-        } => {
+        ListingInstruction::RejectPerpMarket { reason_code } => {
             msg!("Instruction: RejectPerpMarket");
-            process_reject_perp_market(program_id, accounts, reason_code, slash_percentage)
+            process_reject_perp_market(program_id, accounts, reason_code)
         }
 
         ListingInstruction::CancelPerpMarketProposal => {
@@ -410,6 +405,353 @@ pub fn process_instruction(
             msg!("Query: PerpMarket {}", market_index);
             Ok(())
         }
+
+        // =====================================================================
+        // 质押结算指令
+        // =====================================================================
+        ListingInstruction::ClaimObjectionStake => {
+            msg!("Instruction: ClaimObjectionStake");
+            process_claim_objection_stake(program_id, accounts)
+        }
+
+        ListingInstruction::ClaimRejectedStake { proposal_type } => {
+            msg!("Instruction: ClaimRejectedStake");
+            process_claim_rejected_stake(program_id, accounts, proposal_type)
+        }
+
+        // =====================================================================
+        // Admin 权限管理指令
+        // =====================================================================
+        ListingInstruction::ProposeAdmin { new_admin } => {
+            msg!("Instruction: ProposeAdmin");
+            process_propose_admin(program_id, accounts, new_admin)
+        }
+
+        ListingInstruction::AcceptAdmin => {
+            msg!("Instruction: AcceptAdmin");
+            process_accept_admin(program_id, accounts)
+        }
+
+        ListingInstruction::CancelAdminTransfer => {
+            msg!("Instruction: CancelAdminTransfer");
+            process_cancel_admin_transfer(program_id, accounts)
+        }
+
+        ListingInstruction::SetRole { role, authority } => {
+            msg!("Instruction: SetRole");
+            process_set_role(program_id, accounts, role, authority)
+        }
+
+        ListingInstruction::WithdrawTreasury { amount, destination } => {
+            msg!("Instruction: WithdrawTreasury");
+            process_withdraw_treasury(program_id, accounts, amount, destination)
+        }
+
+        ListingInstruction::SetFeeRecipient { fee_recipient } => {
+            msg!("Instruction: SetFeeRecipient");
+            process_set_fee_recipient(program_id, accounts, fee_recipient)
+        }
+
+        ListingInstruction::UpdateObjectionConfig {
+            objection_forfeit_bps,
+            objector_reward_bps,
+        } => {
+            msg!("Instruction: UpdateObjectionConfig");
+            process_update_objection_config(
+                program_id,
+                accounts,
+                objection_forfeit_bps,
+                objector_reward_bps,
+            )
+        }
+
+        // =====================================================================
+        // 多签委员会指令
+        // =====================================================================
+        ListingInstruction::InitializeAdminCouncil { members, threshold } => {
+            msg!("Instruction: InitializeAdminCouncil");
+            process_initialize_admin_council(program_id, accounts, members, threshold)
+        }
+
+        ListingInstruction::UpdateAdminCouncil { members, threshold } => {
+            msg!("Instruction: UpdateAdminCouncil");
+            process_update_admin_council(program_id, accounts, members, threshold)
+        }
+
+        ListingInstruction::CreatePendingAction {
+            instruction_data,
+            account_keys,
+        } => {
+            msg!("Instruction: CreatePendingAction");
+            process_create_pending_action(program_id, accounts, instruction_data, account_keys)
+        }
+
+        ListingInstruction::ApprovePendingAction => {
+            msg!("Instruction: ApprovePendingAction");
+            process_approve_pending_action(program_id, accounts)
+        }
+
+        ListingInstruction::ExecutePendingAction { instruction_data } => {
+            msg!("Instruction: ExecutePendingAction");
+            process_execute_pending_action(program_id, accounts, instruction_data)
+        }
+
+        // =====================================================================
+        // Perp 参数时间锁指令
+        // =====================================================================
+        ListingInstruction::SchedulePerpParamChange {
+            max_leverage,
+            initial_margin_rate_e6,
+            maintenance_margin_rate_e6,
+            max_open_interest_e6,
+        } => {
+            msg!("Instruction: SchedulePerpParamChange");
+            process_schedule_perp_param_change(
+                program_id,
+                accounts,
+                max_leverage,
+                initial_margin_rate_e6,
+                maintenance_margin_rate_e6,
+                max_open_interest_e6,
+            )
+        }
+
+        ListingInstruction::ExecuteParamChange => {
+            msg!("Instruction: ExecuteParamChange");
+            process_execute_param_change(program_id, accounts)
+        }
+
+        ListingInstruction::CancelParamChange => {
+            msg!("Instruction: CancelParamChange");
+            process_cancel_param_change(program_id, accounts)
+        }
+
+        ListingInstruction::SetParamChangeDelay { delay_seconds } => {
+            msg!("Instruction: SetParamChangeDelay");
+            process_set_param_change_delay(program_id, accounts, delay_seconds)
+        }
+
+        ListingInstruction::SetGuardian { guardian } => {
+            msg!("Instruction: SetGuardian");
+            process_set_guardian(program_id, accounts, guardian)
+        }
+
+        ListingInstruction::SetPauseScope { scopes, paused } => {
+            msg!("Instruction: SetPauseScope");
+            process_set_pause_scope(program_id, accounts, scopes, paused)
+        }
+
+        // =====================================================================
+        // 社区投票指令
+        // =====================================================================
+        ListingInstruction::CastVote {
+            proposal_type,
+            support,
+            amount,
+        } => {
+            msg!("Instruction: CastVote");
+            process_cast_vote(program_id, accounts, proposal_type, support, amount)
+        }
+
+        ListingInstruction::ResolveProposal { proposal_type } => {
+            msg!("Instruction: ResolveProposal");
+            process_resolve_proposal(program_id, accounts, proposal_type)
+        }
+
+        ListingInstruction::ClaimVoteStake => {
+            msg!("Instruction: ClaimVoteStake");
+            process_claim_vote_stake(program_id, accounts)
+        }
+
+        ListingInstruction::SetVotingPeriod { voting_period_seconds } => {
+            msg!("Instruction: SetVotingPeriod");
+            process_set_voting_period(program_id, accounts, voting_period_seconds)
+        }
+
+        // =====================================================================
+        // 申诉指令
+        // =====================================================================
+        ListingInstruction::FileAppeal { proposal_type } => {
+            msg!("Instruction: FileAppeal");
+            process_file_appeal(program_id, accounts, proposal_type)
+        }
+
+        ListingInstruction::ResolveAppeal { proposal_type, overturn } => {
+            msg!("Instruction: ResolveAppeal");
+            process_resolve_appeal(program_id, accounts, proposal_type, overturn)
+        }
+
+        ListingInstruction::UpdateAppealConfig {
+            appeal_window_seconds,
+            appeal_bond_percentage,
+        } => {
+            msg!("Instruction: UpdateAppealConfig");
+            process_update_appeal_config(
+                program_id,
+                accounts,
+                appeal_window_seconds,
+                appeal_bond_percentage,
+            )
+        }
+
+        ListingInstruction::AuditTreasury => {
+            msg!("Instruction: AuditTreasury");
+            process_audit_treasury(program_id, accounts)
+        }
+
+        ListingInstruction::UpdateSlashSchedule {
+            cancel_slash_bps,
+            vote_rejection_slash_bps,
+            reason_slash_bps,
+        } => {
+            msg!("Instruction: UpdateSlashSchedule");
+            process_update_slash_schedule(
+                program_id,
+                accounts,
+                cancel_slash_bps,
+                vote_rejection_slash_bps,
+                reason_slash_bps,
+            )
+        }
+        ListingInstruction::SetStakeMode { stake_mode } => {
+            msg!("Instruction: SetStakeMode");
+            process_set_stake_mode(program_id, accounts, stake_mode)
+        }
+        ListingInstruction::UpdateStakePricing {
+            enabled,
+            free_pending,
+            per_pending_bps,
+            max_multiplier_bps,
+            decay_seconds,
+        } => {
+            msg!("Instruction: UpdateStakePricing");
+            process_update_stake_pricing(
+                program_id,
+                accounts,
+                enabled,
+                free_pending,
+                per_pending_bps,
+                max_multiplier_bps,
+                decay_seconds,
+            )
+        }
+        ListingInstruction::IndexRegisteredToken => {
+            msg!("Instruction: IndexRegisteredToken");
+            process_index_registered_token(program_id, accounts)
+        }
+        ListingInstruction::ProposeSyntheticToken {
+            nonce,
+            symbol,
+            decimals,
+            oracle,
+        } => {
+            msg!("Instruction: ProposeSyntheticToken");
+            process_propose_token(program_id, accounts, nonce, symbol, None, decimals, Some(oracle))
+        }
+        ListingInstruction::AllowMarketReplacement {
+            market_type,
+            base_token_index,
+            quote_token_index,
+            allowed,
+        } => {
+            msg!("Instruction: AllowMarketReplacement");
+            process_allow_market_replacement(
+                program_id,
+                accounts,
+                market_type,
+                base_token_index,
+                quote_token_index,
+                allowed,
+            )
+        }
+        ListingInstruction::IndexListedMarket { market_type } => {
+            msg!("Instruction: IndexListedMarket");
+            process_index_listed_market(program_id, accounts, market_type)
+        }
+        ListingInstruction::SetTokenMetadata {
+            name,
+            uri,
+            category,
+            metadata_hash,
+        } => {
+            msg!("Instruction: SetTokenMetadata");
+            let content = TokenMetadataContent { name, uri, category, metadata_hash };
+            process_set_token_metadata(program_id, accounts, content)
+        }
+        ListingInstruction::ScheduleTokenMetadataUpdate {
+            name,
+            uri,
+            category,
+            metadata_hash,
+        } => {
+            msg!("Instruction: ScheduleTokenMetadataUpdate");
+            let content = TokenMetadataContent { name, uri, category, metadata_hash };
+            process_schedule_token_metadata_update(program_id, accounts, content)
+        }
+        ListingInstruction::ExecuteTokenMetadataUpdate => {
+            msg!("Instruction: ExecuteTokenMetadataUpdate");
+            process_execute_token_metadata_update(program_id, accounts)
+        }
+        ListingInstruction::CancelTokenMetadataUpdate => {
+            msg!("Instruction: CancelTokenMetadataUpdate");
+            process_cancel_token_metadata_update(program_id, accounts)
+        }
+        ListingInstruction::CreatePushOracle {
+            feed_id,
+            expo,
+            publisher,
+        } => {
+            msg!("Instruction: CreatePushOracle");
+            process_create_push_oracle(program_id, accounts, feed_id, expo, publisher)
+        }
+        ListingInstruction::UpdatePushOraclePrice { price, conf } => {
+            msg!("Instruction: UpdatePushOraclePrice");
+            process_update_push_oracle_price(program_id, accounts, price, conf)
+        }
+        ListingInstruction::SetPushOraclePublisher { publisher } => {
+            msg!("Instruction: SetPushOraclePublisher");
+            process_set_push_oracle_publisher(program_id, accounts, publisher)
+        }
+        ListingInstruction::UpdatePerpOracleConfig {
+            max_staleness_seconds,
+            max_confidence_bps,
+            fallback_oracle,
+        } => {
+            msg!("Instruction: UpdatePerpOracleConfig");
+            process_update_perp_oracle_config(
+                program_id,
+                accounts,
+                max_staleness_seconds,
+                max_confidence_bps,
+                fallback_oracle,
+            )
+        }
+        ListingInstruction::UpdateTokenOracleConfig {
+            max_staleness_seconds,
+            max_confidence_bps,
+            fallback_oracle,
+        } => {
+            msg!("Instruction: UpdateTokenOracleConfig");
+            process_update_token_oracle_config(
+                program_id,
+                accounts,
+                max_staleness_seconds,
+                max_confidence_bps,
+                fallback_oracle,
+            )
+        }
+        ListingInstruction::MigrateListingConfig => {
+            msg!("Instruction: MigrateListingConfig");
+            process_migrate_listing_config(program_id, accounts)
+        }
+        ListingInstruction::WithdrawTreasuryToken { amount, destination } => {
+            msg!("Instruction: WithdrawTreasuryToken");
+            process_withdraw_treasury_token(program_id, accounts, amount, destination)
+        }
+        ListingInstruction::SetInsuranceVault { insurance_vault } => {
+            msg!("Instruction: SetInsuranceVault");
+            process_set_insurance_vault(program_id, accounts, insurance_vault)
+        }
     }
 }
 
@@ -505,7 +847,26 @@ fn process_initialize(
         total_staked_lamports: 0,
         is_paused: false,
         bump: config_bump,
-        reserved: [0u8; 64],
+        objection_forfeit_bps: ListingConfig::DEFAULT_OBJECTION_FORFEIT_BPS,
+        objector_reward_bps: ListingConfig::DEFAULT_OBJECTOR_REWARD_BPS,
+        total_slashed_lamports: 0,
+        fee_recipient: Pubkey::default(),
+        pending_admin: Pubkey::default(),
+        reviewer: Pubkey::default(),
+        risk_manager: Pubkey::default(),
+        pauser: Pubkey::default(),
+        treasury_manager: Pubkey::default(),
+        pool_operator: Pubkey::default(),
+        param_change_delay_seconds: ListingConfig::DEFAULT_PARAM_CHANGE_DELAY,
+        guardian: Pubkey::default(),
+        pause_flags: 0,
+        voting_period_seconds: ListingConfig::DEFAULT_VOTING_PERIOD,
+        appeal_window_seconds: ListingConfig::DEFAULT_APPEAL_WINDOW,
+        appeal_bond_percentage: ListingConfig::DEFAULT_APPEAL_BOND_PERCENTAGE,
+        stake_mode: StakeMode::Native,
+        pending_proposals: 0,
+        insurance_vault: Pubkey::default(),
+        reserved: [0u8; 3],
     };
 
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
@@ -520,7 +881,33 @@ fn process_initialize(
     Ok(())
 }
 
-fn process_update_admin(
+fn process_migrate_listing_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    // 加载配置（旧版布局的新增字段已取默认值）并验证 Admin
+    let config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    verify_legacy_listing_config(config_account)?;
+
+    // 扩容并写入当前布局
+    resize_account(admin, config_account, LISTING_CONFIG_SIZE, system_program)?;
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Event: ListingConfigMigrated");
+    msg!("Size: {} -> {} bytes", LEGACY_LISTING_CONFIG_SIZE, LISTING_CONFIG_SIZE);
+
+    Ok(())
+}
+
+fn process_propose_admin(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     new_admin: Pubkey,
@@ -531,16 +918,131 @@ fn process_update_admin(
     let config_account = next_account_info(account_iter)?;
 
     // 反序列化
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
     
     // 验证 Admin
-    verify_admin(admin, &config)?;
+    verify_admin(admin, &config, accounts)?;
+
+    if new_admin == Pubkey::default() {
+        return Err(ListingError::InvalidAdmin.into());
+    }
+
+    // 记录待接受的新 Admin（需新 Admin 签名 AcceptAdmin 后生效）
+    config.pending_admin = new_admin;
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Event: AdminTransferProposed");
+    msg!("Current admin: {}", config.admin);
+    msg!("Pending admin: {}", new_admin);
+
+    Ok(())
+}
+
+fn process_accept_admin(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let new_admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+
+    // 验证签名
+    if !new_admin.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut config = load_listing_config(program_id, config_account)?;
+
+    // 验证存在待处理的转移且签名者为 pending_admin
+    if config.pending_admin == Pubkey::default() {
+        return Err(ListingError::NoPendingAdmin.into());
+    }
+    if new_admin.key != &config.pending_admin {
+        return Err(ListingError::InvalidAdmin.into());
+    }
+
+    let previous_admin = config.admin;
+    config.admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Event: AdminTransferAccepted");
+    msg!("Previous admin: {}", previous_admin);
+    msg!("New admin: {}", config.admin);
+
+    Ok(())
+}
+
+fn process_cancel_admin_transfer(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    if config.pending_admin == Pubkey::default() {
+        return Err(ListingError::NoPendingAdmin.into());
+    }
+
+    let cancelled_admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Event: AdminTransferCancelled");
+    msg!("Cancelled pending admin: {}", cancelled_admin);
+
+    Ok(())
+}
+
+fn process_set_role(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    role: Role,
+    authority: Pubkey,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    // Pubkey::default() 表示撤销该角色（仅 Admin 可执行）
+    *config.role_authority_mut(role) = authority;
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Event: RoleUpdated");
+    msg!("Role: {:?}", role);
+    msg!("Authority: {}", authority);
+
+    Ok(())
+}
+
+fn process_set_guardian(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    guardian: Pubkey,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
 
-    // 更新 Admin
-    config.admin = new_admin;
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    config.guardian = guardian;
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
-    msg!("Admin updated to: {}", new_admin);
+    msg!("Event: GuardianUpdated");
+    msg!("Guardian: {}", guardian);
 
     Ok(())
 }
@@ -557,8 +1059,8 @@ fn process_update_stake_config(
     let admin = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
 
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
-    verify_admin(admin, &config)?;
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
 
     if let Some(amount) = token_stake_amount {
         config.token_stake_amount = amount;
@@ -590,8 +1092,8 @@ fn process_update_review_periods(
     let admin = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
 
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
-    verify_admin(admin, &config)?;
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
 
     if let Some(period) = token_review_period {
         config.token_review_period_seconds = period;
@@ -620,11 +1122,11 @@ fn process_set_paused(
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
     
-    let admin = next_account_info(account_iter)?;
+    let pauser = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
 
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
-    verify_admin(admin, &config)?;
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_pause_authority(pauser, &config, paused, accounts)?;
 
     config.is_paused = paused;
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
@@ -634,16 +1136,49 @@ fn process_set_paused(
     Ok(())
 }
 
+fn process_set_pause_scope(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    scopes: u8,
+    paused: bool,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let pauser = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_pause_authority(pauser, &config, paused, accounts)?;
+
+    if scopes == 0 || scopes & !PAUSE_ALL != 0 {
+        return Err(ListingError::InvalidPauseScope.into());
+    }
+
+    if paused {
+        config.pause_flags |= scopes;
+    } else {
+        config.pause_flags &= !scopes;
+    }
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Event: PauseScopeUpdated");
+    msg!("Scopes: {:#04x}, paused: {}", scopes, paused);
+    msg!("Pause flags: {:#04x}", config.pause_flags);
+
+    Ok(())
+}
+
 // =============================================================================
 // PLP-1: Token 注册指令处理
 // =============================================================================
 
+/// 提交 Token 注册申请（mint = None 为 Synthetic 资产）
 fn process_propose_token(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     nonce: u64,
     symbol: [u8; 8],
-    mint: Pubkey,
+    mint: Option<Pubkey>,
     decimals: u8,
     oracle: Option<Pubkey>,
 ) -> ProgramResult {
@@ -653,9 +1188,16 @@ fn process_propose_token(
     let proposal_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
     let treasury_account = next_account_info(account_iter)?;
-    let token_mint = next_account_info(account_iter)?;
-    let _oracle_account = next_account_info(account_iter).ok(); // 可选
+    let token_mint = match mint {
+        Some(_) => Some(next_account_info(account_iter)?),
+        None => None,
+    };
+    let oracle_account = next_account_info(account_iter).ok(); // SPL 资产可选，Synthetic 资产必须
     let system_program = next_account_info(account_iter)?;
+    let stake_pricing_account = next_account_info(account_iter)?;
+    let asset_kind_hint = if mint.is_some() { AssetKind::SplMint } else { AssetKind::Synthetic };
+    let (mint_index_account, symbol_index_account) =
+        next_token_index_accounts(account_iter, asset_kind_hint)?;
 
     // 验证签名
     if !proposer.is_signer {
@@ -663,12 +1205,10 @@ fn process_propose_token(
     }
 
     // 加载配置
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
     
     // 检查是否暂停
-    if config.is_paused {
-        return Err(ListingError::ListingPaused.into());
-    }
+    verify_not_paused(&config, PAUSE_TOKEN_PROPOSALS)?;
 
     // 验证 Treasury PDA
     if treasury_account.key != &config.treasury {
@@ -680,10 +1220,48 @@ fn process_propose_token(
     validate_symbol(&symbol)?;
     validate_decimals(decimals)?;
 
-    // 验证 Mint 存在
-    if token_mint.key != &mint {
-        return Err(ListingError::InvalidAccount.into());
-    }
+    let (asset_kind, authorities, risk_flags) = match (mint, token_mint) {
+        (Some(mint), Some(token_mint)) => {
+            // 验证 Mint 账户并校验精度
+            if token_mint.key != &mint {
+                return Err(ListingError::InvalidAccount.into());
+            }
+            let VerifiedMint { authorities, asset_kind, risk_flags } =
+                verify_spl_mint(token_mint, decimals)?;
+            validate_token_risk_flags(risk_flags)?;
+            (asset_kind, Some(authorities), risk_flags)
+        }
+        _ => {
+            // Synthetic 资产没有 Mint，必须提供有效 Oracle
+            if oracle.is_none() {
+                msg!("Synthetic asset requires an oracle");
+                return Err(ListingError::InvalidOracle.into());
+            }
+            (AssetKind::Synthetic, None, 0)
+        }
+    };
+    let mint = mint.unwrap_or_default();
+
+    // 验证 Oracle 账户，识别数据源并记录喂价 ID
+    let (oracle_source, oracle_feed) = match oracle {
+        Some(oracle) => {
+            let oracle_account = oracle_account.ok_or(ProgramError::NotEnoughAccountKeys)?;
+            if oracle_account.key != &oracle {
+                return Err(ListingError::InvalidOracle.into());
+            }
+            validate_oracle_exists(oracle_account, program_id)?
+        }
+        None => (OracleSource::default(), OracleFeed::default()),
+    };
+
+    // 已注册的 Mint / Symbol 提前拒绝
+    verify_token_unregistered(
+        program_id,
+        mint_index_account,
+        symbol_index_account,
+        &mint,
+        &symbol,
+    )?;
 
     // 验证 Proposal PDA
     let (expected_proposal, bump) = derive_token_proposal_pda(proposer.key, nonce, program_id);
@@ -696,36 +1274,27 @@ fn process_propose_token(
         return Err(ListingError::AlreadyInitialized.into());
     }
 
+    // 计算质押金额（动态定价启用时按待审提案数加价）
+    let base_stake = config.token_stake_amount;
+    let stake_amount = price_proposal_stake(
+        program_id,
+        &mut config,
+        stake_pricing_account,
+        base_stake,
+    )?;
+
     // 检查 proposer 余额是否足够
-    let stake_amount = config.token_stake_amount;
-    if proposer.lamports() < stake_amount {
+    if config.stake_mode == StakeMode::Native && proposer.lamports() < stake_amount {
         msg!("Insufficient balance. Required: {} lamports ({} N1024)", 
              stake_amount, stake_amount / 1_000_000_000);
         return Err(ListingError::InsufficientStake.into());
     }
 
-    // 转移原生 N1024 质押 (lamports)
-    transfer_native_lamports(
-        proposer,
-        treasury_account,
-        stake_amount,
-        system_program,
-        None,
-    )?;
-
-    // 更新配置中的总质押额
-    config.total_staked_lamports = config.total_staked_lamports
-        .checked_add(stake_amount)
-        .ok_or(ListingError::Overflow)?;
-    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
-
-    msg!("Stake transferred: {} N1024", stake_amount / 1_000_000_000);
-
-    // 创建 Proposal 账户
+    // 创建 Proposal 账户（Synthetic 资产没有 Mint 权限快照，该段留空）
     create_account(
         proposer,
         proposal_account,
-        TOKEN_PROPOSAL_SIZE,
+        TOKEN_PROPOSAL_ACCOUNT_SIZE,
         program_id,
         system_program,
         &[
@@ -736,20 +1305,32 @@ fn process_propose_token(
         ],
     )?;
 
+    // 转移质押到提案托管
+    escrow_proposal_stake(
+        program_id,
+        proposer,
+        proposal_account,
+        treasury_account,
+        system_program,
+        account_iter,
+        config.stake_mode,
+        stake_amount,
+    )?;
+
     // 初始化数据
     let current_ts = get_current_timestamp()?;
     let review_deadline = current_ts + config.token_review_period_seconds as i64;
 
     let proposal = TokenProposal {
         discriminator: TokenProposal::DISCRIMINATOR,
-        version: 1,
+        version: ESCROW_PROPOSAL_VERSION,
         proposer: *proposer.key,
         nonce,
         symbol,
         mint,
         decimals,
         oracle,
-        stake_amount: config.token_stake_amount,
+        stake_amount,
         status: ProposalStatus::Pending,
         created_at: current_ts,
         review_deadline,
@@ -757,23 +1338,149 @@ fn process_propose_token(
         objection_stake: 0,
         stake_claimed: false,
         bump,
-        reserved: [0u8; 64],
+        slash_amount: 0,
+        objector_reward: 0,
+        voting_deadline: review_deadline + config.voting_period_seconds as i64,
+        votes_for: 0,
+        votes_against: 0,
+        rejected_at: 0,
+        appeal_bond: 0,
+        stake_mode: config.stake_mode,
+        asset_kind,
+        risk_flags,
+        oracle_source,
+        slash_pending: false,
+        reserved: [0u8; 3],
     };
 
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
     proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+    if let Some(authorities) = &authorities {
+        authorities.store(&mut proposal_account.data.borrow_mut())?;
+    }
+    oracle_feed.store(&mut proposal_account.data.borrow_mut(), TOKEN_PROPOSAL_FEED_OFFSET)?;
 
     msg!("Token proposal created");
     msg!("Proposer: {}", proposer.key);
     msg!("Symbol: {:?}", std::str::from_utf8(&symbol).unwrap_or(""));
-    msg!("Mint: {}", mint);
-    msg!("Stake: {} 1024", config.token_stake_amount / 1_000_000);
+    msg!("Asset kind: {:?}", asset_kind);
+    if let Some(authorities) = &authorities {
+        msg!("Mint: {}", mint);
+        msg!("Mint authority: {:?}", authorities.mint_authority);
+        msg!("Freeze authority: {:?}", authorities.freeze_authority);
+    } else {
+        msg!("Oracle: {:?}", oracle);
+    }
+    if asset_kind == AssetKind::Token2022Mint {
+        msg!("Token-2022 mint, risk flags: {:#04x}", risk_flags);
+        if requires_token_review(risk_flags) {
+            msg!("Reviewer approval required (no auto-approval)");
+        }
+    }
+    msg!("Stake: {} N1024", stake_amount / 1_000_000_000);
     msg!("Review deadline: {}", review_deadline);
 
     Ok(())
 }
 
+/// 计算新提案质押（动态定价启用时按待审提案数加价），并计入待审提案数
+fn price_proposal_stake(
+    program_id: &Pubkey,
+    config: &mut ListingConfig,
+    pricing_account: &AccountInfo,
+    base_stake: u64,
+) -> Result<u64, ProgramError> {
+    let mut pricing = load_stake_pricing(program_id, pricing_account)?;
+    let mut stake_amount = base_stake;
+
+    if pricing.enabled {
+        let current_ts = get_current_timestamp()?;
+        let surge_bps = calculate_stake_surge(&pricing, config.pending_proposals, current_ts);
+        stake_amount = calculate_dynamic_stake(base_stake, surge_bps, pricing.max_multiplier_bps)?;
+
+        pricing.surge_bps = surge_bps;
+        pricing.surge_updated_at = current_ts;
+        pricing.serialize(&mut &mut pricing_account.data.borrow_mut()[..])?;
+
+        msg!("Dynamic stake surge: {} bps ({} pending)", surge_bps, config.pending_proposals);
+    }
+
+    config.pending_proposals = config.pending_proposals
+        .checked_add(1)
+        .ok_or(ListingError::Overflow)?;
+
+    Ok(stake_amount)
+}
+
+/// 提案离开待审核状态时减少待审提案数（升级前创建的提案未计数，饱和扣减）
+fn release_pending_proposal(config: &mut ListingConfig) {
+    config.pending_proposals = config.pending_proposals.saturating_sub(1);
+}
+
+/// 将新提案的质押存入托管（SPL 模式下先创建提案托管 token 账户）
+#[allow(clippy::too_many_arguments)]
+fn escrow_proposal_stake<'r, 'a>(
+    program_id: &Pubkey,
+    proposer: &'r AccountInfo<'a>,
+    proposal_account: &'r AccountInfo<'a>,
+    treasury_account: &'r AccountInfo<'a>,
+    system_program: &'r AccountInfo<'a>,
+    account_iter: &mut std::slice::Iter<'r, AccountInfo<'a>>,
+    stake_mode: StakeMode,
+    stake_amount: u64,
+) -> ProgramResult {
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
+        treasury_account,
+        ESCROW_PROPOSAL_VERSION,
+        stake_mode,
+        account_iter,
+        true,
+    )?;
+    let StakeVault::Token(spl) = vault else {
+        transfer_native_lamports(
+            proposer,
+            proposal_account,
+            stake_amount,
+            system_program,
+            None,
+        )?;
+        msg!("Stake escrowed: {} N1024", stake_amount / 1_000_000_000);
+        return Ok(());
+    };
+
+    // 质押 mint 以 Treasury 质押 token 账户为准
+    let stake_mint = next_account_info(account_iter)?;
+    if stake_mint.key != &unpack_token_account(spl.treasury_token)?.mint {
+        return Err(ListingError::InvalidStakeMint.into());
+    }
+
+    let (_, escrow_bump) = derive_stake_escrow_pda(proposal_account.key, program_id);
+    create_stake_token_account(
+        proposer,
+        spl.escrow_token,
+        stake_mint,
+        spl.stake_authority,
+        spl.token_program,
+        system_program,
+        &[STAKE_ESCROW_SEED, proposal_account.key.as_ref(), &[escrow_bump]],
+    )?;
+    spl_token_transfer(
+        spl.user_token.ok_or(ListingError::InvalidAccount)?,
+        spl.escrow_token,
+        proposer,
+        spl.token_program,
+        stake_amount,
+        None,
+    )?;
+
+    msg!("Stake escrowed: {} (stake token base units)", stake_amount);
+    Ok(())
+}
+
 fn process_object_token(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     stake_amount: u64,
 ) -> ProgramResult {
@@ -781,6 +1488,7 @@ fn process_object_token(
     
     let objector = next_account_info(account_iter)?;
     let proposal_account = next_account_info(account_iter)?;
+    let objection_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
     let treasury_account = next_account_info(account_iter)?;
     let system_program = next_account_info(account_iter)?;
@@ -791,7 +1499,7 @@ fn process_object_token(
     }
 
     // 加载配置
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
 
     // 验证 Treasury
     if treasury_account.key != &config.treasury {
@@ -812,30 +1520,53 @@ fn process_object_token(
         return Err(ListingError::ReviewDeadlinePassed.into());
     }
 
+    // 验证金额
+    if stake_amount == 0 {
+        return Err(ListingError::InvalidAmount.into());
+    }
+
     // 检查余额
-    if objector.lamports() < stake_amount {
+    if proposal.stake_mode == StakeMode::Native && objector.lamports() < stake_amount {
         msg!("Insufficient balance for objection stake");
         return Err(ListingError::InsufficientStake.into());
     }
 
-    // 转移原生 N1024 反对质押
-    transfer_native_lamports(
-        objector,
+    // 转移反对质押到提案托管
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
         treasury_account,
-        stake_amount,
+        proposal.version,
+        proposal.stake_mode,
+        account_iter,
+        true,
+    )?;
+    deposit_stake(
+        &mut config,
+        &vault,
+        objector,
         system_program,
-        None,
+        stake_amount,
     )?;
-
-    // 更新总质押
-    config.total_staked_lamports = config.total_staked_lamports
-        .checked_add(stake_amount)
-        .ok_or(ListingError::Overflow)?;
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
-    // 更新反对信息
-    proposal.objection_count = proposal.objection_count.checked_add(1)
-        .ok_or(ListingError::Overflow)?;
+    // 记录反对者 Objection PDA
+    let is_new_objector = record_objection(
+        program_id,
+        objector,
+        objection_account,
+        proposal_account.key,
+        ProposalType::Token,
+        stake_amount,
+        system_program,
+        current_ts,
+    )?;
+
+    // 更新反对信息（每个反对者只计数一次）
+    if is_new_objector {
+        proposal.objection_count = proposal.objection_count.checked_add(1)
+            .ok_or(ListingError::Overflow)?;
+    }
     proposal.objection_stake = proposal.objection_stake.checked_add(stake_amount)
         .ok_or(ListingError::Overflow)?;
 
@@ -849,31 +1580,166 @@ fn process_object_token(
     Ok(())
 }
 
-fn process_approve_token(
+/// 计算拒绝罚没并更新全局统计，返回 (slash_amount, objector_reward)
+///
+/// 罚没部分转入 Treasury（反对者奖励部分在取回前仍留在提案托管中）。
+/// 可申诉的拒绝传入 `defer_slash`，罚没资金留在托管中且暂不计入统计，申诉结束后由
+/// `take_pending_slash` 取出、计入统计并转入 Treasury
+fn settle_rejection(
+    config: &mut ListingConfig,
+    vault: &StakeVault,
+    stake_amount: u64,
+    objection_stake: u64,
+    slash_bps: u16,
+    defer_slash: bool,
+) -> Result<(u64, u64), ProgramError> {
+    let slash_amount = calculate_slash_amount(stake_amount, slash_bps);
+    let objector_reward = calculate_objector_reward_pool(
+        slash_amount,
+        objection_stake,
+        config.objector_reward_bps,
+    );
+
+    if !defer_slash {
+        slash_stake(
+            config,
+            vault,
+            slash_amount - objector_reward,
+        )?;
+        config.total_slashed_lamports = config.total_slashed_lamports
+            .checked_add(slash_amount)
+            .ok_or(ListingError::Overflow)?;
+    }
+
+    Ok((slash_amount, objector_reward))
+}
+
+/// 读取 MintIndex（Synthetic 资产没有）与 SymbolIndex PDA
+fn next_token_index_accounts<'a, 'b, I: Iterator<Item = &'a AccountInfo<'b>>>(
+    account_iter: &mut I,
+    asset_kind: AssetKind,
+) -> Result<(Option<&'a AccountInfo<'b>>, &'a AccountInfo<'b>), ProgramError> {
+    let mint_index_account = match asset_kind {
+        AssetKind::Synthetic => None,
+        _ => Some(next_account_info(account_iter)?),
+    };
+    let symbol_index_account = next_account_info(account_iter)?;
+    Ok((mint_index_account, symbol_index_account))
+}
+
+/// 检查 Mint / Symbol 尚未被已注册 Token 占用（mint_index_account 为 None 时只检查 Symbol）
+fn verify_token_unregistered(
     program_id: &Pubkey,
-    accounts: &[AccountInfo],
+    mint_index_account: Option<&AccountInfo>,
+    symbol_index_account: &AccountInfo,
+    mint: &Pubkey,
+    symbol: &[u8; 8],
 ) -> ProgramResult {
-    let account_iter = &mut accounts.iter();
-    
-    let admin = next_account_info(account_iter)?;
-    let proposal_account = next_account_info(account_iter)?;
-    let registry_account = next_account_info(account_iter)?;
-    let config_account = next_account_info(account_iter)?;
-    let system_program = next_account_info(account_iter)?;
-    
-    // 加载配置并验证 Admin
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
-    verify_admin(admin, &config)?;
-
-    // 加载提案
-    // Note: Use deserialize() instead of try_from_slice() because when oracle = None,
-    // the serialized data is shorter than the account size (Option<None> uses 1 byte vs 33).
-    let mut proposal = TokenProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
-    
-    // 验证状态
-    if proposal.status != ProposalStatus::Pending {
-        return Err(ListingError::ProposalNotPending.into());
+    if let Some(mint_index_account) = mint_index_account {
+        let (expected_mint_index, _) = derive_mint_index_pda(mint, program_id);
+        if mint_index_account.key != &expected_mint_index {
+            return Err(ListingError::InvalidPda.into());
+        }
+        if !mint_index_account.data_is_empty() {
+            msg!("Mint {} already registered", mint);
+            return Err(ListingError::DuplicateToken.into());
+        }
+    }
+    let (expected_symbol_index, _) = derive_symbol_index_pda(symbol, program_id);
+    if symbol_index_account.key != &expected_symbol_index {
+        return Err(ListingError::InvalidPda.into());
+    }
+    if !symbol_index_account.data_is_empty() {
+        msg!("Symbol {:?} already registered", std::str::from_utf8(symbol_seed(symbol)).unwrap_or(""));
+        return Err(ListingError::DuplicateToken.into());
     }
+    Ok(())
+}
+
+/// 创建 MintIndex PDA（调用方已验证地址且账户为空）
+fn create_mint_index<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    mint_index_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    mint: &Pubkey,
+    token_index: u16,
+) -> ProgramResult {
+    let (_, bump) = derive_mint_index_pda(mint, program_id);
+    create_account(
+        payer,
+        mint_index_account,
+        MINT_INDEX_SIZE,
+        program_id,
+        system_program,
+        &[MINT_INDEX_SEED, mint.as_ref(), &[bump]],
+    )?;
+    let index = MintIndex {
+        discriminator: MintIndex::DISCRIMINATOR,
+        mint: *mint,
+        token_index,
+        bump,
+        reserved: [0u8; 16],
+    };
+    index.serialize(&mut &mut mint_index_account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+/// 创建 SymbolIndex PDA（调用方已验证地址且账户为空）
+fn create_symbol_index<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    symbol_index_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    symbol: &[u8; 8],
+    token_index: u16,
+) -> ProgramResult {
+    let (_, bump) = derive_symbol_index_pda(symbol, program_id);
+    create_account(
+        payer,
+        symbol_index_account,
+        SYMBOL_INDEX_SIZE,
+        program_id,
+        system_program,
+        &[SYMBOL_INDEX_SEED, symbol_seed(symbol), &[bump]],
+    )?;
+    let index = SymbolIndex {
+        discriminator: SymbolIndex::DISCRIMINATOR,
+        symbol: *symbol,
+        token_index,
+        bump,
+        reserved: [0u8; 16],
+    };
+    index.serialize(&mut &mut symbol_index_account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+/// 为批准的 Token 提案创建 TokenRegistry 及 Mint/Symbol 唯一性索引，返回 token_index
+/// （Synthetic 资产 mint_index_account 为 None）
+#[allow(clippy::too_many_arguments)]
+fn register_approved_token<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    registry_account: &AccountInfo<'a>,
+    mint_index_account: Option<&AccountInfo<'a>>,
+    symbol_index_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    config: &mut ListingConfig,
+    proposal: &mut TokenProposal,
+    proposal_account: &AccountInfo<'a>,
+    current_ts: i64,
+) -> Result<u16, ProgramError> {
+    let proposal_key = proposal_account.key;
+    let oracle_feed = OracleFeed::load(&proposal_account.data.borrow(), TOKEN_PROPOSAL_FEED_OFFSET);
+
+    // 同一 Mint / Symbol 只能注册一次
+    verify_token_unregistered(
+        program_id,
+        mint_index_account,
+        symbol_index_account,
+        &proposal.mint,
+        &proposal.symbol,
+    )?;
 
     // 分配 token_index
     let token_index = config.total_tokens;
@@ -888,16 +1754,15 @@ fn process_approve_token(
 
     // 创建 Registry 账户
     create_account(
-        admin,
+        payer,
         registry_account,
-        TOKEN_REGISTRY_SIZE,
+        TOKEN_REGISTRY_ACCOUNT_SIZE,
         program_id,
         system_program,
         &[TOKEN_REGISTRY_SEED, &token_index.to_le_bytes(), &[bump]],
     )?;
 
     // 初始化 Registry
-    let current_ts = get_current_timestamp()?;
     let registry = TokenRegistry {
         discriminator: TokenRegistry::DISCRIMINATOR,
         version: 1,
@@ -910,16 +1775,90 @@ fn process_approve_token(
         proposer: proposal.proposer,
         approved_at: current_ts,
         bump,
-        reserved: [0u8; 64],
+        asset_kind: proposal.asset_kind,
+        risk_flags: proposal.risk_flags,
+        metadata: derive_token_metadata_pda(proposal_key, program_id).0,
+        oracle_source: proposal.oracle_source,
+        reserved: [0u8; 29],
     };
 
     registry.serialize(&mut &mut registry_account.data.borrow_mut()[..])?;
+    oracle_feed.store(&mut registry_account.data.borrow_mut(), TOKEN_REGISTRY_FEED_OFFSET)?;
+
+    // 创建唯一性索引（Synthetic 资产没有 Mint）
+    if let Some(mint_index_account) = mint_index_account {
+        create_mint_index(
+            program_id,
+            payer,
+            mint_index_account,
+            system_program,
+            &proposal.mint,
+            token_index,
+        )?;
+    }
+    create_symbol_index(
+        program_id,
+        payer,
+        symbol_index_account,
+        system_program,
+        &proposal.symbol,
+        token_index,
+    )?;
 
-    // 更新提案状态
     proposal.status = ProposalStatus::Approved;
+
+    Ok(token_index)
+}
+
+fn process_approve_token(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+    
+    let reviewer = next_account_info(account_iter)?;
+    let proposal_account = next_account_info(account_iter)?;
+    let registry_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+    
+    // 加载配置并验证 Reviewer
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_role(reviewer, &config, Role::Reviewer, accounts)?;
+
+    // 加载提案
+    // Note: Use deserialize() instead of try_from_slice() because when oracle = None,
+    // the serialized data is shorter than the account size (Option<None> uses 1 byte vs 33).
+    let mut proposal = TokenProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+    
+    // 验证状态
+    if proposal.status != ProposalStatus::Pending {
+        return Err(ListingError::ProposalNotPending.into());
+    }
+
+    // 创建 Registry 账户（多签委员会审批时由额外的 Payer 支付租金）
+    let payer = resolve_payer(reviewer, account_iter)?;
+    let (mint_index_account, symbol_index_account) =
+        next_token_index_accounts(account_iter, proposal.asset_kind)?;
+    let current_ts = get_current_timestamp()?;
+    let token_index = register_approved_token(
+        program_id,
+        payer,
+        registry_account,
+        mint_index_account,
+        symbol_index_account,
+        system_program,
+        &mut config,
+        &mut proposal,
+        proposal_account,
+        current_ts,
+    )?;
+
+    // 更新提案状态
     proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
 
-    // 保存配置
+    // 保存配置（提案已离开待审核状态）
+    release_pending_proposal(&mut config);
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
     msg!("Token approved");
@@ -931,21 +1870,26 @@ fn process_approve_token(
 }
 
 fn process_reject_token(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     reason_code: u8,
-    slash_percentage: u8,
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
     
-    let admin = next_account_info(account_iter)?;
+    let reviewer = next_account_info(account_iter)?;
     let proposal_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
-    let _treasury_account = next_account_info(account_iter)?;
+    let treasury_account = next_account_info(account_iter)?;
+    let slash_schedule_account = next_account_info(account_iter)?;
 
-    // 加载配置并验证 Admin
-    let config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
-    verify_admin(admin, &config)?;
+    // 加载配置并验证 Reviewer
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_role(reviewer, &config, Role::Reviewer, accounts)?;
+
+    // 验证 Treasury
+    if treasury_account.key != &config.treasury {
+        return Err(ListingError::InvalidAccount.into());
+    }
 
     // 加载提案
     let mut proposal = TokenProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
@@ -955,28 +1899,50 @@ fn process_reject_token(
         return Err(ListingError::ProposalNotPending.into());
     }
 
-    // 验证罚没比例
-    if slash_percentage > 100 {
-        return Err(ListingError::InvalidAmount.into());
-    }
+    // 按拒绝原因查询罚没比例
+    let slash_schedule = load_slash_schedule(program_id, slash_schedule_account)?;
+    let slash_bps = slash_schedule.reason_slash_bps(reason_code)
+        .ok_or(ListingError::InvalidReasonCode)?;
 
-    // 更新状态
+    // 计算罚没金额（申诉窗口结束前留在托管中，剩余部分由 proposer 通过 ClaimRejectedStake 取回）
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
+        treasury_account,
+        proposal.version,
+        proposal.stake_mode,
+        account_iter,
+        false,
+    )?;
+    let (slash_amount, objector_reward) = settle_rejection(
+        &mut config,
+        &vault,
+        proposal.stake_amount,
+        proposal.objection_stake,
+        slash_bps,
+        true,
+    )?;
+    release_pending_proposal(&mut config);
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    // 更新状态（记录拒绝时间，开启申诉窗口）
     proposal.status = ProposalStatus::Rejected;
+    proposal.slash_amount = slash_amount;
+    proposal.objector_reward = objector_reward;
+    proposal.rejected_at = get_current_timestamp()?;
+    proposal.slash_pending = true;
     proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
-
-    // 计算罚没金额（保留在 treasury 中）
-    let slash_amount = proposal.stake_amount * slash_percentage as u64 / 100;
     
     msg!("Token rejected");
     msg!("Reason code: {}", reason_code);
-    msg!("Slash percentage: {}%", slash_percentage);
-    msg!("Slash amount: {} 1024", slash_amount / 1_000_000);
+    msg!("Slash: {} bps", slash_bps);
+    msg!("Slash amount: {} N1024", slash_amount / 1_000_000_000);
 
     Ok(())
 }
 
 fn process_cancel_token_proposal(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
@@ -985,6 +1951,7 @@ fn process_cancel_token_proposal(
     let proposal_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
     let treasury_account = next_account_info(account_iter)?;
+    let slash_schedule_account = next_account_info(account_iter)?;
 
     // 验证签名
     if !proposer.is_signer {
@@ -992,7 +1959,7 @@ fn process_cancel_token_proposal(
     }
 
     // 加载配置
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
 
     // 验证 Treasury
     if treasury_account.key != &config.treasury {
@@ -1015,28 +1982,43 @@ fn process_cancel_token_proposal(
     // 更新状态
     proposal.status = ProposalStatus::Cancelled;
     
-    // 计算退还金额（扣除 5%）
-    let refund = proposal.stake_amount * 95 / 100;
-    let slash = proposal.stake_amount - refund;
+    // 计算退还金额（按 SlashSchedule 扣除取消罚没）
+    let slash_schedule = load_slash_schedule(program_id, slash_schedule_account)?;
+    let slash = calculate_slash_amount(proposal.stake_amount, slash_schedule.cancel_slash_bps);
+    let refund = proposal.stake_amount - slash;
     
-    // 从 Treasury PDA 退还原生 N1024 (直接操作 lamports)
-    transfer_lamports_from_pda(
+    // 从提案托管退还质押，罚没部分转入 Treasury
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
         treasury_account,
+        proposal.version,
+        proposal.stake_mode,
+        account_iter,
+        true,
+    )?;
+    release_stake(
+        &mut config,
+        &vault,
         proposer,
         refund,
     )?;
-
-    // 更新总质押额（扣除退还部分）
-    config.total_staked_lamports = config.total_staked_lamports
-        .checked_sub(refund)
+    slash_stake(
+        &mut config,
+        &vault,
+        slash,
+    )?;
+    config.total_slashed_lamports = config.total_slashed_lamports
+        .checked_add(slash)
         .ok_or(ListingError::Overflow)?;
+    release_pending_proposal(&mut config);
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
     proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
 
     msg!("Token proposal cancelled");
-    msg!("Refund: {} N1024 (95%)", refund / 1_000_000_000);
-    msg!("Slashed: {} N1024 (5%)", slash / 1_000_000_000);
+    msg!("Refund: {} N1024", refund / 1_000_000_000);
+    msg!("Slashed: {} N1024 ({} bps)", slash / 1_000_000_000, slash_schedule.cancel_slash_bps);
 
     Ok(())
 }
@@ -1059,10 +2041,13 @@ fn process_finalize_token(
     }
 
     // 加载配置
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_not_paused(&config, PAUSE_FINALIZATION)?;
 
     // 加载提案
     let mut proposal = TokenProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+    let (mint_index_account, symbol_index_account) =
+        next_token_index_accounts(account_iter, proposal.asset_kind)?;
     
     // 验证状态
     if proposal.status != ProposalStatus::Pending {
@@ -1075,56 +2060,42 @@ fn process_finalize_token(
         return Err(ListingError::ReviewDeadlineNotReached.into());
     }
 
-    // 检查是否有反对
-    if proposal.objection_count > 0 {
+    // 检查是否有反对（有争议的提案需通过 ResolveProposal 投票决定）
+    if is_proposal_contested(
+        ProposalType::Token,
+        proposal.objection_count,
+        proposal.objection_stake,
+        proposal.stake_amount,
+    ) {
         msg!("Cannot auto-approve: {} objections pending", proposal.objection_count);
         return Err(ListingError::ProposalNotPending.into());
     }
 
-    // 分配 token_index
-    let token_index = config.total_tokens;
-    config.total_tokens = config.total_tokens.checked_add(1)
-        .ok_or(ListingError::Overflow)?;
-
-    // 验证 Registry PDA
-    let (expected_registry, bump) = derive_token_registry_pda(token_index, program_id);
-    if registry_account.key != &expected_registry {
-        return Err(ListingError::InvalidPda.into());
+    // 高风险 Token-2022 扩展必须由 Reviewer 批准
+    if requires_token_review(proposal.risk_flags) {
+        msg!("Cannot auto-approve: risk flags {:#04x}", proposal.risk_flags);
+        return Err(ListingError::ManualReviewRequired.into());
     }
 
     // 创建 Registry 账户
-    create_account(
+    let token_index = register_approved_token(
+        program_id,
         caller,
         registry_account,
-        TOKEN_REGISTRY_SIZE,
-        program_id,
+        mint_index_account,
+        symbol_index_account,
         system_program,
-        &[TOKEN_REGISTRY_SEED, &token_index.to_le_bytes(), &[bump]],
+        &mut config,
+        &mut proposal,
+        proposal_account,
+        current_ts,
     )?;
 
-    // 初始化 Registry
-    let registry = TokenRegistry {
-        discriminator: TokenRegistry::DISCRIMINATOR,
-        version: 1,
-        token_index,
-        symbol: proposal.symbol,
-        mint: proposal.mint,
-        decimals: proposal.decimals,
-        oracle: proposal.oracle,
-        is_active: true,
-        proposer: proposal.proposer,
-        approved_at: current_ts,
-        bump,
-        reserved: [0u8; 64],
-    };
-
-    registry.serialize(&mut &mut registry_account.data.borrow_mut()[..])?;
-
     // 更新提案状态
-    proposal.status = ProposalStatus::Approved;
     proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
 
-    // 保存配置
+    // 保存配置（提案已离开待审核状态）
+    release_pending_proposal(&mut config);
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
     msg!("Token auto-approved (finalized)");
@@ -1134,7 +2105,7 @@ fn process_finalize_token(
 }
 
 fn process_claim_token_stake(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
@@ -1151,7 +2122,7 @@ fn process_claim_token_stake(
     }
 
     // 加载配置
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
 
     // 验证 Treasury
     if treasury_account.key != &config.treasury {
@@ -1190,17 +2161,22 @@ fn process_claim_token_stake(
         return Err(ListingError::StakeLockPeriodNotEnded.into());
     }
 
-    // 从 Treasury PDA 退还原生 N1024
-    transfer_lamports_from_pda(
+    // 从提案托管退还质押
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
         treasury_account,
+        proposal.version,
+        proposal.stake_mode,
+        account_iter,
+        true,
+    )?;
+    release_stake(
+        &mut config,
+        &vault,
         proposer,
         proposal.stake_amount,
     )?;
-
-    // 更新总质押额
-    config.total_staked_lamports = config.total_staked_lamports
-        .checked_sub(proposal.stake_amount)
-        .ok_or(ListingError::Overflow)?;
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
     // 标记已取回
@@ -1214,19 +2190,19 @@ fn process_claim_token_stake(
 }
 
 fn process_update_token_status(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     is_active: bool,
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
     
-    let admin = next_account_info(account_iter)?;
+    let pauser = next_account_info(account_iter)?;
     let registry_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
 
-    // 加载配置并验证 Admin
-    let config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
-    verify_admin(admin, &config)?;
+    // 加载配置并验证 Pauser
+    let config = load_listing_config(program_id, config_account)?;
+    verify_role(pauser, &config, Role::Pauser, accounts)?;
 
     // 加载 Registry
     let mut registry = TokenRegistry::deserialize(&mut &registry_account.data.borrow()[..])?;
@@ -1240,10 +2216,82 @@ fn process_update_token_status(
     Ok(())
 }
 
+/// 为唯一性索引上线前注册的 Token 补建 MintIndex / SymbolIndex（任何人可调用，已存在的索引跳过）
+fn process_index_registered_token(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let payer = next_account_info(account_iter)?;
+    let registry_account = next_account_info(account_iter)?;
+
+    // 验证签名
+    if !payer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // 加载并验证 Registry
+    if registry_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let registry = TokenRegistry::deserialize(&mut &registry_account.data.borrow()[..])?;
+    if registry.discriminator != TokenRegistry::DISCRIMINATOR {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    let (mint_index_account, symbol_index_account) =
+        next_token_index_accounts(account_iter, registry.asset_kind)?;
+    let system_program = next_account_info(account_iter)?;
+
+    // 验证索引 PDA
+    if let Some(mint_index_account) = mint_index_account {
+        let (expected_mint_index, _) = derive_mint_index_pda(&registry.mint, program_id);
+        if mint_index_account.key != &expected_mint_index {
+            return Err(ListingError::InvalidPda.into());
+        }
+    }
+    let (expected_symbol_index, _) = derive_symbol_index_pda(&registry.symbol, program_id);
+    if symbol_index_account.key != &expected_symbol_index {
+        return Err(ListingError::InvalidPda.into());
+    }
+
+    // 补建缺失的索引（重复注册的旧 Token 只有先补建者占有索引）
+    if let Some(mint_index_account) = mint_index_account.filter(|account| account.data_is_empty()) {
+        create_mint_index(
+            program_id,
+            payer,
+            mint_index_account,
+            system_program,
+            &registry.mint,
+            registry.token_index,
+        )?;
+        msg!("Mint index created");
+    }
+    if symbol_index_account.data_is_empty() {
+        create_symbol_index(
+            program_id,
+            payer,
+            symbol_index_account,
+            system_program,
+            &registry.symbol,
+            registry.token_index,
+        )?;
+        msg!("Symbol index created");
+    }
+
+    msg!("Token index: {}", registry.token_index);
+    msg!("Symbol: {:?}", std::str::from_utf8(symbol_seed(&registry.symbol)).unwrap_or(""));
+    msg!("Mint: {}", registry.mint);
+
+    Ok(())
+}
+
 // =============================================================================
 // PLP-2: Spot 市场上架指令处理（占位）
 // =============================================================================
 
+#[allow(clippy::too_many_arguments)]
 fn process_propose_spot_market(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -1267,6 +2315,8 @@ fn process_propose_spot_market(
     let quote_token_registry = next_account_info(account_iter)?;
     let treasury_account = next_account_info(account_iter)?;
     let system_program = next_account_info(account_iter)?;
+    let stake_pricing_account = next_account_info(account_iter)?;
+    let (pair_index_account, current_market) = next_pair_accounts(account_iter)?;
 
     // 验证签名
     if !proposer.is_signer {
@@ -1274,12 +2324,10 @@ fn process_propose_spot_market(
     }
 
     // 加载配置
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
     
     // 检查是否暂停
-    if config.is_paused {
-        return Err(ListingError::ListingPaused.into());
-    }
+    verify_not_paused(&config, PAUSE_SPOT_PROPOSALS)?;
 
     // 验证 Treasury
     if treasury_account.key != &config.treasury {
@@ -1290,22 +2338,40 @@ fn process_propose_spot_market(
     validate_market_symbol(&symbol, true)?;
 
     // 验证 Base Token 已注册
-    let base_registry = TokenRegistry::deserialize(&mut &base_token_registry.data.borrow()[..])?;
+    let base_registry = load_token_registry(program_id, base_token_registry, base_token_index)?;
     if base_registry.token_index != base_token_index || !base_registry.is_active {
         return Err(ListingError::TokenNotRegistered.into());
     }
 
     // 验证 Quote Token 已注册
-    let quote_registry = TokenRegistry::deserialize(&mut &quote_token_registry.data.borrow()[..])?;
+    let quote_registry = load_token_registry(program_id, quote_token_registry, quote_token_index)?;
     if quote_registry.token_index != quote_token_index || !quote_registry.is_active {
         return Err(ListingError::TokenNotRegistered.into());
     }
 
+    // Synthetic 资产没有 Mint，不能用于 Spot 市场
+    if base_registry.asset_kind == AssetKind::Synthetic
+        || quote_registry.asset_kind == AssetKind::Synthetic
+    {
+        msg!("Synthetic assets can only be used as perp bases");
+        return Err(ListingError::SyntheticAssetNotAllowed.into());
+    }
+
     // 验证 Base 和 Quote 不同
     if base_token_index == quote_token_index {
         return Err(ListingError::SameTokenPair.into());
     }
 
+    // 交易对已有激活市场时拒绝（Admin 允许替换时除外）
+    verify_pair_available(
+        program_id,
+        MarketType::Spot,
+        base_token_index,
+        quote_token_index,
+        pair_index_account,
+        current_market,
+    )?;
+
     // 验证参数
     validate_sizes(tick_size_e6, lot_size_e6)?;
     validate_fee_rates(taker_fee_bps, maker_fee_bps)?;
@@ -1321,28 +2387,21 @@ fn process_propose_spot_market(
         return Err(ListingError::AlreadyInitialized.into());
     }
 
+    // 计算质押金额（动态定价启用时按待审提案数加价）
+    let base_stake = config.spot_stake_amount;
+    let stake_amount = price_proposal_stake(
+        program_id,
+        &mut config,
+        stake_pricing_account,
+        base_stake,
+    )?;
+
     // 检查余额
-    let stake_amount = config.spot_stake_amount;
-    if proposer.lamports() < stake_amount {
+    if config.stake_mode == StakeMode::Native && proposer.lamports() < stake_amount {
         msg!("Insufficient balance. Required: {} N1024", stake_amount / 1_000_000_000);
         return Err(ListingError::InsufficientStake.into());
     }
 
-    // 转移原生 N1024 质押
-    transfer_native_lamports(
-        proposer,
-        treasury_account,
-        stake_amount,
-        system_program,
-        None,
-    )?;
-
-    // 更新总质押
-    config.total_staked_lamports = config.total_staked_lamports
-        .checked_add(stake_amount)
-        .ok_or(ListingError::Overflow)?;
-    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
-
     // 创建 Proposal 账户
     create_account(
         proposer,
@@ -1358,13 +2417,25 @@ fn process_propose_spot_market(
         ],
     )?;
 
+    // 转移质押到提案托管
+    escrow_proposal_stake(
+        program_id,
+        proposer,
+        proposal_account,
+        treasury_account,
+        system_program,
+        account_iter,
+        config.stake_mode,
+        stake_amount,
+    )?;
+
     // 初始化数据
     let current_ts = get_current_timestamp()?;
     let review_deadline = current_ts + config.spot_review_period_seconds as i64;
 
     let proposal = SpotMarketProposal {
         discriminator: SpotMarketProposal::DISCRIMINATOR,
-        version: 1,
+        version: ESCROW_PROPOSAL_VERSION,
         proposer: *proposer.key,
         nonce,
         symbol,
@@ -1384,9 +2455,19 @@ fn process_propose_spot_market(
         objection_stake: 0,
         stake_claimed: false,
         bump,
-        reserved: [0u8; 64],
+        slash_amount: 0,
+        objector_reward: 0,
+        voting_deadline: review_deadline + config.voting_period_seconds as i64,
+        votes_for: 0,
+        votes_against: 0,
+        rejected_at: 0,
+        appeal_bond: 0,
+        stake_mode: config.stake_mode,
+        slash_pending: false,
+        reserved: [0u8; 6],
     };
 
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
     proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
 
     msg!("Spot market proposal created");
@@ -1399,7 +2480,7 @@ fn process_propose_spot_market(
 }
 
 fn process_object_spot_market(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     stake_amount: u64,
 ) -> ProgramResult {
@@ -1407,6 +2488,7 @@ fn process_object_spot_market(
     
     let objector = next_account_info(account_iter)?;
     let proposal_account = next_account_info(account_iter)?;
+    let objection_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
     let treasury_account = next_account_info(account_iter)?;
     let system_program = next_account_info(account_iter)?;
@@ -1417,7 +2499,7 @@ fn process_object_spot_market(
     }
 
     // 加载配置
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
 
     // 验证 Treasury
     if treasury_account.key != &config.treasury {
@@ -1438,30 +2520,53 @@ fn process_object_spot_market(
         return Err(ListingError::ReviewDeadlinePassed.into());
     }
 
+    // 验证金额
+    if stake_amount == 0 {
+        return Err(ListingError::InvalidAmount.into());
+    }
+
     // 检查余额
-    if objector.lamports() < stake_amount {
+    if proposal.stake_mode == StakeMode::Native && objector.lamports() < stake_amount {
         msg!("Insufficient balance for objection stake");
         return Err(ListingError::InsufficientStake.into());
     }
 
-    // 转移原生 N1024 反对质押
-    transfer_native_lamports(
-        objector,
+    // 转移反对质押到提案托管
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
         treasury_account,
-        stake_amount,
+        proposal.version,
+        proposal.stake_mode,
+        account_iter,
+        true,
+    )?;
+    deposit_stake(
+        &mut config,
+        &vault,
+        objector,
         system_program,
-        None,
+        stake_amount,
     )?;
-
-    // 更新总质押
-    config.total_staked_lamports = config.total_staked_lamports
-        .checked_add(stake_amount)
-        .ok_or(ListingError::Overflow)?;
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
-    // 更新反对信息
-    proposal.objection_count = proposal.objection_count.checked_add(1)
-        .ok_or(ListingError::Overflow)?;
+    // 记录反对者 Objection PDA
+    let is_new_objector = record_objection(
+        program_id,
+        objector,
+        objection_account,
+        proposal_account.key,
+        ProposalType::SpotMarket,
+        stake_amount,
+        system_program,
+        current_ts,
+    )?;
+
+    // 更新反对信息（每个反对者只计数一次）
+    if is_new_objector {
+        proposal.objection_count = proposal.objection_count.checked_add(1)
+            .ok_or(ListingError::Overflow)?;
+    }
     proposal.objection_stake = proposal.objection_stake.checked_add(stake_amount)
         .ok_or(ListingError::Overflow)?;
 
@@ -1475,30 +2580,192 @@ fn process_object_spot_market(
     Ok(())
 }
 
-fn process_approve_spot_market(
+/// 读取 PairIndex PDA 及其当前市场账户（仅 PairIndex 已初始化时传入）
+fn next_pair_accounts<'a, 'b, I: Iterator<Item = &'a AccountInfo<'b>>>(
+    account_iter: &mut I,
+) -> Result<(&'a AccountInfo<'b>, Option<&'a AccountInfo<'b>>), ProgramError> {
+    let pair_index_account = next_account_info(account_iter)?;
+    let current_market = if pair_index_account.data_is_empty() {
+        None
+    } else {
+        Some(next_account_info(account_iter)?)
+    };
+    Ok((pair_index_account, current_market))
+}
+
+/// 加载并验证 PairIndex（未初始化时返回 None）
+fn load_pair_index(
     program_id: &Pubkey,
-    accounts: &[AccountInfo],
-) -> ProgramResult {
-    let account_iter = &mut accounts.iter();
-    
-    let admin = next_account_info(account_iter)?;
-    let proposal_account = next_account_info(account_iter)?;
-    let market_account = next_account_info(account_iter)?;
-    let config_account = next_account_info(account_iter)?;
-    let system_program = next_account_info(account_iter)?;
+    market_type: MarketType,
+    base_token_index: u16,
+    quote_token_index: u16,
+    pair_index_account: &AccountInfo,
+) -> Result<Option<PairIndex>, ProgramError> {
+    let (expected_pair_index, _) =
+        derive_pair_index_pda(market_type, base_token_index, quote_token_index, program_id);
+    if pair_index_account.key != &expected_pair_index {
+        return Err(ListingError::InvalidPda.into());
+    }
+    if pair_index_account.data_is_empty() {
+        return Ok(None);
+    }
+    if pair_index_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let pair = PairIndex::try_from_slice(&pair_index_account.data.borrow())?;
+    if pair.discriminator != PairIndex::DISCRIMINATOR {
+        return Err(ListingError::InvalidAccount.into());
+    }
+    Ok(Some(pair))
+}
 
-    // 加载配置并验证 Admin
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
-    verify_admin(admin, &config)?;
+/// 返回交易对当前市场是否激活；deactivate = true 时同时将其停用
+fn update_pair_market(
+    program_id: &Pubkey,
+    pair: &PairIndex,
+    market_account: Option<&AccountInfo>,
+    deactivate: bool,
+) -> Result<bool, ProgramError> {
+    let market_account = market_account.ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if market_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    match pair.market_type {
+        MarketType::Spot => {
+            let (expected_market, _) = derive_spot_market_pda(pair.market_index, program_id);
+            if market_account.key != &expected_market {
+                return Err(ListingError::InvalidPda.into());
+            }
+            let mut market = SpotMarket::try_from_slice(&market_account.data.borrow())?;
+            let was_active = market.is_active;
+            if deactivate && was_active {
+                market.is_active = false;
+                market.serialize(&mut &mut market_account.data.borrow_mut()[..])?;
+            }
+            Ok(was_active)
+        }
+        MarketType::Perp => {
+            let (expected_market, _) = derive_perp_market_pda(pair.market_index, program_id);
+            if market_account.key != &expected_market {
+                return Err(ListingError::InvalidPda.into());
+            }
+            let mut market = PerpMarket::try_from_slice(&market_account.data.borrow())?;
+            let was_active = market.is_active;
+            if deactivate && was_active {
+                market.is_active = false;
+                market.serialize(&mut &mut market_account.data.borrow_mut()[..])?;
+            }
+            Ok(was_active)
+        }
+    }
+}
 
-    // 加载提案
-    let mut proposal = SpotMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
-    
-    // 验证状态
-    if proposal.status != ProposalStatus::Pending {
-        return Err(ListingError::ProposalNotPending.into());
+/// 检查交易对没有激活的市场（或 Admin 已允许替换）
+fn verify_pair_available(
+    program_id: &Pubkey,
+    market_type: MarketType,
+    base_token_index: u16,
+    quote_token_index: u16,
+    pair_index_account: &AccountInfo,
+    current_market: Option<&AccountInfo>,
+) -> ProgramResult {
+    let pair = load_pair_index(
+        program_id,
+        market_type,
+        base_token_index,
+        quote_token_index,
+        pair_index_account,
+    )?;
+    if let Some(pair) = pair {
+        if !pair.replacement_allowed && update_pair_market(program_id, &pair, current_market, false)? {
+            msg!("Pair already listed as market {}", pair.market_index);
+            return Err(ListingError::MarketAlreadyExists.into());
+        }
     }
+    Ok(())
+}
+
+/// 将 PairIndex 指向新批准的市场：首次上架时创建，Admin 允许替换时停用旧市场
+#[allow(clippy::too_many_arguments)]
+fn claim_pair_index<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    pair_index_account: &AccountInfo<'a>,
+    current_market: Option<&AccountInfo<'a>>,
+    system_program: &AccountInfo<'a>,
+    market_type: MarketType,
+    base_token_index: u16,
+    quote_token_index: u16,
+    market_index: u16,
+) -> ProgramResult {
+    let pair = load_pair_index(
+        program_id,
+        market_type,
+        base_token_index,
+        quote_token_index,
+        pair_index_account,
+    )?;
+    let pair = match pair {
+        Some(mut pair) => {
+            let was_active =
+                update_pair_market(program_id, &pair, current_market, pair.replacement_allowed)?;
+            if was_active && !pair.replacement_allowed {
+                msg!("Pair already listed as market {}", pair.market_index);
+                return Err(ListingError::MarketAlreadyExists.into());
+            }
+            if was_active {
+                msg!("Market {} replaced by {}", pair.market_index, market_index);
+            }
+            pair.market_index = market_index;
+            pair.replacement_allowed = false;
+            pair
+        }
+        None => {
+            let (_, bump) =
+                derive_pair_index_pda(market_type, base_token_index, quote_token_index, program_id);
+            create_account(
+                payer,
+                pair_index_account,
+                PAIR_INDEX_SIZE,
+                program_id,
+                system_program,
+                &[
+                    PAIR_INDEX_SEED,
+                    &[market_type as u8],
+                    &base_token_index.to_le_bytes(),
+                    &quote_token_index.to_le_bytes(),
+                    &[bump],
+                ],
+            )?;
+            PairIndex {
+                discriminator: PairIndex::DISCRIMINATOR,
+                market_type,
+                base_token_index,
+                quote_token_index,
+                market_index,
+                replacement_allowed: false,
+                bump,
+                reserved: [0u8; 16],
+            }
+        }
+    };
+    pair.serialize(&mut &mut pair_index_account.data.borrow_mut()[..])?;
+    Ok(())
+}
 
+/// 为批准的 Spot 提案创建 SpotMarket，返回 market_index
+#[allow(clippy::too_many_arguments)]
+fn list_approved_spot_market<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    market_account: &AccountInfo<'a>,
+    pair_index_account: &AccountInfo<'a>,
+    current_market: Option<&AccountInfo<'a>>,
+    system_program: &AccountInfo<'a>,
+    config: &mut ListingConfig,
+    proposal: &mut SpotMarketProposal,
+    current_ts: i64,
+) -> Result<u16, ProgramError> {
     // 分配 market_index
     let market_index = config.total_spot_markets;
     config.total_spot_markets = config.total_spot_markets.checked_add(1)
@@ -1512,7 +2779,7 @@ fn process_approve_spot_market(
 
     // 创建 Market 账户
     create_account(
-        admin,
+        payer,
         market_account,
         SPOT_MARKET_SIZE,
         program_id,
@@ -1521,7 +2788,6 @@ fn process_approve_spot_market(
     )?;
 
     // 初始化 Market
-    let current_ts = get_current_timestamp()?;
     let market = SpotMarket {
         discriminator: SpotMarket::DISCRIMINATOR,
         version: 1,
@@ -1545,11 +2811,69 @@ fn process_approve_spot_market(
 
     market.serialize(&mut &mut market_account.data.borrow_mut()[..])?;
 
-    // 更新提案状态
+    // 交易对索引指向新市场
+    claim_pair_index(
+        program_id,
+        payer,
+        pair_index_account,
+        current_market,
+        system_program,
+        MarketType::Spot,
+        proposal.base_token_index,
+        proposal.quote_token_index,
+        market_index,
+    )?;
+
     proposal.status = ProposalStatus::Approved;
+
+    Ok(market_index)
+}
+
+fn process_approve_spot_market(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+    
+    let reviewer = next_account_info(account_iter)?;
+    let proposal_account = next_account_info(account_iter)?;
+    let market_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    // 加载配置并验证 Reviewer
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_role(reviewer, &config, Role::Reviewer, accounts)?;
+
+    // 加载提案
+    let mut proposal = SpotMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+    
+    // 验证状态
+    if proposal.status != ProposalStatus::Pending {
+        return Err(ListingError::ProposalNotPending.into());
+    }
+
+    // 创建 Market 账户（多签委员会审批时由额外的 Payer 支付租金）
+    let payer = resolve_payer(reviewer, account_iter)?;
+    let (pair_index_account, current_market) = next_pair_accounts(account_iter)?;
+    let current_ts = get_current_timestamp()?;
+    let market_index = list_approved_spot_market(
+        program_id,
+        payer,
+        market_account,
+        pair_index_account,
+        current_market,
+        system_program,
+        &mut config,
+        &mut proposal,
+        current_ts,
+    )?;
+
+    // 更新提案状态
     proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
 
-    // 保存配置
+    // 保存配置（提案已离开待审核状态）
+    release_pending_proposal(&mut config);
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
     msg!("Spot market approved");
@@ -1560,21 +2884,26 @@ fn process_approve_spot_market(
 }
 
 fn process_reject_spot_market(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     reason_code: u8,
-    slash_percentage: u8,
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
     
-    let admin = next_account_info(account_iter)?;
+    let reviewer = next_account_info(account_iter)?;
     let proposal_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
-    let _treasury_account = next_account_info(account_iter)?;
+    let treasury_account = next_account_info(account_iter)?;
+    let slash_schedule_account = next_account_info(account_iter)?;
 
-    // 加载配置并验证 Admin
-    let config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
-    verify_admin(admin, &config)?;
+    // 加载配置并验证 Reviewer
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_role(reviewer, &config, Role::Reviewer, accounts)?;
+
+    // 验证 Treasury
+    if treasury_account.key != &config.treasury {
+        return Err(ListingError::InvalidAccount.into());
+    }
 
     // 加载提案
     let mut proposal = SpotMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
@@ -1584,28 +2913,50 @@ fn process_reject_spot_market(
         return Err(ListingError::ProposalNotPending.into());
     }
 
-    // 验证罚没比例
-    if slash_percentage > 100 {
-        return Err(ListingError::InvalidAmount.into());
-    }
+    // 按拒绝原因查询罚没比例
+    let slash_schedule = load_slash_schedule(program_id, slash_schedule_account)?;
+    let slash_bps = slash_schedule.reason_slash_bps(reason_code)
+        .ok_or(ListingError::InvalidReasonCode)?;
 
-    // 更新状态
+    // 计算罚没金额（申诉窗口结束前留在托管中，剩余部分由 proposer 通过 ClaimRejectedStake 取回）
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
+        treasury_account,
+        proposal.version,
+        proposal.stake_mode,
+        account_iter,
+        false,
+    )?;
+    let (slash_amount, objector_reward) = settle_rejection(
+        &mut config,
+        &vault,
+        proposal.stake_amount,
+        proposal.objection_stake,
+        slash_bps,
+        true,
+    )?;
+    release_pending_proposal(&mut config);
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    // 更新状态（记录拒绝时间，开启申诉窗口）
     proposal.status = ProposalStatus::Rejected;
+    proposal.slash_amount = slash_amount;
+    proposal.objector_reward = objector_reward;
+    proposal.rejected_at = get_current_timestamp()?;
+    proposal.slash_pending = true;
     proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
-
-    // 计算罚没金额（保留在 treasury 中）
-    let slash_amount = proposal.stake_amount * slash_percentage as u64 / 100;
     
     msg!("Spot market rejected");
     msg!("Reason code: {}", reason_code);
-    msg!("Slash percentage: {}%", slash_percentage);
+    msg!("Slash: {} bps", slash_bps);
     msg!("Slash amount: {} N1024", slash_amount / 1_000_000_000);
 
     Ok(())
 }
 
 fn process_cancel_spot_proposal(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
@@ -1614,6 +2965,7 @@ fn process_cancel_spot_proposal(
     let proposal_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
     let treasury_account = next_account_info(account_iter)?;
+    let slash_schedule_account = next_account_info(account_iter)?;
 
     // 验证签名
     if !proposer.is_signer {
@@ -1621,7 +2973,7 @@ fn process_cancel_spot_proposal(
     }
 
     // 加载配置
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
 
     // 验证 Treasury
     if treasury_account.key != &config.treasury {
@@ -1644,28 +2996,43 @@ fn process_cancel_spot_proposal(
     // 更新状态
     proposal.status = ProposalStatus::Cancelled;
     
-    // 计算退还金额（扣除 5%）
-    let refund = proposal.stake_amount * 95 / 100;
-    let slash = proposal.stake_amount - refund;
+    // 计算退还金额（按 SlashSchedule 扣除取消罚没）
+    let slash_schedule = load_slash_schedule(program_id, slash_schedule_account)?;
+    let slash = calculate_slash_amount(proposal.stake_amount, slash_schedule.cancel_slash_bps);
+    let refund = proposal.stake_amount - slash;
     
-    // 从 Treasury PDA 退还原生 N1024
-    transfer_lamports_from_pda(
-        treasury_account,
+    // 从提案托管退还质押，罚没部分转入 Treasury
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
+        treasury_account,
+        proposal.version,
+        proposal.stake_mode,
+        account_iter,
+        true,
+    )?;
+    release_stake(
+        &mut config,
+        &vault,
         proposer,
         refund,
     )?;
-
-    // 更新总质押额（扣除退还部分）
-    config.total_staked_lamports = config.total_staked_lamports
-        .checked_sub(refund)
+    slash_stake(
+        &mut config,
+        &vault,
+        slash,
+    )?;
+    config.total_slashed_lamports = config.total_slashed_lamports
+        .checked_add(slash)
         .ok_or(ListingError::Overflow)?;
+    release_pending_proposal(&mut config);
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
     proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
 
     msg!("Spot market proposal cancelled");
-    msg!("Refund: {} N1024 (95%)", refund / 1_000_000_000);
-    msg!("Slashed: {} N1024 (5%)", slash / 1_000_000_000);
+    msg!("Refund: {} N1024", refund / 1_000_000_000);
+    msg!("Slashed: {} N1024 ({} bps)", slash / 1_000_000_000, slash_schedule.cancel_slash_bps);
 
     Ok(())
 }
@@ -1681,6 +3048,7 @@ fn process_finalize_spot_market(
     let market_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
     let system_program = next_account_info(account_iter)?;
+    let (pair_index_account, current_market) = next_pair_accounts(account_iter)?;
 
     // 验证签名
     if !caller.is_signer {
@@ -1688,7 +3056,8 @@ fn process_finalize_spot_market(
     }
 
     // 加载配置
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_not_paused(&config, PAUSE_FINALIZATION)?;
 
     // 加载提案
     let mut proposal = SpotMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
@@ -1704,63 +3073,36 @@ fn process_finalize_spot_market(
         return Err(ListingError::ReviewDeadlineNotReached.into());
     }
 
-    // 检查是否有反对（如果反对数过多则不能自动批准）
-    if proposal.objection_count > 0 && proposal.objection_stake > proposal.stake_amount / 2 {
+    // 检查是否有反对（如果反对数过多则不能自动批准，需通过 ResolveProposal 投票决定）
+    if is_proposal_contested(
+        ProposalType::SpotMarket,
+        proposal.objection_count,
+        proposal.objection_stake,
+        proposal.stake_amount,
+    ) {
         msg!("Cannot auto-approve: significant objections (stake: {} vs proposal: {})", 
              proposal.objection_stake, proposal.stake_amount);
         return Err(ListingError::ProposalNotPending.into());
     }
 
-    // 分配 market_index
-    let market_index = config.total_spot_markets;
-    config.total_spot_markets = config.total_spot_markets.checked_add(1)
-        .ok_or(ListingError::Overflow)?;
-
-    // 验证 Market PDA
-    let (expected_market, bump) = derive_spot_market_pda(market_index, program_id);
-    if market_account.key != &expected_market {
-        return Err(ListingError::InvalidPda.into());
-    }
-
     // 创建 Market 账户
-    create_account(
+    let market_index = list_approved_spot_market(
+        program_id,
         caller,
         market_account,
-        SPOT_MARKET_SIZE,
-        program_id,
+        pair_index_account,
+        current_market,
         system_program,
-        &[SPOT_MARKET_SEED, &market_index.to_le_bytes(), &[bump]],
+        &mut config,
+        &mut proposal,
+        current_ts,
     )?;
 
-    // 初始化 Market
-    let market = SpotMarket {
-        discriminator: SpotMarket::DISCRIMINATOR,
-        version: 1,
-        market_index,
-        symbol: proposal.symbol,
-        base_token_index: proposal.base_token_index,
-        quote_token_index: proposal.quote_token_index,
-        tick_size_e6: proposal.tick_size_e6,
-        lot_size_e6: proposal.lot_size_e6,
-        taker_fee_bps: proposal.taker_fee_bps,
-        maker_fee_bps: proposal.maker_fee_bps,
-        min_order_size_e6: proposal.min_order_size_e6,
-        max_order_size_e6: proposal.max_order_size_e6,
-        is_active: true,
-        is_paused: false,
-        proposer: proposal.proposer,
-        approved_at: current_ts,
-        bump,
-        reserved: [0u8; 64],
-    };
-
-    market.serialize(&mut &mut market_account.data.borrow_mut()[..])?;
-
     // 更新提案状态
-    proposal.status = ProposalStatus::Approved;
     proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
 
-    // 保存配置
+    // 保存配置（提案已离开待审核状态）
+    release_pending_proposal(&mut config);
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
     msg!("Spot market auto-approved (finalized)");
@@ -1788,7 +3130,7 @@ fn process_claim_spot_stake(
     }
 
     // 加载配置
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
 
     // 验证 Treasury
     if treasury_account.key != &config.treasury {
@@ -1827,17 +3169,22 @@ fn process_claim_spot_stake(
         return Err(ListingError::StakeLockPeriodNotEnded.into());
     }
 
-    // 从 Treasury PDA 退还原生 N1024
-    transfer_lamports_from_pda(
+    // 从提案托管退还质押
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
         treasury_account,
+        proposal.version,
+        proposal.stake_mode,
+        account_iter,
+        true,
+    )?;
+    release_stake(
+        &mut config,
+        &vault,
         proposer,
         proposal.stake_amount,
     )?;
-
-    // 更新总质押额
-    config.total_staked_lamports = config.total_staked_lamports
-        .checked_sub(proposal.stake_amount)
-        .ok_or(ListingError::Overflow)?;
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
     // 标记已取回
@@ -1851,20 +3198,20 @@ fn process_claim_spot_stake(
 }
 
 fn process_update_spot_status(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     is_active: Option<bool>,
     is_paused: Option<bool>,
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
     
-    let admin = next_account_info(account_iter)?;
+    let pauser = next_account_info(account_iter)?;
     let market_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
 
-    // 加载配置并验证 Admin
-    let config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
-    verify_admin(admin, &config)?;
+    // 加载配置并验证 Pauser
+    let config = load_listing_config(program_id, config_account)?;
+    verify_role(pauser, &config, Role::Pauser, accounts)?;
 
     // 加载 Market
     let mut market = SpotMarket::try_from_slice(&market_account.data.borrow())?;
@@ -1888,7 +3235,7 @@ fn process_update_spot_status(
 }
 
 fn process_update_spot_params(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     taker_fee_bps: Option<u16>,
     maker_fee_bps: Option<i16>,
@@ -1897,13 +3244,13 @@ fn process_update_spot_params(
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
     
-    let admin = next_account_info(account_iter)?;
+    let risk_manager = next_account_info(account_iter)?;
     let market_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
 
-    // 加载配置并验证 Admin
-    let config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
-    verify_admin(admin, &config)?;
+    // 加载配置并验证 RiskManager
+    let config = load_listing_config(program_id, config_account)?;
+    verify_role(risk_manager, &config, Role::RiskManager, accounts)?;
 
     // 加载 Market
     let mut market = SpotMarket::try_from_slice(&market_account.data.borrow())?;
@@ -1917,7 +3264,7 @@ fn process_update_spot_params(
         msg!("Taker fee updated to: {} bps", fee);
     }
     if let Some(fee) = maker_fee_bps {
-        if fee < -500 || fee > 500 {
+        if !(-500..=500).contains(&fee) {
             return Err(ListingError::InvalidFeeRate.into());
         }
         market.maker_fee_bps = fee;
@@ -1944,6 +3291,7 @@ fn process_update_spot_params(
 // PLP-3: Perp 市场上架指令处理（占位）
 // =============================================================================
 
+#[allow(clippy::too_many_arguments)]
 fn process_propose_perp_market(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -1974,6 +3322,8 @@ fn process_propose_perp_market(
     let oracle_account = next_account_info(account_iter)?;
     let treasury_account = next_account_info(account_iter)?;
     let system_program = next_account_info(account_iter)?;
+    let stake_pricing_account = next_account_info(account_iter)?;
+    let (pair_index_account, current_market) = next_pair_accounts(account_iter)?;
 
     // 验证签名
     if !proposer.is_signer {
@@ -1981,12 +3331,10 @@ fn process_propose_perp_market(
     }
 
     // 加载配置
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
     
     // 检查是否暂停
-    if config.is_paused {
-        return Err(ListingError::ListingPaused.into());
-    }
+    verify_not_paused(&config, PAUSE_PERP_PROPOSALS)?;
 
     // 验证 Treasury
     if treasury_account.key != &config.treasury {
@@ -1997,29 +3345,45 @@ fn process_propose_perp_market(
     validate_market_symbol(&symbol, false)?;
 
     // 验证 Base Token 已注册
-    let base_registry = TokenRegistry::deserialize(&mut &base_token_registry.data.borrow()[..])?;
+    let base_registry = load_token_registry(program_id, base_token_registry, base_token_index)?;
     if base_registry.token_index != base_token_index || !base_registry.is_active {
         return Err(ListingError::TokenNotRegistered.into());
     }
 
     // 验证 Quote Token 已注册
-    let quote_registry = TokenRegistry::deserialize(&mut &quote_token_registry.data.borrow()[..])?;
+    let quote_registry = load_token_registry(program_id, quote_token_registry, quote_token_index)?;
     if quote_registry.token_index != quote_token_index || !quote_registry.is_active {
         return Err(ListingError::TokenNotRegistered.into());
     }
 
+    // Synthetic 资产只能作为 Base
+    if quote_registry.asset_kind == AssetKind::Synthetic {
+        msg!("Synthetic assets can only be used as perp bases");
+        return Err(ListingError::SyntheticAssetNotAllowed.into());
+    }
+
     // 验证 Base 和 Quote 不同
     if base_token_index == quote_token_index {
         return Err(ListingError::SameTokenPair.into());
     }
 
+    // 交易对已有激活市场时拒绝（Admin 允许替换时除外）
+    verify_pair_available(
+        program_id,
+        MarketType::Perp,
+        base_token_index,
+        quote_token_index,
+        pair_index_account,
+        current_market,
+    )?;
+
     // 验证 Oracle
     if oracle_account.key != &oracle {
         return Err(ListingError::InvalidOracle.into());
     }
     
-    // Pyth Oracle 验证
-    validate_oracle_exists(oracle_account)?;
+    // 验证 Oracle 账户，识别数据源并记录喂价 ID
+    let (oracle_source, oracle_feed) = validate_oracle_exists(oracle_account, program_id)?;
 
     // 验证参数
     validate_sizes(tick_size_e6, lot_size_e6)?;
@@ -2038,33 +3402,26 @@ fn process_propose_perp_market(
         return Err(ListingError::AlreadyInitialized.into());
     }
 
+    // 计算质押金额（动态定价启用时按待审提案数加价）
+    let base_stake = config.perp_stake_amount;
+    let stake_amount = price_proposal_stake(
+        program_id,
+        &mut config,
+        stake_pricing_account,
+        base_stake,
+    )?;
+
     // 检查余额
-    let stake_amount = config.perp_stake_amount;
-    if proposer.lamports() < stake_amount {
+    if config.stake_mode == StakeMode::Native && proposer.lamports() < stake_amount {
         msg!("Insufficient balance. Required: {} N1024", stake_amount / 1_000_000_000);
         return Err(ListingError::InsufficientStake.into());
     }
 
-    // 转移原生 N1024 质押
-    transfer_native_lamports(
-        proposer,
-        treasury_account,
-        stake_amount,
-        system_program,
-        None,
-    )?;
-
-    // 更新总质押
-    config.total_staked_lamports = config.total_staked_lamports
-        .checked_add(stake_amount)
-        .ok_or(ListingError::Overflow)?;
-    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
-
-    // 创建 Proposal 账户
+    // 创建 Proposal 账户（尾部记录 Oracle 喂价 ID）
     create_account(
         proposer,
         proposal_account,
-        PERP_PROPOSAL_SIZE,
+        PERP_PROPOSAL_ACCOUNT_SIZE,
         program_id,
         system_program,
         &[
@@ -2075,13 +3432,25 @@ fn process_propose_perp_market(
         ],
     )?;
 
+    // 转移质押到提案托管
+    escrow_proposal_stake(
+        program_id,
+        proposer,
+        proposal_account,
+        treasury_account,
+        system_program,
+        account_iter,
+        config.stake_mode,
+        stake_amount,
+    )?;
+
     // 初始化数据
     let current_ts = get_current_timestamp()?;
     let review_deadline = current_ts + config.perp_review_period_seconds as i64;
 
     let proposal = PerpMarketProposal {
         discriminator: PerpMarketProposal::DISCRIMINATOR,
-        version: 1,
+        version: ESCROW_PROPOSAL_VERSION,
         proposer: *proposer.key,
         nonce,
         symbol,
@@ -2107,10 +3476,22 @@ fn process_propose_perp_market(
         objection_stake: 0,
         stake_claimed: false,
         bump,
-        reserved: [0u8; 64],
+        slash_amount: 0,
+        objector_reward: 0,
+        voting_deadline: review_deadline + config.voting_period_seconds as i64,
+        votes_for: 0,
+        votes_against: 0,
+        rejected_at: 0,
+        appeal_bond: 0,
+        stake_mode: config.stake_mode,
+        oracle_source,
+        slash_pending: false,
+        reserved: [0u8; 5],
     };
 
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
     proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+    oracle_feed.store(&mut proposal_account.data.borrow_mut(), PERP_PROPOSAL_SIZE)?;
 
     msg!("Perp market proposal created");
     msg!("Proposer: {}", proposer.key);
@@ -2123,7 +3504,7 @@ fn process_propose_perp_market(
 }
 
 fn process_object_perp_market(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     stake_amount: u64,
 ) -> ProgramResult {
@@ -2131,6 +3512,7 @@ fn process_object_perp_market(
     
     let objector = next_account_info(account_iter)?;
     let proposal_account = next_account_info(account_iter)?;
+    let objection_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
     let treasury_account = next_account_info(account_iter)?;
     let system_program = next_account_info(account_iter)?;
@@ -2141,7 +3523,7 @@ fn process_object_perp_market(
     }
 
     // 加载配置
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
 
     // 验证 Treasury
     if treasury_account.key != &config.treasury {
@@ -2162,30 +3544,53 @@ fn process_object_perp_market(
         return Err(ListingError::ReviewDeadlinePassed.into());
     }
 
+    // 验证金额
+    if stake_amount == 0 {
+        return Err(ListingError::InvalidAmount.into());
+    }
+
     // 检查余额
-    if objector.lamports() < stake_amount {
+    if proposal.stake_mode == StakeMode::Native && objector.lamports() < stake_amount {
         msg!("Insufficient balance for objection stake");
         return Err(ListingError::InsufficientStake.into());
     }
 
-    // 转移原生 N1024 反对质押
-    transfer_native_lamports(
-        objector,
+    // 转移反对质押到提案托管
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
         treasury_account,
-        stake_amount,
+        proposal.version,
+        proposal.stake_mode,
+        account_iter,
+        true,
+    )?;
+    deposit_stake(
+        &mut config,
+        &vault,
+        objector,
         system_program,
-        None,
+        stake_amount,
     )?;
-
-    // 更新总质押
-    config.total_staked_lamports = config.total_staked_lamports
-        .checked_add(stake_amount)
-        .ok_or(ListingError::Overflow)?;
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
-    // 更新反对信息
-    proposal.objection_count = proposal.objection_count.checked_add(1)
-        .ok_or(ListingError::Overflow)?;
+    // 记录反对者 Objection PDA
+    let is_new_objector = record_objection(
+        program_id,
+        objector,
+        objection_account,
+        proposal_account.key,
+        ProposalType::PerpMarket,
+        stake_amount,
+        system_program,
+        current_ts,
+    )?;
+
+    // 更新反对信息（每个反对者只计数一次）
+    if is_new_objector {
+        proposal.objection_count = proposal.objection_count.checked_add(1)
+            .ok_or(ListingError::Overflow)?;
+    }
     proposal.objection_stake = proposal.objection_stake.checked_add(stake_amount)
         .ok_or(ListingError::Overflow)?;
 
@@ -2199,30 +3604,70 @@ fn process_object_perp_market(
     Ok(())
 }
 
-fn process_approve_perp_market(
-    program_id: &Pubkey,
-    accounts: &[AccountInfo],
-) -> ProgramResult {
-    let account_iter = &mut accounts.iter();
-    
-    let admin = next_account_info(account_iter)?;
-    let proposal_account = next_account_info(account_iter)?;
-    let market_account = next_account_info(account_iter)?;
-    let config_account = next_account_info(account_iter)?;
-    let system_program = next_account_info(account_iter)?;
+/// 永续市场上线时读取价格所需的 Oracle 账户
+struct PerpOracleAccounts<'r, 'a> {
+    /// 提案 Oracle 账户（PerpMarketProposal.oracle）
+    oracle: &'r AccountInfo<'a>,
+    /// 基础 Token 的 Registry（提供 OracleRiskConfig）
+    base_registry: &'r AccountInfo<'a>,
+    /// 备用 Oracle 账户（仅 Registry 配置了备用 Oracle 时）
+    fallback: Option<&'r AccountInfo<'a>>,
+}
 
-    // 验证 Admin
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
-    verify_admin(admin, &config)?;
+/// 读取 Oracle 账户、基础 Token Registry 与备用 Oracle（Registry 未配置时不传）
+fn next_perp_oracle_accounts<'r, 'a, I: Iterator<Item = &'r AccountInfo<'a>>>(
+    account_iter: &mut I,
+) -> Result<PerpOracleAccounts<'r, 'a>, ProgramError> {
+    let oracle = next_account_info(account_iter)?;
+    let base_registry = next_account_info(account_iter)?;
+    let fallback = if OracleRiskConfig::load(&base_registry.data.borrow()).has_fallback() {
+        Some(next_account_info(account_iter)?)
+    } else {
+        None
+    };
+    Ok(PerpOracleAccounts { oracle, base_registry, fallback })
+}
 
-    // 加载提案
-    let mut proposal = PerpMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
-    
-    // 验证状态
-    if proposal.status != ProposalStatus::Pending {
-        return Err(ListingError::ProposalNotPending.into());
+/// 为批准的 Perp 提案创建 PerpMarket，返回 market_index
+///
+/// 上线时要求 Oracle 价格有效且新鲜，并记录为市场参考价。阈值和备用 Oracle
+/// 取自基础 Token 的 `OracleRiskConfig`，新市场沿用该配置
+#[allow(clippy::too_many_arguments)]
+fn list_approved_perp_market<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    market_account: &AccountInfo<'a>,
+    pair_index_account: &AccountInfo<'a>,
+    current_market: Option<&AccountInfo<'a>>,
+    oracles: &PerpOracleAccounts,
+    system_program: &AccountInfo<'a>,
+    config: &mut ListingConfig,
+    proposal: &mut PerpMarketProposal,
+    proposal_account: &AccountInfo<'a>,
+    current_ts: i64,
+) -> Result<u16, ProgramError> {
+    if oracles.oracle.key != &proposal.oracle {
+        msg!("Oracle account mismatch: expected {}", proposal.oracle);
+        return Err(ListingError::InvalidOracle.into());
     }
 
+    // 加载基础 Token 的 Oracle 风险配置
+    load_token_registry(program_id, oracles.base_registry, proposal.base_token_index)?;
+    let oracle_config =
+        perp_listing_oracle_config(&oracles.base_registry.data.borrow(), &proposal.oracle);
+
+    // 验证 Oracle 实时价格（主 Oracle 与提案记录的喂价 ID 比对，无效时回退到备用 Oracle）
+    let oracle_feed = OracleFeed::load(&proposal_account.data.borrow(), PERP_PROPOSAL_SIZE);
+    let reference = validate_oracle_with_fallback(
+        oracles.oracle,
+        oracles.fallback,
+        proposal.oracle_source,
+        &oracle_feed,
+        &oracle_config,
+        program_id,
+        current_ts,
+    )?;
+
     // 分配 market_index
     let market_index = config.total_perp_markets;
     config.total_perp_markets = config.total_perp_markets.checked_add(1)
@@ -2236,7 +3681,7 @@ fn process_approve_perp_market(
 
     // 创建 Market 账户
     create_account(
-        admin,
+        payer,
         market_account,
         PERP_MARKET_SIZE,
         program_id,
@@ -2245,7 +3690,6 @@ fn process_approve_perp_market(
     )?;
 
     // 初始化 Market
-    let current_ts = get_current_timestamp()?;
     let market = PerpMarket {
         discriminator: PerpMarket::DISCRIMINATOR,
         version: 1,
@@ -2274,16 +3718,81 @@ fn process_approve_perp_market(
         proposer: proposal.proposer,
         approved_at: current_ts,
         bump,
-        reserved: [0u8; 64],
+        oracle_source: proposal.oracle_source,
+        oracle_config,
+        reference_price: reference.price,
+        reference_expo: reference.expo,
+        reserved: [0u8; 12],
     };
 
     market.serialize(&mut &mut market_account.data.borrow_mut()[..])?;
 
-    // 更新提案状态
+    // 交易对索引指向新市场
+    claim_pair_index(
+        program_id,
+        payer,
+        pair_index_account,
+        current_market,
+        system_program,
+        MarketType::Perp,
+        proposal.base_token_index,
+        proposal.quote_token_index,
+        market_index,
+    )?;
+
     proposal.status = ProposalStatus::Approved;
+
+    Ok(market_index)
+}
+
+fn process_approve_perp_market(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+    
+    let reviewer = next_account_info(account_iter)?;
+    let proposal_account = next_account_info(account_iter)?;
+    let market_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    // 验证 Reviewer
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_role(reviewer, &config, Role::Reviewer, accounts)?;
+
+    // 加载提案
+    let mut proposal = PerpMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+    
+    // 验证状态
+    if proposal.status != ProposalStatus::Pending {
+        return Err(ListingError::ProposalNotPending.into());
+    }
+
+    // 创建 Market 账户（多签委员会审批时由额外的 Payer 支付租金）
+    let payer = resolve_payer(reviewer, account_iter)?;
+    let (pair_index_account, current_market) = next_pair_accounts(account_iter)?;
+    let oracles = next_perp_oracle_accounts(account_iter)?;
+    let current_ts = get_current_timestamp()?;
+    let market_index = list_approved_perp_market(
+        program_id,
+        payer,
+        market_account,
+        pair_index_account,
+        current_market,
+        &oracles,
+        system_program,
+        &mut config,
+        &mut proposal,
+        proposal_account,
+        current_ts,
+    )?;
+
+    // 更新提案状态
     proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
 
-    // 保存配置
+    // 保存配置（提案已离开待审核状态）
+    release_pending_proposal(&mut config);
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
     msg!("Perp market approved");
@@ -2295,21 +3804,26 @@ fn process_approve_perp_market(
 }
 
 fn process_reject_perp_market(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     reason_code: u8,
-    slash_percentage: u8,
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
     
-    let admin = next_account_info(account_iter)?;
+    let reviewer = next_account_info(account_iter)?;
     let proposal_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
-    let _treasury_account = next_account_info(account_iter)?;
+    let treasury_account = next_account_info(account_iter)?;
+    let slash_schedule_account = next_account_info(account_iter)?;
 
-    // 加载配置并验证 Admin
-    let config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
-    verify_admin(admin, &config)?;
+    // 加载配置并验证 Reviewer
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_role(reviewer, &config, Role::Reviewer, accounts)?;
+
+    // 验证 Treasury
+    if treasury_account.key != &config.treasury {
+        return Err(ListingError::InvalidAccount.into());
+    }
 
     // 加载提案
     let mut proposal = PerpMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
@@ -2319,28 +3833,50 @@ fn process_reject_perp_market(
         return Err(ListingError::ProposalNotPending.into());
     }
 
-    // 验证罚没比例
-    if slash_percentage > 100 {
-        return Err(ListingError::InvalidAmount.into());
-    }
+    // 按拒绝原因查询罚没比例
+    let slash_schedule = load_slash_schedule(program_id, slash_schedule_account)?;
+    let slash_bps = slash_schedule.reason_slash_bps(reason_code)
+        .ok_or(ListingError::InvalidReasonCode)?;
 
-    // 更新状态
+    // 计算罚没金额（申诉窗口结束前留在托管中，剩余部分由 proposer 通过 ClaimRejectedStake 取回）
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
+        treasury_account,
+        proposal.version,
+        proposal.stake_mode,
+        account_iter,
+        false,
+    )?;
+    let (slash_amount, objector_reward) = settle_rejection(
+        &mut config,
+        &vault,
+        proposal.stake_amount,
+        proposal.objection_stake,
+        slash_bps,
+        true,
+    )?;
+    release_pending_proposal(&mut config);
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    // 更新状态（记录拒绝时间，开启申诉窗口）
     proposal.status = ProposalStatus::Rejected;
+    proposal.slash_amount = slash_amount;
+    proposal.objector_reward = objector_reward;
+    proposal.rejected_at = get_current_timestamp()?;
+    proposal.slash_pending = true;
     proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
-
-    // 计算罚没金额（保留在 treasury 中）
-    let slash_amount = proposal.stake_amount * slash_percentage as u64 / 100;
     
     msg!("Perp market rejected");
     msg!("Reason code: {}", reason_code);
-    msg!("Slash percentage: {}%", slash_percentage);
+    msg!("Slash: {} bps", slash_bps);
     msg!("Slash amount: {} N1024", slash_amount / 1_000_000_000);
 
     Ok(())
 }
 
 fn process_cancel_perp_proposal(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
@@ -2349,6 +3885,7 @@ fn process_cancel_perp_proposal(
     let proposal_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
     let treasury_account = next_account_info(account_iter)?;
+    let slash_schedule_account = next_account_info(account_iter)?;
 
     // 验证签名
     if !proposer.is_signer {
@@ -2356,7 +3893,7 @@ fn process_cancel_perp_proposal(
     }
 
     // 加载配置
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
 
     // 验证 Treasury
     if treasury_account.key != &config.treasury {
@@ -2379,28 +3916,43 @@ fn process_cancel_perp_proposal(
     // 更新状态
     proposal.status = ProposalStatus::Cancelled;
     
-    // 计算退还金额（扣除 5%）
-    let refund = proposal.stake_amount * 95 / 100;
-    let slash = proposal.stake_amount - refund;
+    // 计算退还金额（按 SlashSchedule 扣除取消罚没）
+    let slash_schedule = load_slash_schedule(program_id, slash_schedule_account)?;
+    let slash = calculate_slash_amount(proposal.stake_amount, slash_schedule.cancel_slash_bps);
+    let refund = proposal.stake_amount - slash;
     
-    // 从 Treasury PDA 退还原生 N1024
-    transfer_lamports_from_pda(
+    // 从提案托管退还质押，罚没部分转入 Treasury
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
         treasury_account,
+        proposal.version,
+        proposal.stake_mode,
+        account_iter,
+        true,
+    )?;
+    release_stake(
+        &mut config,
+        &vault,
         proposer,
         refund,
     )?;
-
-    // 更新总质押额（扣除退还部分）
-    config.total_staked_lamports = config.total_staked_lamports
-        .checked_sub(refund)
+    slash_stake(
+        &mut config,
+        &vault,
+        slash,
+    )?;
+    config.total_slashed_lamports = config.total_slashed_lamports
+        .checked_add(slash)
         .ok_or(ListingError::Overflow)?;
+    release_pending_proposal(&mut config);
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
     proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
 
     msg!("Perp market proposal cancelled");
-    msg!("Refund: {} N1024 (95%)", refund / 1_000_000_000);
-    msg!("Slashed: {} N1024 (5%)", slash / 1_000_000_000);
+    msg!("Refund: {} N1024", refund / 1_000_000_000);
+    msg!("Slashed: {} N1024 ({} bps)", slash / 1_000_000_000, slash_schedule.cancel_slash_bps);
 
     Ok(())
 }
@@ -2416,6 +3968,8 @@ fn process_finalize_perp_market(
     let market_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
     let system_program = next_account_info(account_iter)?;
+    let (pair_index_account, current_market) = next_pair_accounts(account_iter)?;
+    let oracles = next_perp_oracle_accounts(account_iter)?;
 
     // 验证签名
     if !caller.is_signer {
@@ -2423,7 +3977,8 @@ fn process_finalize_perp_market(
     }
 
     // 加载配置
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_not_paused(&config, PAUSE_FINALIZATION)?;
 
     // 加载提案
     let mut proposal = PerpMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
@@ -2439,73 +3994,38 @@ fn process_finalize_perp_market(
         return Err(ListingError::ReviewDeadlineNotReached.into());
     }
 
-    // 检查是否有重大反对（如果反对质押超过提案质押的 50%）
-    if proposal.objection_count > 0 && proposal.objection_stake > proposal.stake_amount / 2 {
+    // 检查是否有重大反对（如果反对质押超过提案质押的 50%，需通过 ResolveProposal 投票决定）
+    if is_proposal_contested(
+        ProposalType::PerpMarket,
+        proposal.objection_count,
+        proposal.objection_stake,
+        proposal.stake_amount,
+    ) {
         msg!("Cannot auto-approve: significant objections (stake: {} vs proposal: {})", 
              proposal.objection_stake, proposal.stake_amount);
         return Err(ListingError::ProposalNotPending.into());
     }
 
-    // 分配 market_index
-    let market_index = config.total_perp_markets;
-    config.total_perp_markets = config.total_perp_markets.checked_add(1)
-        .ok_or(ListingError::Overflow)?;
-
-    // 验证 PDA
-    let (expected_market, bump) = derive_perp_market_pda(market_index, program_id);
-    if market_account.key != &expected_market {
-        return Err(ListingError::InvalidPda.into());
-    }
-
     // 创建 Market 账户
-    create_account(
+    let market_index = list_approved_perp_market(
+        program_id,
         caller,
         market_account,
-        PERP_MARKET_SIZE,
-        program_id,
+        pair_index_account,
+        current_market,
+        &oracles,
         system_program,
-        &[PERP_MARKET_SEED, &market_index.to_le_bytes(), &[bump]],
+        &mut config,
+        &mut proposal,
+        proposal_account,
+        current_ts,
     )?;
 
-    // 初始化 Market
-    let market = PerpMarket {
-        discriminator: PerpMarket::DISCRIMINATOR,
-        version: 1,
-        market_index,
-        symbol: proposal.symbol,
-        base_token_index: proposal.base_token_index,
-        quote_token_index: proposal.quote_token_index,
-        oracle: proposal.oracle,
-        tick_size_e6: proposal.tick_size_e6,
-        lot_size_e6: proposal.lot_size_e6,
-        max_leverage: proposal.max_leverage,
-        initial_margin_rate_e6: proposal.initial_margin_rate_e6,
-        maintenance_margin_rate_e6: proposal.maintenance_margin_rate_e6,
-        taker_fee_bps: proposal.taker_fee_bps,
-        maker_fee_bps: proposal.maker_fee_bps,
-        min_order_size_e6: proposal.min_order_size_e6,
-        max_order_size_e6: proposal.max_order_size_e6,
-        max_open_interest_e6: proposal.max_open_interest_e6,
-        current_open_interest_long_e6: 0,
-        current_open_interest_short_e6: 0,
-        insurance_fund_deposit_e6: proposal.insurance_fund_deposit_e6,
-        funding_rate_e9: 0,
-        last_funding_ts: current_ts,
-        is_active: true,
-        is_paused: false,
-        proposer: proposal.proposer,
-        approved_at: current_ts,
-        bump,
-        reserved: [0u8; 64],
-    };
-
-    market.serialize(&mut &mut market_account.data.borrow_mut()[..])?;
-
     // 更新提案状态
-    proposal.status = ProposalStatus::Approved;
     proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
 
-    // 保存配置
+    // 保存配置（提案已离开待审核状态）
+    release_pending_proposal(&mut config);
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
     msg!("Perp market auto-approved (finalized)");
@@ -2534,7 +4054,7 @@ fn process_claim_perp_stake(
     }
 
     // 加载配置
-    let mut config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
+    let mut config = load_listing_config(program_id, config_account)?;
 
     // 验证 Treasury
     if treasury_account.key != &config.treasury {
@@ -2573,17 +4093,22 @@ fn process_claim_perp_stake(
         return Err(ListingError::StakeLockPeriodNotEnded.into());
     }
 
-    // 从 Treasury PDA 退还原生 N1024
-    transfer_lamports_from_pda(
+    // 从提案托管退还质押
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
         treasury_account,
+        proposal.version,
+        proposal.stake_mode,
+        account_iter,
+        true,
+    )?;
+    release_stake(
+        &mut config,
+        &vault,
         proposer,
         proposal.stake_amount,
     )?;
-
-    // 更新总质押额
-    config.total_staked_lamports = config.total_staked_lamports
-        .checked_sub(proposal.stake_amount)
-        .ok_or(ListingError::Overflow)?;
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
     // 标记已取回
@@ -2597,20 +4122,20 @@ fn process_claim_perp_stake(
 }
 
 fn process_update_perp_status(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     is_active: Option<bool>,
     is_paused: Option<bool>,
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
     
-    let admin = next_account_info(account_iter)?;
+    let pauser = next_account_info(account_iter)?;
     let market_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
 
-    // 加载配置并验证 Admin
-    let config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
-    verify_admin(admin, &config)?;
+    // 加载配置并验证 Pauser
+    let config = load_listing_config(program_id, config_account)?;
+    verify_role(pauser, &config, Role::Pauser, accounts)?;
 
     // 加载 Market
     let mut market = PerpMarket::try_from_slice(&market_account.data.borrow())?;
@@ -2633,8 +4158,9 @@ fn process_update_perp_status(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn process_update_perp_params(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     max_leverage: Option<u8>,
     initial_margin_rate_e6: Option<u32>,
@@ -2645,41 +4171,37 @@ fn process_update_perp_params(
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
     
-    let admin = next_account_info(account_iter)?;
+    let risk_manager = next_account_info(account_iter)?;
     let market_account = next_account_info(account_iter)?;
     let config_account = next_account_info(account_iter)?;
 
-    // 加载配置并验证 Admin
-    let config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
-    verify_admin(admin, &config)?;
+    // 加载配置并验证 RiskManager
+    let config = load_listing_config(program_id, config_account)?;
+    verify_role(risk_manager, &config, Role::RiskManager, accounts)?;
 
     // 加载 Market
     let mut market = PerpMarket::try_from_slice(&market_account.data.borrow())?;
-    
-    // 更新参数
-    if let Some(leverage) = max_leverage {
-        if leverage == 0 || leverage > 100 {
-            return Err(ListingError::InvalidLeverage.into());
-        }
-        market.max_leverage = leverage;
-        msg!("Max leverage updated to: {}x", leverage);
-    }
-    if let Some(rate) = initial_margin_rate_e6 {
-        if rate == 0 || rate > 1_000_000 {
-            return Err(ListingError::InvalidInitialMarginRate.into());
-        }
-        market.initial_margin_rate_e6 = rate;
-        msg!("Initial margin rate updated to: {} (e6)", rate);
-    }
-    if let Some(rate) = maintenance_margin_rate_e6 {
-        // 验证维持保证金率 < 初始保证金率
-        let init_rate = initial_margin_rate_e6.unwrap_or(market.initial_margin_rate_e6);
-        if rate >= init_rate {
-            return Err(ListingError::InvalidMaintenanceMarginRate.into());
-        }
-        market.maintenance_margin_rate_e6 = rate;
-        msg!("Maintenance margin rate updated to: {} (e6)", rate);
+
+    // 已上线市场（含被停用的）风险参数需通过时间锁变更（紧急下调 OI 上限除外）
+    if is_perp_param_change_timelocked(
+        &market,
+        max_leverage,
+        initial_margin_rate_e6,
+        maintenance_margin_rate_e6,
+        max_open_interest_e6,
+    ) {
+        msg!("Listed market risk params must go through SchedulePerpParamChange");
+        return Err(ListingError::ParamChangeTimelocked.into());
     }
+
+    // 更新参数
+    apply_perp_risk_params(
+        &mut market,
+        max_leverage,
+        initial_margin_rate_e6,
+        maintenance_margin_rate_e6,
+        max_open_interest_e6,
+    )?;
     if let Some(fee) = taker_fee_bps {
         if fee > 1000 {
             return Err(ListingError::InvalidFeeRate.into());
@@ -2688,16 +4210,12 @@ fn process_update_perp_params(
         msg!("Taker fee updated to: {} bps", fee);
     }
     if let Some(fee) = maker_fee_bps {
-        if fee < -500 || fee > 500 {
+        if !(-500..=500).contains(&fee) {
             return Err(ListingError::InvalidFeeRate.into());
         }
         market.maker_fee_bps = fee;
         msg!("Maker fee updated to: {} bps", fee);
     }
-    if let Some(max_oi) = max_open_interest_e6 {
-        market.max_open_interest_e6 = max_oi;
-        msg!("Max open interest updated to: {} (e6)", max_oi);
-    }
 
     market.serialize(&mut &mut market_account.data.borrow_mut()[..])?;
 
@@ -2734,10 +4252,8 @@ fn process_initialize_liquidity_pool(
     }
 
     // 加载配置
-    let config = ListingConfig::try_from_slice(&config_account.data.borrow())?;
-    if config.is_paused {
-        return Err(ListingError::ListingPaused.into());
-    }
+    let config = load_listing_config(program_id, config_account)?;
+    verify_not_paused(&config, PAUSE_LIQUIDITY_POOLS)?;
 
     // 解析市场类型
     let market_type_enum = match market_type {
@@ -2746,24 +4262,26 @@ fn process_initialize_liquidity_pool(
         _ => return Err(ListingError::InvalidMarketType.into()),
     };
 
-    // 获取市场索引并验证市场
-    let market_index: u16;
-    match market_type_enum {
+    // 获取市场索引并验证市场（永续市场同时取上线时的 Oracle 参考价）
+    if market_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let (market_index, reference_price): (u16, Option<(i64, i32)>) = match market_type_enum {
         MarketType::Spot => {
             let market = SpotMarket::try_from_slice(&market_account.data.borrow())?;
             if !market.is_active {
                 return Err(ListingError::MarketNotFound.into());
             }
-            market_index = market.market_index;
+            (market.market_index, None)
         }
         MarketType::Perp => {
             let market = PerpMarket::try_from_slice(&market_account.data.borrow())?;
             if !market.is_active {
                 return Err(ListingError::MarketNotFound.into());
             }
-            market_index = market.market_index;
+            (market.market_index, Some((market.reference_price, market.reference_expo)))
         }
-    }
+    };
 
     // 验证 PDA - 使用 creator 作为 nonce 的组成部分
     let (expected_pool, bump) = Pubkey::find_program_address(
@@ -2789,6 +4307,16 @@ fn process_initialize_liquidity_pool(
         return Err(ListingError::InvalidPriceRange.into());
     }
 
+    // 永续池价格区间须包含上线时的 Oracle 参考价
+    if let Some((reference_price, reference_expo)) = reference_price {
+        verify_price_range_contains_reference(
+            price_lower_e6,
+            price_upper_e6,
+            reference_price,
+            reference_expo,
+        )?;
+    }
+
     // 验证订单密度 (1-100)
     if order_density == 0 || order_density > 100 {
         return Err(ListingError::InvalidOrderDensity.into());
@@ -2854,7 +4382,7 @@ fn process_initialize_liquidity_pool(
 }
 
 fn process_fund_liquidity_pool(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     base_amount_e6: u64,
     quote_amount_e6: u64,
@@ -2863,7 +4391,7 @@ fn process_fund_liquidity_pool(
     
     let funder = next_account_info(account_iter)?;
     let pool_account = next_account_info(account_iter)?;
-    let _config_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
     // 注: 实际实现需要资金转移，通过 Vault Program CPI
 
     // 验证签名
@@ -2871,6 +4399,10 @@ fn process_fund_liquidity_pool(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // 检查是否暂停
+    let config = load_listing_config(program_id, config_account)?;
+    verify_not_paused(&config, PAUSE_LIQUIDITY_POOLS)?;
+
     // 加载 Pool
     let mut pool = LiquidityPool::try_from_slice(&pool_account.data.borrow())?;
     
@@ -2911,7 +4443,7 @@ fn process_fund_liquidity_pool(
 }
 
 fn process_adjust_liquidity_pool(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     price_lower_e6: Option<u64>,
     price_upper_e6: Option<u64>,
@@ -2922,12 +4454,7 @@ fn process_adjust_liquidity_pool(
     
     let operator = next_account_info(account_iter)?;
     let pool_account = next_account_info(account_iter)?;
-    let _config_account = next_account_info(account_iter)?;
-
-    // 验证签名
-    if !operator.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
-    }
+    let config_account = next_account_info(account_iter)?;
 
     // 加载 Pool
     let pool = LiquidityPool::try_from_slice(&pool_account.data.borrow())?;
@@ -2937,10 +4464,10 @@ fn process_adjust_liquidity_pool(
         return Err(ListingError::PoolNotActive.into());
     }
 
-    // 验证权限：只有创建者可以调整
-    if pool.creator != *operator.key {
-        return Err(ListingError::Unauthorized.into());
-    }
+    // 验证权限：只有创建者或 PoolOperator 可以调整
+    let config = load_listing_config(program_id, config_account)?;
+    verify_not_paused(&config, PAUSE_LIQUIDITY_POOLS)?;
+    verify_pool_authority(operator, &pool.creator, &config, accounts)?;
 
     // 验证参数（如果提供）
     if let (Some(lower), Some(upper)) = (price_lower_e6, price_upper_e6) {
@@ -2981,7 +4508,7 @@ fn process_adjust_liquidity_pool(
 }
 
 fn process_refresh_liquidity_orders(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
@@ -2989,13 +4516,9 @@ fn process_refresh_liquidity_orders(
     let operator = next_account_info(account_iter)?;
     let pool_account = next_account_info(account_iter)?;
     let _market_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
     let _oracle_account = next_account_info(account_iter)?;
 
-    // 验证签名
-    if !operator.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
-    }
-
     // 加载 Pool
     let pool = LiquidityPool::try_from_slice(&pool_account.data.borrow())?;
     
@@ -3004,10 +4527,10 @@ fn process_refresh_liquidity_orders(
         return Err(ListingError::PoolNotActive.into());
     }
 
-    // 验证权限：只有创建者可以刷新订单
-    if pool.creator != *operator.key {
-        return Err(ListingError::Unauthorized.into());
-    }
+    // 验证权限：只有创建者或 PoolOperator 可以刷新订单
+    let config = load_listing_config(program_id, config_account)?;
+    verify_not_paused(&config, PAUSE_LIQUIDITY_POOLS)?;
+    verify_pool_authority(operator, &pool.creator, &config, accounts)?;
 
     // TODO: 实际刷新订单逻辑
     // 1. 从 Oracle 获取当前价格
@@ -3022,7 +4545,7 @@ fn process_refresh_liquidity_orders(
 }
 
 fn process_withdraw_liquidity_profit(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     base_amount_e6: u64,
     quote_amount_e6: u64,
@@ -3031,12 +4554,7 @@ fn process_withdraw_liquidity_profit(
     
     let withdrawer = next_account_info(account_iter)?;
     let pool_account = next_account_info(account_iter)?;
-    let _config_account = next_account_info(account_iter)?;
-
-    // 验证签名
-    if !withdrawer.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
-    }
+    let config_account = next_account_info(account_iter)?;
 
     // 加载 Pool
     let mut pool = LiquidityPool::try_from_slice(&pool_account.data.borrow())?;
@@ -3046,10 +4564,10 @@ fn process_withdraw_liquidity_profit(
         return Err(ListingError::PoolNotActive.into());
     }
 
-    // 验证权限
-    if pool.creator != *withdrawer.key {
-        return Err(ListingError::Unauthorized.into());
-    }
+    // 验证权限：创建者或 PoolOperator
+    let config = load_listing_config(program_id, config_account)?;
+    verify_not_paused(&config, PAUSE_LIQUIDITY_POOLS)?;
+    verify_pool_authority(withdrawer, &pool.creator, &config, accounts)?;
 
     // 验证金额
     if base_amount_e6 == 0 && quote_amount_e6 == 0 {
@@ -3083,19 +4601,14 @@ fn process_withdraw_liquidity_profit(
 }
 
 fn process_retire_liquidity_pool(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
     
     let operator = next_account_info(account_iter)?;
     let pool_account = next_account_info(account_iter)?;
-    let _config_account = next_account_info(account_iter)?;
-
-    // 验证签名
-    if !operator.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
-    }
+    let config_account = next_account_info(account_iter)?;
 
     // 加载 Pool
     let mut pool = LiquidityPool::try_from_slice(&pool_account.data.borrow())?;
@@ -3105,10 +4618,10 @@ fn process_retire_liquidity_pool(
         return Err(ListingError::PoolNotActive.into());
     }
 
-    // 验证权限
-    if pool.creator != *operator.key {
-        return Err(ListingError::Unauthorized.into());
-    }
+    // 验证权限：创建者或 PoolOperator
+    let config = load_listing_config(program_id, config_account)?;
+    verify_not_paused(&config, PAUSE_LIQUIDITY_POOLS)?;
+    verify_pool_authority(operator, &pool.creator, &config, accounts)?;
 
     // 验证没有剩余资金（需先提取所有资金）
     if pool.base_amount_e6 > 0 || pool.quote_amount_e6 > 0 {
@@ -3127,3 +4640,3129 @@ fn process_retire_liquidity_pool(
     Ok(())
 }
 
+
+// =============================================================================
+// 反对质押结算指令处理
+// =============================================================================
+
+/// 创建或累加反对者的 Objection PDA
+///
+/// 返回 true 表示该反对者首次反对此提案
+#[allow(clippy::too_many_arguments)]
+fn record_objection<'a>(
+    program_id: &Pubkey,
+    objector: &AccountInfo<'a>,
+    objection_account: &AccountInfo<'a>,
+    proposal: &Pubkey,
+    proposal_type: ProposalType,
+    amount: u64,
+    system_program: &AccountInfo<'a>,
+    current_ts: i64,
+) -> Result<bool, ProgramError> {
+    // 验证 Objection PDA
+    let (expected_objection, bump) = derive_objection_pda(proposal, objector.key, program_id);
+    if objection_account.key != &expected_objection {
+        return Err(ListingError::InvalidPda.into());
+    }
+
+    // 已存在：累加反对质押
+    if !objection_account.data_is_empty() {
+        let mut objection = Objection::try_from_slice(&objection_account.data.borrow())?;
+        objection.amount = objection.amount
+            .checked_add(amount)
+            .ok_or(ListingError::Overflow)?;
+        objection.serialize(&mut &mut objection_account.data.borrow_mut()[..])?;
+        return Ok(false);
+    }
+
+    // 首次反对：创建 Objection 账户
+    create_account(
+        objector,
+        objection_account,
+        OBJECTION_SIZE,
+        program_id,
+        system_program,
+        &[OBJECTION_SEED, proposal.as_ref(), objector.key.as_ref(), &[bump]],
+    )?;
+
+    let objection = Objection {
+        discriminator: Objection::DISCRIMINATOR,
+        version: 1,
+        proposal_type,
+        proposal: *proposal,
+        objector: *objector.key,
+        amount,
+        created_at: current_ts,
+        claimed: false,
+        bump,
+        reserved: [0u8; 64],
+    };
+
+    objection.serialize(&mut &mut objection_account.data.borrow_mut()[..])?;
+
+    Ok(true)
+}
+
+fn process_claim_objection_stake(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let objector = next_account_info(account_iter)?;
+    let objection_account = next_account_info(account_iter)?;
+    let proposal_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let treasury_account = next_account_info(account_iter)?;
+
+    // 验证签名
+    if !objector.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // 加载配置
+    let mut config = load_listing_config(program_id, config_account)?;
+
+    // 验证 Treasury
+    if treasury_account.key != &config.treasury {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    // 验证账户 owner
+    if objection_account.owner != program_id || proposal_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+
+    // 加载 Objection
+    let mut objection = Objection::try_from_slice(&objection_account.data.borrow())?;
+
+    // 验证反对者和提案
+    if &objection.objector != objector.key {
+        return Err(ListingError::Unauthorized.into());
+    }
+    if &objection.proposal != proposal_account.key {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    // 验证未取回
+    if objection.claimed {
+        return Err(ListingError::StakeAlreadyClaimed.into());
+    }
+
+    // 读取提案结果
+    let current_ts = get_current_timestamp()?;
+    let appeal_window = config.appeal_window_seconds;
+    let (status, version, stake_mode, objection_stake, objector_reward, appeal_open) = match objection.proposal_type {
+        ProposalType::Token => {
+            let proposal = TokenProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            let appeal_open = is_appeal_window_open(
+                proposal.status,
+                proposal.rejected_at,
+                proposal.appeal_bond,
+                proposal.stake_claimed,
+                appeal_window,
+                current_ts,
+            );
+            (
+                proposal.status,
+                proposal.version,
+                proposal.stake_mode,
+                proposal.objection_stake,
+                proposal.objector_reward,
+                appeal_open,
+            )
+        }
+        ProposalType::SpotMarket => {
+            let proposal = SpotMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            let appeal_open = is_appeal_window_open(
+                proposal.status,
+                proposal.rejected_at,
+                proposal.appeal_bond,
+                proposal.stake_claimed,
+                appeal_window,
+                current_ts,
+            );
+            (
+                proposal.status,
+                proposal.version,
+                proposal.stake_mode,
+                proposal.objection_stake,
+                proposal.objector_reward,
+                appeal_open,
+            )
+        }
+        ProposalType::PerpMarket => {
+            let proposal = PerpMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            let appeal_open = is_appeal_window_open(
+                proposal.status,
+                proposal.rejected_at,
+                proposal.appeal_bond,
+                proposal.stake_claimed,
+                appeal_window,
+                current_ts,
+            );
+            (
+                proposal.status,
+                proposal.version,
+                proposal.stake_mode,
+                proposal.objection_stake,
+                proposal.objector_reward,
+                appeal_open,
+            )
+        }
+    };
+
+    // 拒绝决定可能被申诉推翻，申诉窗口关闭前不能领取奖励
+    if appeal_open {
+        return Err(ListingError::AppealWindowOpen.into());
+    }
+
+    // 计算退还和奖励
+    let (refund, reward) = calculate_objection_payout(
+        status,
+        objection.amount,
+        objection_stake,
+        objector_reward,
+        config.objection_forfeit_bps,
+    )?;
+    let payout = refund.checked_add(reward).ok_or(ListingError::Overflow)?;
+
+    // 从提案托管退还质押，被没收部分转入 Treasury
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
+        treasury_account,
+        version,
+        stake_mode,
+        account_iter,
+        true,
+    )?;
+    release_stake(
+        &mut config,
+        &vault,
+        objector,
+        payout,
+    )?;
+    slash_stake(
+        &mut config,
+        &vault,
+        objection.amount - refund,
+    )?;
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    // 标记已取回
+    objection.claimed = true;
+    objection.serialize(&mut &mut objection_account.data.borrow_mut()[..])?;
+
+    msg!("Objection stake claimed");
+    msg!("Proposal status: {:?}", status);
+    msg!("Refund: {} N1024", refund / 1_000_000_000);
+    msg!("Reward: {} N1024", reward / 1_000_000_000);
+    msg!("Forfeited: {} N1024", (objection.amount - refund) / 1_000_000_000);
+
+    Ok(())
+}
+
+fn process_update_objection_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    objection_forfeit_bps: Option<u16>,
+    objector_reward_bps: Option<u16>,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    if let Some(bps) = objection_forfeit_bps {
+        validate_bps(bps)?;
+        config.objection_forfeit_bps = bps;
+        msg!("Objection forfeit updated to: {} bps", bps);
+    }
+    if let Some(bps) = objector_reward_bps {
+        validate_bps(bps)?;
+        config.objector_reward_bps = bps;
+        msg!("Objector reward updated to: {} bps", bps);
+    }
+
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Objection config updated");
+
+    Ok(())
+}
+
+/// 校验被拒绝提案的质押取回条件，返回应退还金额并标记已取回
+///
+/// 申诉逾期未裁决时维持拒绝，申诉保证金随剩余质押一并退还
+#[allow(clippy::too_many_arguments)]
+fn take_rejected_stake_refund(
+    proposer: &Pubkey,
+    proposal_proposer: &Pubkey,
+    status: &mut ProposalStatus,
+    stake_amount: u64,
+    slash_amount: u64,
+    appeal_bond: u64,
+    appeal_expired: bool,
+    stake_claimed: &mut bool,
+) -> Result<u64, ProgramError> {
+    // 验证 Proposer
+    if proposal_proposer != proposer {
+        return Err(ListingError::NotProposer.into());
+    }
+
+    // 验证状态已拒绝（或申诉已逾期）
+    let returned_bond = match *status {
+        ProposalStatus::Rejected => 0,
+        ProposalStatus::Appealed if appeal_expired => {
+            msg!("Appeal expired without resolution, returning bond");
+            *status = ProposalStatus::Rejected;
+            appeal_bond
+        }
+        _ => return Err(ListingError::ProposalNotPending.into()),
+    };
+
+    // 验证未取回
+    if *stake_claimed {
+        return Err(ListingError::StakeAlreadyClaimed.into());
+    }
+
+    *stake_claimed = true;
+
+    stake_amount
+        .checked_sub(slash_amount)
+        .ok_or(ListingError::Underflow)?
+        .checked_add(returned_bond)
+        .ok_or(ListingError::Overflow.into())
+}
+
+fn process_claim_rejected_stake(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    proposal_type: ProposalType,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let proposer = next_account_info(account_iter)?;
+    let proposal_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let treasury_account = next_account_info(account_iter)?;
+
+    // 加载配置
+    let mut config = load_listing_config(program_id, config_account)?;
+
+    // 验证 Treasury
+    if treasury_account.key != &config.treasury {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    // 验证提案账户 owner
+    if proposal_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+
+    // 加载提案并计算退还金额（质押 - 罚没），同时取出留在托管中的罚没
+    let current_ts = get_current_timestamp()?;
+    let (refund, pending_slash, appeal_open, version, stake_mode) = match proposal_type {
+        ProposalType::Token => {
+            let mut proposal = TokenProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            let appeal_open = is_appeal_window_open(
+                proposal.status,
+                proposal.rejected_at,
+                proposal.appeal_bond,
+                proposal.stake_claimed,
+                config.appeal_window_seconds,
+                current_ts,
+            );
+            let appeal_expired = is_appeal_expired(
+                proposal.status,
+                proposal.rejected_at,
+                config.appeal_window_seconds,
+                current_ts,
+            );
+            let refund = take_rejected_stake_refund(
+                proposer.key,
+                &proposal.proposer,
+                &mut proposal.status,
+                proposal.stake_amount,
+                proposal.slash_amount,
+                proposal.appeal_bond,
+                appeal_expired,
+                &mut proposal.stake_claimed,
+            )?;
+            let pending_slash = take_pending_slash(
+                &mut config,
+                &mut proposal.slash_pending,
+                proposal.slash_amount,
+                proposal.objector_reward,
+            )?;
+            proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+            (refund, pending_slash, appeal_open, proposal.version, proposal.stake_mode)
+        }
+        ProposalType::SpotMarket => {
+            let mut proposal = SpotMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            let appeal_open = is_appeal_window_open(
+                proposal.status,
+                proposal.rejected_at,
+                proposal.appeal_bond,
+                proposal.stake_claimed,
+                config.appeal_window_seconds,
+                current_ts,
+            );
+            let appeal_expired = is_appeal_expired(
+                proposal.status,
+                proposal.rejected_at,
+                config.appeal_window_seconds,
+                current_ts,
+            );
+            let refund = take_rejected_stake_refund(
+                proposer.key,
+                &proposal.proposer,
+                &mut proposal.status,
+                proposal.stake_amount,
+                proposal.slash_amount,
+                proposal.appeal_bond,
+                appeal_expired,
+                &mut proposal.stake_claimed,
+            )?;
+            let pending_slash = take_pending_slash(
+                &mut config,
+                &mut proposal.slash_pending,
+                proposal.slash_amount,
+                proposal.objector_reward,
+            )?;
+            proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+            (refund, pending_slash, appeal_open, proposal.version, proposal.stake_mode)
+        }
+        ProposalType::PerpMarket => {
+            let mut proposal = PerpMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            let appeal_open = is_appeal_window_open(
+                proposal.status,
+                proposal.rejected_at,
+                proposal.appeal_bond,
+                proposal.stake_claimed,
+                config.appeal_window_seconds,
+                current_ts,
+            );
+            let appeal_expired = is_appeal_expired(
+                proposal.status,
+                proposal.rejected_at,
+                config.appeal_window_seconds,
+                current_ts,
+            );
+            let refund = take_rejected_stake_refund(
+                proposer.key,
+                &proposal.proposer,
+                &mut proposal.status,
+                proposal.stake_amount,
+                proposal.slash_amount,
+                proposal.appeal_bond,
+                appeal_expired,
+                &mut proposal.stake_claimed,
+            )?;
+            let pending_slash = take_pending_slash(
+                &mut config,
+                &mut proposal.slash_pending,
+                proposal.slash_amount,
+                proposal.objector_reward,
+            )?;
+            proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+            (refund, pending_slash, appeal_open, proposal.version, proposal.stake_mode)
+        }
+    };
+
+    // 申诉窗口内取回视为放弃申诉，须 Proposer 签名；窗口结束后任何人可触发结算
+    if appeal_open && !proposer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // 从提案托管退还质押，留在托管中的罚没转入 Treasury
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
+        treasury_account,
+        version,
+        stake_mode,
+        account_iter,
+        true,
+    )?;
+    release_stake(
+        &mut config,
+        &vault,
+        proposer,
+        refund,
+    )?;
+    if pending_slash > 0 {
+        slash_stake(
+            &mut config,
+            &vault,
+            pending_slash,
+        )?;
+    }
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Rejected stake claimed");
+    msg!("Refund: {} N1024", refund / 1_000_000_000);
+    msg!("Slashed: {} N1024", pending_slash / 1_000_000_000);
+
+    Ok(())
+}
+
+fn process_withdraw_treasury(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    destination: TreasuryDestination,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let treasury_manager = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let treasury_account = next_account_info(account_iter)?;
+    let destination_account = next_account_info(account_iter)?;
+
+    // 加载配置并验证 TreasuryManager
+    let config = load_listing_config(program_id, config_account)?;
+    verify_role(treasury_manager, &config, Role::TreasuryManager, accounts)?;
+
+    // 验证 Treasury
+    if treasury_account.key != &config.treasury {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    // 验证提取目标
+    verify_treasury_destination(&config, destination, destination_account)?;
+
+    // 验证金额
+    if amount == 0 {
+        return Err(ListingError::InvalidAmount.into());
+    }
+
+    // 只允许提取盈余（锁定质押和租金豁免余额不可提取）
+    let rent_minimum = Rent::get()?.minimum_balance(treasury_account.data_len());
+    let surplus = calculate_treasury_surplus(
+        treasury_account.lamports(),
+        config.total_staked_lamports,
+        rent_minimum,
+    );
+    if amount > surplus {
+        msg!("Withdraw amount exceeds treasury surplus: {} > {}", amount, surplus);
+        return Err(ListingError::InsufficientBalance.into());
+    }
+
+    transfer_lamports_from_pda(
+        treasury_account,
+        destination_account,
+        amount,
+    )?;
+    verify_treasury_solvency(treasury_account, &config)?;
+
+    msg!("Treasury withdrawn");
+    msg!("Destination: {:?} ({})", destination, destination_account.key);
+    msg!("Amount: {} N1024", amount / 1_000_000_000);
+    msg!("Remaining surplus: {} N1024", (surplus - amount) / 1_000_000_000);
+
+    Ok(())
+}
+
+fn process_withdraw_treasury_token(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    destination: TreasuryDestination,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let treasury_manager = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let treasury_token = next_account_info(account_iter)?;
+    let stake_authority = next_account_info(account_iter)?;
+    let token_program = next_account_info(account_iter)?;
+    let destination_token = next_account_info(account_iter)?;
+    let recipient = next_account_info(account_iter)?;
+
+    // 加载配置并验证 TreasuryManager
+    let config = load_listing_config(program_id, config_account)?;
+    verify_role(treasury_manager, &config, Role::TreasuryManager, accounts)?;
+
+    // 验证 Token Program 和 PDA
+    if token_program.key != &spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let (expected_authority, stake_authority_bump) = derive_stake_authority_pda(program_id);
+    if stake_authority.key != &expected_authority {
+        return Err(ListingError::InvalidPda.into());
+    }
+    let (expected_treasury_token, _) = derive_treasury_stake_pda(program_id);
+    if treasury_token.key != &expected_treasury_token {
+        return Err(ListingError::InvalidPda.into());
+    }
+
+    // 验证提取目标（目标 token 账户须归属于接收方）
+    verify_treasury_destination(&config, destination, recipient)?;
+    if unpack_token_account(destination_token)?.owner != *recipient.key {
+        msg!("Destination token account not owned by recipient");
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    // 验证金额
+    if amount == 0 {
+        return Err(ListingError::InvalidAmount.into());
+    }
+
+    // SPL 质押托管在各提案的 Stake Escrow 中，Treasury 质押 token 账户只接收罚没
+    let balance = unpack_token_account(treasury_token)?.amount;
+    if amount > balance {
+        msg!("Withdraw amount exceeds treasury token balance: {} > {}", amount, balance);
+        return Err(ListingError::InsufficientBalance.into());
+    }
+
+    spl_token_transfer(
+        treasury_token,
+        destination_token,
+        stake_authority,
+        token_program,
+        amount,
+        Some(&[&[STAKE_AUTHORITY_SEED, &[stake_authority_bump]]]),
+    )?;
+
+    msg!("Treasury token withdrawn");
+    msg!("Destination: {:?} ({})", destination, destination_token.key);
+    msg!("Amount: {}", amount);
+    msg!("Remaining: {}", balance - amount);
+
+    Ok(())
+}
+
+fn process_set_fee_recipient(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    fee_recipient: Pubkey,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    config.fee_recipient = fee_recipient;
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Fee recipient updated to: {}", fee_recipient);
+
+    Ok(())
+}
+
+fn process_set_insurance_vault(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    insurance_vault: Pubkey,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    config.insurance_vault = insurance_vault;
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Insurance vault updated to: {}", insurance_vault);
+
+    Ok(())
+}
+
+fn process_audit_treasury(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let config_account = next_account_info(account_iter)?;
+    let treasury_account = next_account_info(account_iter)?;
+
+    let config = load_listing_config(program_id, config_account)?;
+
+    // 验证 Treasury
+    if treasury_account.key != &config.treasury {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    let rent_minimum = Rent::get()?.minimum_balance(treasury_account.data_len());
+    let delta = calculate_treasury_delta(
+        treasury_account.lamports(),
+        config.total_staked_lamports,
+        rent_minimum,
+    );
+
+    let audit = TreasuryAudit {
+        treasury_lamports: treasury_account.lamports(),
+        total_staked_lamports: config.total_staked_lamports,
+        rent_minimum,
+        surplus_lamports: i64::try_from(delta).map_err(|_| ListingError::Overflow)?,
+    };
+    set_return_data(&audit.try_to_vec()?);
+
+    msg!("Event: TreasuryAudited");
+    msg!("Treasury: {} lamports", audit.treasury_lamports);
+    msg!("Outstanding stakes: {} lamports", audit.total_staked_lamports);
+    msg!("Rent minimum: {} lamports", audit.rent_minimum);
+    if delta < 0 {
+        msg!("Treasury insolvent, shortfall: {} lamports", -delta);
+    } else {
+        msg!("Surplus: {} lamports", delta);
+    }
+
+    Ok(())
+}
+
+/// 加载罚没比例表（PDA 未初始化时使用默认值）
+fn load_slash_schedule(
+    program_id: &Pubkey,
+    schedule_account: &AccountInfo,
+) -> Result<SlashSchedule, ProgramError> {
+    let (expected_schedule, bump) = derive_slash_schedule_pda(program_id);
+    if schedule_account.key != &expected_schedule {
+        return Err(ListingError::InvalidPda.into());
+    }
+    if schedule_account.data_is_empty() {
+        return Ok(SlashSchedule::default_schedule(bump));
+    }
+    if schedule_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let schedule = SlashSchedule::try_from_slice(&schedule_account.data.borrow())?;
+    if schedule.discriminator != SlashSchedule::DISCRIMINATOR {
+        return Err(ListingError::InvalidAccount.into());
+    }
+    Ok(schedule)
+}
+
+fn process_update_slash_schedule(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    cancel_slash_bps: u16,
+    vote_rejection_slash_bps: u16,
+    reason_slash_bps: Vec<u16>,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let schedule_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    let config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    validate_slash_schedule(cancel_slash_bps, vote_rejection_slash_bps, &reason_slash_bps)?;
+
+    let mut schedule = load_slash_schedule(program_id, schedule_account)?;
+
+    // 首次更新时创建 SlashSchedule 账户（多签委员会时由额外的 Payer 支付租金）
+    if schedule_account.data_is_empty() {
+        let payer = resolve_payer(admin, account_iter)?;
+        create_account(
+            payer,
+            schedule_account,
+            SLASH_SCHEDULE_SIZE,
+            program_id,
+            system_program,
+            &[SLASH_SCHEDULE_SEED, &[schedule.bump]],
+        )?;
+    }
+
+    schedule.cancel_slash_bps = cancel_slash_bps;
+    schedule.vote_rejection_slash_bps = vote_rejection_slash_bps;
+    schedule.reason_slash_bps = [0u16; MAX_SLASH_REASONS];
+    schedule.reason_slash_bps[..reason_slash_bps.len()].copy_from_slice(&reason_slash_bps);
+    schedule.reason_count = reason_slash_bps.len() as u8;
+    schedule.serialize(&mut &mut schedule_account.data.borrow_mut()[..])?;
+
+    msg!("Slash schedule updated");
+    msg!("Cancel: {} bps", cancel_slash_bps);
+    msg!("Vote rejection: {} bps", vote_rejection_slash_bps);
+    msg!("Reject reasons: {:?}", reason_slash_bps);
+
+    Ok(())
+}
+
+fn process_set_stake_mode(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    stake_mode: StakeMode,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    if stake_mode == StakeMode::Spl {
+        let stake_mint = next_account_info(account_iter)?;
+        let treasury_token = next_account_info(account_iter)?;
+        let stake_authority = next_account_info(account_iter)?;
+        let token_program = next_account_info(account_iter)?;
+        let system_program = next_account_info(account_iter)?;
+
+        if token_program.key != &spl_token::id() {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        let (expected_authority, _) = derive_stake_authority_pda(program_id);
+        if stake_authority.key != &expected_authority {
+            return Err(ListingError::InvalidPda.into());
+        }
+        let (expected_treasury_token, treasury_token_bump) = derive_treasury_stake_pda(program_id);
+        if treasury_token.key != &expected_treasury_token {
+            return Err(ListingError::InvalidPda.into());
+        }
+
+        if treasury_token.data_is_empty() {
+            // 首次启用时创建 Treasury 质押 token 账户（多签委员会时由额外的 Payer 支付租金）
+            let payer = resolve_payer(admin, account_iter)?;
+            create_stake_token_account(
+                payer,
+                treasury_token,
+                stake_mint,
+                stake_authority,
+                token_program,
+                system_program,
+                &[TREASURY_STAKE_SEED, &[treasury_token_bump]],
+            )?;
+        } else if stake_mint.key != &unpack_token_account(treasury_token)?.mint {
+            msg!("Stake mint cannot be changed once set");
+            return Err(ListingError::InvalidStakeMint.into());
+        }
+
+        msg!("Stake mint: {}", stake_mint.key);
+    }
+
+    config.stake_mode = stake_mode;
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Stake mode updated to: {:?}", stake_mode);
+
+    Ok(())
+}
+
+/// 加载动态质押定价（PDA 未初始化时使用默认值，不启用）
+fn load_stake_pricing(
+    program_id: &Pubkey,
+    pricing_account: &AccountInfo,
+) -> Result<StakePricing, ProgramError> {
+    let (expected_pricing, bump) = derive_stake_pricing_pda(program_id);
+    if pricing_account.key != &expected_pricing {
+        return Err(ListingError::InvalidPda.into());
+    }
+    if pricing_account.data_is_empty() {
+        return Ok(StakePricing::default_pricing(bump));
+    }
+    if pricing_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let pricing = StakePricing::try_from_slice(&pricing_account.data.borrow())?;
+    if pricing.discriminator != StakePricing::DISCRIMINATOR {
+        return Err(ListingError::InvalidAccount.into());
+    }
+    Ok(pricing)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn process_update_stake_pricing(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    enabled: bool,
+    free_pending: u16,
+    per_pending_bps: u16,
+    max_multiplier_bps: u32,
+    decay_seconds: u32,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let pricing_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    let config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    validate_stake_pricing(max_multiplier_bps, decay_seconds)?;
+
+    let mut pricing = load_stake_pricing(program_id, pricing_account)?;
+
+    // 首次更新时创建 StakePricing 账户（多签委员会时由额外的 Payer 支付租金）
+    if pricing_account.data_is_empty() {
+        let payer = resolve_payer(admin, account_iter)?;
+        create_account(
+            payer,
+            pricing_account,
+            STAKE_PRICING_SIZE,
+            program_id,
+            system_program,
+            &[STAKE_PRICING_SEED, &[pricing.bump]],
+        )?;
+    }
+
+    pricing.enabled = enabled;
+    pricing.free_pending = free_pending;
+    pricing.per_pending_bps = per_pending_bps;
+    pricing.max_multiplier_bps = max_multiplier_bps;
+    pricing.decay_seconds = decay_seconds;
+    pricing.serialize(&mut &mut pricing_account.data.borrow_mut()[..])?;
+
+    msg!("Stake pricing updated");
+    msg!("Enabled: {}", enabled);
+    msg!("Free pending: {}, per pending: {} bps", free_pending, per_pending_bps);
+    msg!("Max multiplier: {} bps, decay: {}s", max_multiplier_bps, decay_seconds);
+
+    Ok(())
+}
+
+// =============================================================================
+// Token 元数据指令处理
+// =============================================================================
+
+/// 加载并验证本程序的 TokenMetadata
+fn load_token_metadata(
+    program_id: &Pubkey,
+    metadata_account: &AccountInfo,
+) -> Result<TokenMetadata, ProgramError> {
+    if metadata_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let metadata = TokenMetadata::try_from_slice(&metadata_account.data.borrow())?;
+    if metadata.discriminator != TokenMetadata::DISCRIMINATOR {
+        return Err(ListingError::InvalidAccount.into());
+    }
+    Ok(metadata)
+}
+
+/// 验证元数据修改权限：提案者本人签名，或 Admin（含多签委员会）
+fn verify_metadata_authority(
+    authority: &AccountInfo,
+    config: &ListingConfig,
+    metadata: &TokenMetadata,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if authority.key == &metadata.proposer {
+        if !authority.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        return Ok(());
+    }
+    verify_admin(authority, config, accounts)
+}
+
+fn process_set_token_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    content: TokenMetadataContent,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let proposer = next_account_info(account_iter)?;
+    let proposal_account = next_account_info(account_iter)?;
+    let metadata_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    // 验证签名
+    if !proposer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // 加载提案
+    if proposal_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let proposal = TokenProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+    if proposal.discriminator != TokenProposal::DISCRIMINATOR {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    // 验证 Proposer
+    if &proposal.proposer != proposer.key {
+        return Err(ListingError::NotProposer.into());
+    }
+
+    // 只能在提案期间设置
+    if proposal.status != ProposalStatus::Pending {
+        return Err(ListingError::ProposalNotPending.into());
+    }
+
+    validate_token_metadata(&content)?;
+
+    // 验证 TokenMetadata PDA
+    let (expected_metadata, bump) = derive_token_metadata_pda(proposal_account.key, program_id);
+    if metadata_account.key != &expected_metadata {
+        return Err(ListingError::InvalidPda.into());
+    }
+    if !metadata_account.data_is_empty() {
+        return Err(ListingError::AlreadyInitialized.into());
+    }
+
+    create_account(
+        proposer,
+        metadata_account,
+        TOKEN_METADATA_SIZE,
+        program_id,
+        system_program,
+        &[TOKEN_METADATA_SEED, proposal_account.key.as_ref(), &[bump]],
+    )?;
+
+    let current_ts = get_current_timestamp()?;
+    let metadata = TokenMetadata {
+        discriminator: TokenMetadata::DISCRIMINATOR,
+        version: 1,
+        proposal: *proposal_account.key,
+        proposer: *proposer.key,
+        content,
+        updated_at: current_ts,
+        pending: TokenMetadataContent::default(),
+        has_pending: false,
+        scheduled_by: Pubkey::default(),
+        effective_at: 0,
+        bump,
+        reserved: [0u8; 64],
+    };
+    metadata.serialize(&mut &mut metadata_account.data.borrow_mut()[..])?;
+
+    msg!("Event: TokenMetadataSet");
+    msg!("Symbol: {}", std::str::from_utf8(symbol_seed(&proposal.symbol)).unwrap_or(""));
+    msg!("Name: {}", metadata.content.name_str());
+
+    Ok(())
+}
+
+fn process_schedule_token_metadata_update(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    content: TokenMetadataContent,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let authority = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let metadata_account = next_account_info(account_iter)?;
+
+    let config = load_listing_config(program_id, config_account)?;
+    let mut metadata = load_token_metadata(program_id, metadata_account)?;
+    verify_metadata_authority(authority, &config, &metadata, accounts)?;
+
+    // 同一元数据只允许一个待生效的修改
+    if metadata.has_pending {
+        return Err(ListingError::ParamChangeAlreadyScheduled.into());
+    }
+
+    validate_token_metadata(&content)?;
+
+    let current_ts = get_current_timestamp()?;
+    let effective_at = current_ts
+        .checked_add(config.param_change_delay_seconds as i64)
+        .ok_or(ListingError::Overflow)?;
+
+    metadata.pending = content;
+    metadata.has_pending = true;
+    metadata.scheduled_by = *authority.key;
+    metadata.effective_at = effective_at;
+    metadata.serialize(&mut &mut metadata_account.data.borrow_mut()[..])?;
+
+    msg!("Event: TokenMetadataUpdateScheduled");
+    msg!("Effective at: {}", effective_at);
+
+    Ok(())
+}
+
+fn process_execute_token_metadata_update(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let caller = next_account_info(account_iter)?;
+    let metadata_account = next_account_info(account_iter)?;
+
+    // 验证签名
+    if !caller.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut metadata = load_token_metadata(program_id, metadata_account)?;
+    if !metadata.has_pending {
+        return Err(ListingError::NoScheduledParamChange.into());
+    }
+
+    // 验证时间锁已过
+    let current_ts = get_current_timestamp()?;
+    if current_ts < metadata.effective_at {
+        msg!("Effective at: {}, now: {}", metadata.effective_at, current_ts);
+        return Err(ListingError::ParamChangeNotEffective.into());
+    }
+
+    metadata.content = std::mem::take(&mut metadata.pending);
+    metadata.has_pending = false;
+    metadata.updated_at = current_ts;
+    metadata.serialize(&mut &mut metadata_account.data.borrow_mut()[..])?;
+
+    msg!("Event: TokenMetadataUpdated");
+    msg!("Name: {}", metadata.content.name_str());
+
+    Ok(())
+}
+
+fn process_cancel_token_metadata_update(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let authority = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let metadata_account = next_account_info(account_iter)?;
+
+    let config = load_listing_config(program_id, config_account)?;
+    let mut metadata = load_token_metadata(program_id, metadata_account)?;
+    verify_metadata_authority(authority, &config, &metadata, accounts)?;
+
+    if !metadata.has_pending {
+        return Err(ListingError::NoScheduledParamChange.into());
+    }
+
+    metadata.pending = TokenMetadataContent::default();
+    metadata.has_pending = false;
+    metadata.serialize(&mut &mut metadata_account.data.borrow_mut()[..])?;
+
+    msg!("Event: TokenMetadataUpdateCancelled");
+
+    Ok(())
+}
+
+// =============================================================================
+// 推送预言机指令处理
+// =============================================================================
+
+/// 加载并验证本程序的 PushOracle
+fn load_push_oracle(
+    program_id: &Pubkey,
+    oracle_account: &AccountInfo,
+) -> Result<PushOracle, ProgramError> {
+    if oracle_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let oracle = PushOracle::try_from_slice(&oracle_account.data.borrow())?;
+    if oracle.discriminator != PushOracle::DISCRIMINATOR {
+        return Err(ListingError::InvalidAccount.into());
+    }
+    Ok(oracle)
+}
+
+fn process_create_push_oracle(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    feed_id: [u8; 32],
+    expo: i32,
+    publisher: Pubkey,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let oracle_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    let config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    if !(-18..=18).contains(&expo) {
+        msg!("Oracle price exponent out of range: {}", expo);
+        return Err(ListingError::InvalidOracle.into());
+    }
+
+    // 验证 PushOracle PDA
+    let (expected_oracle, bump) = derive_push_oracle_pda(&feed_id, program_id);
+    if oracle_account.key != &expected_oracle {
+        return Err(ListingError::InvalidPda.into());
+    }
+    if !oracle_account.data_is_empty() {
+        return Err(ListingError::AlreadyInitialized.into());
+    }
+
+    let payer = resolve_payer(admin, account_iter)?;
+    create_account(
+        payer,
+        oracle_account,
+        PUSH_ORACLE_SIZE,
+        program_id,
+        system_program,
+        &[PUSH_ORACLE_SEED, &feed_id, &[bump]],
+    )?;
+
+    let oracle = PushOracle {
+        discriminator: PushOracle::DISCRIMINATOR,
+        version: 1,
+        feed_id,
+        publisher,
+        price: 0,
+        conf: 0,
+        expo,
+        publish_time: 0,
+        bump,
+        reserved: [0u8; 32],
+    };
+    oracle.serialize(&mut &mut oracle_account.data.borrow_mut()[..])?;
+
+    msg!("Event: PushOracleCreated");
+    msg!("Oracle: {}", oracle_account.key);
+    msg!("Publisher: {}", publisher);
+
+    Ok(())
+}
+
+fn process_update_push_oracle_price(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    price: i64,
+    conf: u64,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let publisher = next_account_info(account_iter)?;
+    let oracle_account = next_account_info(account_iter)?;
+
+    let mut oracle = load_push_oracle(program_id, oracle_account)?;
+
+    // 验证 Publisher
+    if !publisher.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if publisher.key != &oracle.publisher {
+        return Err(ListingError::Unauthorized.into());
+    }
+
+    if price <= 0 {
+        msg!("Oracle price is non-positive: {}", price);
+        return Err(ListingError::InvalidOracle.into());
+    }
+
+    oracle.price = price;
+    oracle.conf = conf;
+    oracle.publish_time = get_current_timestamp()?;
+    oracle.serialize(&mut &mut oracle_account.data.borrow_mut()[..])?;
+
+    msg!("Price: {} x 10^{}", price, oracle.expo);
+
+    Ok(())
+}
+
+fn process_set_push_oracle_publisher(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    publisher: Pubkey,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let oracle_account = next_account_info(account_iter)?;
+
+    let config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    let mut oracle = load_push_oracle(program_id, oracle_account)?;
+    oracle.publisher = publisher;
+    oracle.serialize(&mut &mut oracle_account.data.borrow_mut()[..])?;
+
+    msg!("Event: PushOraclePublisherUpdated");
+    msg!("Publisher: {}", publisher);
+
+    Ok(())
+}
+
+// =============================================================================
+// Oracle 风险配置指令处理
+// =============================================================================
+
+/// 构建 Oracle 风险配置（验证阈值，识别备用 Oracle 数据源）
+fn build_oracle_risk_config<'a, 'b: 'a, I: Iterator<Item = &'a AccountInfo<'b>>>(
+    program_id: &Pubkey,
+    account_iter: &mut I,
+    primary_oracle: &Pubkey,
+    max_staleness_seconds: u32,
+    max_confidence_bps: u16,
+    fallback_oracle: Option<Pubkey>,
+) -> Result<OracleRiskConfig, ProgramError> {
+    validate_oracle_risk_limits(max_staleness_seconds, max_confidence_bps)?;
+
+    let mut oracle_config = OracleRiskConfig {
+        max_staleness_seconds,
+        max_confidence_bps,
+        ..OracleRiskConfig::default()
+    };
+    if let Some(fallback_oracle) = fallback_oracle {
+        let fallback_account = next_account_info(account_iter)?;
+        if fallback_account.key != &fallback_oracle || &fallback_oracle == primary_oracle {
+            msg!("Invalid fallback oracle: {}", fallback_oracle);
+            return Err(ListingError::InvalidOracleConfig.into());
+        }
+        oracle_config.fallback_oracle = fallback_oracle;
+        oracle_config.fallback_source = validate_oracle_exists(fallback_account, program_id)?.0;
+    }
+
+    Ok(oracle_config)
+}
+
+fn log_oracle_risk_config(oracle_config: &OracleRiskConfig) {
+    msg!("Max staleness: {}s", effective_max_staleness(oracle_config));
+    msg!("Max confidence: {} bps", effective_max_confidence_bps(oracle_config));
+    if oracle_config.has_fallback() {
+        msg!("Fallback oracle: {} ({:?})", oracle_config.fallback_oracle, oracle_config.fallback_source);
+    }
+}
+
+fn process_update_perp_oracle_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_staleness_seconds: u32,
+    max_confidence_bps: u16,
+    fallback_oracle: Option<Pubkey>,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let risk_manager = next_account_info(account_iter)?;
+    let market_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+
+    // 加载配置并验证 RiskManager
+    let config = load_listing_config(program_id, config_account)?;
+    verify_role(risk_manager, &config, Role::RiskManager, accounts)?;
+
+    // 加载 Market
+    if market_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let mut market = PerpMarket::try_from_slice(&market_account.data.borrow())?;
+    if market.discriminator != PerpMarket::DISCRIMINATOR {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    market.oracle_config = build_oracle_risk_config(
+        program_id,
+        account_iter,
+        &market.oracle,
+        max_staleness_seconds,
+        max_confidence_bps,
+        fallback_oracle,
+    )?;
+    market.serialize(&mut &mut market_account.data.borrow_mut()[..])?;
+
+    msg!("Event: PerpOracleConfigUpdated");
+    msg!("Market: {}", market_account.key);
+    log_oracle_risk_config(&market.oracle_config);
+
+    Ok(())
+}
+
+fn process_update_token_oracle_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_staleness_seconds: u32,
+    max_confidence_bps: u16,
+    fallback_oracle: Option<Pubkey>,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let risk_manager = next_account_info(account_iter)?;
+    let registry_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    // 加载配置并验证 RiskManager
+    let config = load_listing_config(program_id, config_account)?;
+    verify_role(risk_manager, &config, Role::RiskManager, accounts)?;
+
+    // 加载 Registry
+    if registry_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let registry = TokenRegistry::deserialize(&mut &registry_account.data.borrow()[..])?;
+    if registry.discriminator != TokenRegistry::DISCRIMINATOR {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    let oracle_config = build_oracle_risk_config(
+        program_id,
+        account_iter,
+        &registry.oracle.unwrap_or_default(),
+        max_staleness_seconds,
+        max_confidence_bps,
+        fallback_oracle,
+    )?;
+    let payer = resolve_payer(risk_manager, account_iter)?;
+
+    // 风险配置上线前创建的 Registry 没有尾部空间
+    if registry_account.data_len() < TOKEN_REGISTRY_ACCOUNT_SIZE {
+        resize_account(payer, registry_account, TOKEN_REGISTRY_ACCOUNT_SIZE, system_program)?;
+    }
+    oracle_config.store(&mut registry_account.data.borrow_mut())?;
+
+    msg!("Event: TokenOracleConfigUpdated");
+    msg!("Token index: {}", registry.token_index);
+    log_oracle_risk_config(&oracle_config);
+
+    Ok(())
+}
+
+// =============================================================================
+// 交易对索引指令处理
+// =============================================================================
+
+fn process_allow_market_replacement(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    market_type: MarketType,
+    base_token_index: u16,
+    quote_token_index: u16,
+    allowed: bool,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let pair_index_account = next_account_info(account_iter)?;
+
+    let config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    let mut pair = load_pair_index(
+        program_id,
+        market_type,
+        base_token_index,
+        quote_token_index,
+        pair_index_account,
+    )?
+    .ok_or(ListingError::InvalidAccount)?;
+    pair.replacement_allowed = allowed;
+    pair.serialize(&mut &mut pair_index_account.data.borrow_mut()[..])?;
+
+    msg!("Event: MarketReplacementUpdated");
+    msg!("Market type: {:?}", market_type);
+    msg!("Pair: {} / {}", base_token_index, quote_token_index);
+    msg!("Current market: {}", pair.market_index);
+    msg!("Replacement allowed: {}", allowed);
+
+    Ok(())
+}
+
+/// 为交易对索引上线前上架的市场补建 PairIndex（任何人可调用，已存在时跳过）
+fn process_index_listed_market(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    market_type: MarketType,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let payer = next_account_info(account_iter)?;
+    let market_account = next_account_info(account_iter)?;
+    let pair_index_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    // 验证签名
+    if !payer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // 加载并验证 Market
+    if market_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let (market_index, base_token_index, quote_token_index) = match market_type {
+        MarketType::Spot => {
+            let market = SpotMarket::try_from_slice(&market_account.data.borrow())?;
+            if market.discriminator != SpotMarket::DISCRIMINATOR {
+                return Err(ListingError::InvalidAccount.into());
+            }
+            (market.market_index, market.base_token_index, market.quote_token_index)
+        }
+        MarketType::Perp => {
+            let market = PerpMarket::try_from_slice(&market_account.data.borrow())?;
+            if market.discriminator != PerpMarket::DISCRIMINATOR {
+                return Err(ListingError::InvalidAccount.into());
+            }
+            (market.market_index, market.base_token_index, market.quote_token_index)
+        }
+    };
+
+    // 已有索引时跳过（同一交易对的多个旧市场只有先补建者占有索引）
+    let pair = load_pair_index(
+        program_id,
+        market_type,
+        base_token_index,
+        quote_token_index,
+        pair_index_account,
+    )?;
+    if let Some(pair) = pair {
+        msg!("Pair already indexed to market {}", pair.market_index);
+        return Ok(());
+    }
+    claim_pair_index(
+        program_id,
+        payer,
+        pair_index_account,
+        None,
+        system_program,
+        market_type,
+        base_token_index,
+        quote_token_index,
+        market_index,
+    )?;
+
+    msg!("Pair index created");
+    msg!("Market type: {:?}", market_type);
+    msg!("Pair: {} / {}", base_token_index, quote_token_index);
+    msg!("Market index: {}", market_index);
+
+    Ok(())
+}
+
+// =============================================================================
+// 多签委员会指令处理
+// =============================================================================
+
+/// 将成员列表填充为定长数组
+fn council_members_array(members: &[Pubkey]) -> [Pubkey; MAX_COUNCIL_MEMBERS] {
+    let mut array = [Pubkey::default(); MAX_COUNCIL_MEMBERS];
+    array[..members.len()].copy_from_slice(members);
+    array
+}
+
+/// 加载并验证本程序的 AdminCouncil PDA
+fn load_council_account(
+    program_id: &Pubkey,
+    council_account: &AccountInfo,
+) -> Result<AdminCouncil, ProgramError> {
+    if council_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let (expected_council, _) = derive_admin_council_pda(program_id);
+    if council_account.key != &expected_council {
+        return Err(ListingError::InvalidPda.into());
+    }
+    let council = AdminCouncil::try_from_slice(&council_account.data.borrow())?;
+    if council.discriminator != AdminCouncil::DISCRIMINATOR {
+        return Err(ListingError::InvalidAccount.into());
+    }
+    Ok(council)
+}
+
+/// 加载并验证属于该委员会的 PendingAction
+fn load_pending_action(
+    program_id: &Pubkey,
+    pending_account: &AccountInfo,
+    council_account: &AccountInfo,
+) -> Result<PendingAction, ProgramError> {
+    if pending_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let pending = PendingAction::try_from_slice(&pending_account.data.borrow())?;
+    if pending.discriminator != PendingAction::DISCRIMINATOR
+        || pending.council != *council_account.key
+    {
+        return Err(ListingError::InvalidAccount.into());
+    }
+    Ok(pending)
+}
+
+/// 验证签名者是委员会成员，返回成员位置
+fn verify_council_member(
+    member: &AccountInfo,
+    council: &AdminCouncil,
+) -> Result<usize, ProgramError> {
+    if !member.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    council
+        .member_index(member.key)
+        .ok_or_else(|| ListingError::NotCouncilMember.into())
+}
+
+fn process_initialize_admin_council(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let council_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    let mut config = load_listing_config(program_id, config_account)?;
+
+    // 由当前单签 Admin 创建（同时作为 payer）
+    if !admin.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    verify_admin(admin, &config, accounts)?;
+
+    validate_council(&members, threshold)?;
+
+    // 验证 Council PDA
+    let (expected_council, bump) = derive_admin_council_pda(program_id);
+    if council_account.key != &expected_council {
+        return Err(ListingError::InvalidPda.into());
+    }
+    if !council_account.data_is_empty() {
+        return Err(ListingError::AlreadyInitialized.into());
+    }
+
+    create_account(
+        admin,
+        council_account,
+        ADMIN_COUNCIL_SIZE,
+        program_id,
+        system_program,
+        &[ADMIN_COUNCIL_SEED, &[bump]],
+    )?;
+
+    let council = AdminCouncil {
+        discriminator: AdminCouncil::DISCRIMINATOR,
+        version: 1,
+        members: council_members_array(&members),
+        member_count: members.len() as u8,
+        threshold,
+        epoch: 0,
+        action_count: 0,
+        bump,
+        reserved: [0u8; 64],
+    };
+    council.serialize(&mut &mut council_account.data.borrow_mut()[..])?;
+
+    // Admin 转移给委员会 PDA
+    let previous_admin = config.admin;
+    config.admin = expected_council;
+    config.pending_admin = Pubkey::default();
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Event: AdminCouncilInitialized");
+    msg!("Council: {}", expected_council);
+    msg!("Previous admin: {}", previous_admin);
+    msg!("Threshold: {} of {}", threshold, members.len());
+
+    Ok(())
+}
+
+fn process_update_admin_council(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let council_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+
+    let config = load_listing_config(program_id, config_account)?;
+    verify_admin(council_account, &config, accounts)?;
+
+    let mut council = load_council_account(program_id, council_account)?;
+    validate_council(&members, threshold)?;
+
+    council.members = council_members_array(&members);
+    council.member_count = members.len() as u8;
+    council.threshold = threshold;
+    // 成员变更后，旧的 PendingAction 批准失效
+    council.epoch = council.epoch.checked_add(1).ok_or(ListingError::Overflow)?;
+    council.serialize(&mut &mut council_account.data.borrow_mut()[..])?;
+
+    msg!("Event: AdminCouncilUpdated");
+    msg!("Threshold: {} of {}", threshold, members.len());
+    msg!("Epoch: {}", council.epoch);
+
+    Ok(())
+}
+
+fn process_create_pending_action(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: Vec<u8>,
+    account_keys: Vec<Pubkey>,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let member = next_account_info(account_iter)?;
+    let council_account = next_account_info(account_iter)?;
+    let pending_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    let mut council = load_council_account(program_id, council_account)?;
+    let member_index = verify_council_member(member, &council)?;
+
+    // 目标必须是有效的 Listing 指令
+    ListingInstruction::try_from_slice(&instruction_data)
+        .map_err(|_| ListingError::InvalidInstruction)?;
+
+    // 验证 PendingAction PDA
+    let action_id = council.action_count;
+    let (expected_pending, bump) =
+        derive_pending_action_pda(council_account.key, action_id, program_id);
+    if pending_account.key != &expected_pending {
+        return Err(ListingError::InvalidPda.into());
+    }
+
+    create_account(
+        member,
+        pending_account,
+        PENDING_ACTION_SIZE,
+        program_id,
+        system_program,
+        &[
+            PENDING_ACTION_SEED,
+            council_account.key.as_ref(),
+            &action_id.to_le_bytes(),
+            &[bump],
+        ],
+    )?;
+
+    let action_hash = calculate_action_hash(&instruction_data, account_keys.iter());
+    let pending = PendingAction {
+        discriminator: PendingAction::DISCRIMINATOR,
+        version: 1,
+        council: *council_account.key,
+        action_id,
+        proposer: *member.key,
+        action_hash,
+        council_epoch: council.epoch,
+        approvals: 1u16 << member_index,
+        approval_count: 1,
+        created_at: get_current_timestamp()?,
+        executed: false,
+        bump,
+        reserved: [0u8; 64],
+    };
+    pending.serialize(&mut &mut pending_account.data.borrow_mut()[..])?;
+
+    council.action_count = council.action_count.checked_add(1)
+        .ok_or(ListingError::Overflow)?;
+    council.serialize(&mut &mut council_account.data.borrow_mut()[..])?;
+
+    msg!("Event: PendingActionCreated");
+    msg!("Action ID: {}", action_id);
+    msg!("Action hash: {}", Hash::new_from_array(action_hash));
+    msg!("Approvals: {} / {}", pending.approval_count, council.threshold);
+
+    Ok(())
+}
+
+fn process_approve_pending_action(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let member = next_account_info(account_iter)?;
+    let council_account = next_account_info(account_iter)?;
+    let pending_account = next_account_info(account_iter)?;
+
+    let council = load_council_account(program_id, council_account)?;
+    let member_index = verify_council_member(member, &council)?;
+    let mut pending = load_pending_action(program_id, pending_account, council_account)?;
+
+    if pending.executed || pending.council_epoch != council.epoch {
+        return Err(ListingError::PendingActionNotExecutable.into());
+    }
+
+    let bit = 1u16 << member_index;
+    if pending.approvals & bit != 0 {
+        msg!("Member already approved");
+        return Ok(());
+    }
+    pending.approvals |= bit;
+    pending.approval_count = pending.approval_count.checked_add(1)
+        .ok_or(ListingError::Overflow)?;
+    pending.serialize(&mut &mut pending_account.data.borrow_mut()[..])?;
+
+    msg!("Event: PendingActionApproved");
+    msg!("Action ID: {}", pending.action_id);
+    msg!("Approver: {}", member.key);
+    msg!("Approvals: {} / {}", pending.approval_count, council.threshold);
+
+    Ok(())
+}
+
+fn process_execute_pending_action(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: Vec<u8>,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let member = next_account_info(account_iter)?;
+    let council_account = next_account_info(account_iter)?;
+    let pending_account = next_account_info(account_iter)?;
+    let target_accounts = account_iter.as_slice();
+
+    let council = load_council_account(program_id, council_account)?;
+    verify_council_member(member, &council)?;
+    let mut pending = load_pending_action(program_id, pending_account, council_account)?;
+
+    if pending.executed || pending.council_epoch != council.epoch {
+        return Err(ListingError::PendingActionNotExecutable.into());
+    }
+    if pending.approval_count < council.threshold {
+        msg!("Approvals: {} / {}", pending.approval_count, council.threshold);
+        return Err(ListingError::InsufficientApprovals.into());
+    }
+
+    // 指令数据和账户列表必须与批准时一致
+    let action_hash = calculate_action_hash(
+        &instruction_data,
+        target_accounts.iter().map(|a| a.key),
+    );
+    if action_hash != pending.action_hash {
+        msg!("Action hash mismatch");
+        return Err(ListingError::InvalidInstruction.into());
+    }
+
+    // 先标记已执行，防止重入重复执行
+    pending.executed = true;
+    pending.serialize(&mut &mut pending_account.data.borrow_mut()[..])?;
+
+    // 委员会 PDA 作为签名者调用本程序
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: target_accounts
+            .iter()
+            .map(|a| AccountMeta {
+                pubkey: *a.key,
+                is_signer: a.is_signer || a.key == council_account.key,
+                is_writable: a.is_writable,
+            })
+            .collect(),
+        data: instruction_data,
+    };
+    invoke_signed(&ix, accounts, &[&[ADMIN_COUNCIL_SEED, &[council.bump]]])?;
+
+    msg!("Event: PendingActionExecuted");
+    msg!("Action ID: {}", pending.action_id);
+
+    Ok(())
+}
+
+// =============================================================================
+// Perp 参数时间锁指令处理
+// =============================================================================
+
+/// 加载并验证本程序的 ScheduledParamChange
+fn load_param_change(
+    program_id: &Pubkey,
+    change_account: &AccountInfo,
+) -> Result<ScheduledParamChange, ProgramError> {
+    if change_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    // Option 字段使序列化长度小于账户大小，使用 deserialize()
+    let change = ScheduledParamChange::deserialize(&mut &change_account.data.borrow()[..])?;
+    if change.discriminator != ScheduledParamChange::DISCRIMINATOR {
+        return Err(ListingError::InvalidAccount.into());
+    }
+    Ok(change)
+}
+
+fn process_schedule_perp_param_change(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_leverage: Option<u8>,
+    initial_margin_rate_e6: Option<u32>,
+    maintenance_margin_rate_e6: Option<u32>,
+    max_open_interest_e6: Option<u64>,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let risk_manager = next_account_info(account_iter)?;
+    let market_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let change_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    // 加载配置并验证 RiskManager
+    let config = load_listing_config(program_id, config_account)?;
+    verify_role(risk_manager, &config, Role::RiskManager, accounts)?;
+
+    if max_leverage.is_none()
+        && initial_margin_rate_e6.is_none()
+        && maintenance_margin_rate_e6.is_none()
+        && max_open_interest_e6.is_none()
+    {
+        return Err(ListingError::InvalidInstruction.into());
+    }
+
+    // 加载 Market，并按当前参数预校验
+    if market_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let market = PerpMarket::try_from_slice(&market_account.data.borrow())?;
+    apply_perp_risk_params(
+        &mut market.clone(),
+        max_leverage,
+        initial_margin_rate_e6,
+        maintenance_margin_rate_e6,
+        max_open_interest_e6,
+    )?;
+
+    // 验证 ScheduledParamChange PDA
+    let (expected_change, bump) = derive_param_change_pda(market_account.key, program_id);
+    if change_account.key != &expected_change {
+        return Err(ListingError::InvalidPda.into());
+    }
+
+    if change_account.data_is_empty() {
+        let payer = resolve_payer(risk_manager, account_iter)?;
+        create_account(
+            payer,
+            change_account,
+            PARAM_CHANGE_SIZE,
+            program_id,
+            system_program,
+            &[PARAM_CHANGE_SEED, market_account.key.as_ref(), &[bump]],
+        )?;
+    } else if load_param_change(program_id, change_account)?.is_pending {
+        // 同一市场只允许一个待生效的变更
+        return Err(ListingError::ParamChangeAlreadyScheduled.into());
+    }
+
+    let current_ts = get_current_timestamp()?;
+    let effective_at = current_ts
+        .checked_add(config.param_change_delay_seconds as i64)
+        .ok_or(ListingError::Overflow)?;
+
+    let change = ScheduledParamChange {
+        discriminator: ScheduledParamChange::DISCRIMINATOR,
+        version: 1,
+        market: *market_account.key,
+        market_index: market.market_index,
+        max_leverage,
+        initial_margin_rate_e6,
+        maintenance_margin_rate_e6,
+        max_open_interest_e6,
+        scheduled_by: *risk_manager.key,
+        scheduled_at: current_ts,
+        effective_at,
+        is_pending: true,
+        bump,
+        reserved: [0u8; 64],
+    };
+    change.serialize(&mut &mut change_account.data.borrow_mut()[..])?;
+
+    msg!("Event: PerpParamChangeScheduled");
+    msg!("Market index: {}", market.market_index);
+    msg!("Effective at: {}", effective_at);
+
+    Ok(())
+}
+
+fn process_execute_param_change(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let caller = next_account_info(account_iter)?;
+    let market_account = next_account_info(account_iter)?;
+    let change_account = next_account_info(account_iter)?;
+
+    // 验证签名
+    if !caller.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut change = load_param_change(program_id, change_account)?;
+    if change.market != *market_account.key || market_account.owner != program_id {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    // 验证时间锁已过，并按执行时的市场状态重新校验并写入
+    let current_ts = get_current_timestamp()?;
+    let mut market = PerpMarket::try_from_slice(&market_account.data.borrow())?;
+    apply_scheduled_param_change(&mut market, &mut change, current_ts)?;
+    market.serialize(&mut &mut market_account.data.borrow_mut()[..])?;
+
+    change.serialize(&mut &mut change_account.data.borrow_mut()[..])?;
+
+    msg!("Event: PerpParamChangeExecuted");
+    msg!("Market index: {}", market.market_index);
+
+    Ok(())
+}
+
+fn process_cancel_param_change(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let risk_manager = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let change_account = next_account_info(account_iter)?;
+
+    // 加载配置并验证 RiskManager
+    let config = load_listing_config(program_id, config_account)?;
+    verify_role(risk_manager, &config, Role::RiskManager, accounts)?;
+
+    let mut change = load_param_change(program_id, change_account)?;
+    if !change.is_pending {
+        return Err(ListingError::NoScheduledParamChange.into());
+    }
+
+    change.is_pending = false;
+    change.serialize(&mut &mut change_account.data.borrow_mut()[..])?;
+
+    msg!("Event: PerpParamChangeCancelled");
+    msg!("Market index: {}", change.market_index);
+
+    Ok(())
+}
+
+fn process_set_param_change_delay(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    delay_seconds: u32,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    config.param_change_delay_seconds = delay_seconds;
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Param change delay updated to: {} seconds", delay_seconds);
+
+    Ok(())
+}
+
+// =============================================================================
+// 社区投票指令处理
+// =============================================================================
+
+/// 验证提案处于争议投票窗口内 (review_deadline, voting_deadline]
+#[allow(clippy::too_many_arguments)]
+fn verify_voting_open(
+    proposal_type: ProposalType,
+    status: ProposalStatus,
+    objection_count: u16,
+    objection_stake: u64,
+    stake_amount: u64,
+    review_deadline: i64,
+    voting_deadline: i64,
+    voting_period_seconds: u32,
+    current_ts: i64,
+) -> ProgramResult {
+    if status != ProposalStatus::Pending {
+        return Err(ListingError::ProposalNotPending.into());
+    }
+    if !is_proposal_contested(proposal_type, objection_count, objection_stake, stake_amount) {
+        return Err(ListingError::ProposalNotContested.into());
+    }
+    let voting_deadline =
+        effective_voting_deadline(review_deadline, voting_deadline, voting_period_seconds);
+    if current_ts <= review_deadline || current_ts > voting_deadline {
+        msg!("Voting window: ({}, {}], now: {}", review_deadline, voting_deadline, current_ts);
+        return Err(ListingError::VotingNotOpen.into());
+    }
+    Ok(())
+}
+
+/// 验证争议提案投票已结束，可由 ResolveProposal 结算
+#[allow(clippy::too_many_arguments)]
+fn verify_voting_ended(
+    proposal_type: ProposalType,
+    status: ProposalStatus,
+    objection_count: u16,
+    objection_stake: u64,
+    stake_amount: u64,
+    review_deadline: i64,
+    voting_deadline: i64,
+    voting_period_seconds: u32,
+    current_ts: i64,
+) -> ProgramResult {
+    if status != ProposalStatus::Pending {
+        return Err(ListingError::ProposalNotPending.into());
+    }
+    if !is_proposal_contested(proposal_type, objection_count, objection_stake, stake_amount) {
+        return Err(ListingError::ProposalNotContested.into());
+    }
+    if !is_voting_ended(review_deadline, voting_deadline, voting_period_seconds, current_ts) {
+        msg!("Voting ends at: {}",
+             effective_voting_deadline(review_deadline, voting_deadline, voting_period_seconds));
+        return Err(ListingError::VotingNotEnded.into());
+    }
+    Ok(())
+}
+
+/// 累加票数
+fn add_vote(
+    votes_for: &mut u64,
+    votes_against: &mut u64,
+    support: bool,
+    amount: u64,
+) -> ProgramResult {
+    let tally = if support { votes_for } else { votes_against };
+    *tally = tally.checked_add(amount).ok_or(ListingError::Overflow)?;
+    Ok(())
+}
+
+/// 创建或累加投票者的 Vote PDA（不允许改变立场）
+#[allow(clippy::too_many_arguments)]
+fn record_vote<'a>(
+    program_id: &Pubkey,
+    voter: &AccountInfo<'a>,
+    vote_account: &AccountInfo<'a>,
+    proposal: &Pubkey,
+    proposal_type: ProposalType,
+    support: bool,
+    amount: u64,
+    system_program: &AccountInfo<'a>,
+    current_ts: i64,
+) -> ProgramResult {
+    // 验证 Vote PDA
+    let (expected_vote, bump) = derive_vote_pda(proposal, voter.key, program_id);
+    if vote_account.key != &expected_vote {
+        return Err(ListingError::InvalidPda.into());
+    }
+
+    // 已存在：同一立场累加
+    if !vote_account.data_is_empty() {
+        let mut vote = Vote::try_from_slice(&vote_account.data.borrow())?;
+        if vote.support != support {
+            return Err(ListingError::VoteSideMismatch.into());
+        }
+        vote.amount = vote.amount
+            .checked_add(amount)
+            .ok_or(ListingError::Overflow)?;
+        vote.serialize(&mut &mut vote_account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    // 首次投票：创建 Vote 账户
+    create_account(
+        voter,
+        vote_account,
+        VOTE_SIZE,
+        program_id,
+        system_program,
+        &[VOTE_SEED, proposal.as_ref(), voter.key.as_ref(), &[bump]],
+    )?;
+
+    let vote = Vote {
+        discriminator: Vote::DISCRIMINATOR,
+        version: 1,
+        proposal_type,
+        proposal: *proposal,
+        voter: *voter.key,
+        support,
+        amount,
+        created_at: current_ts,
+        claimed: false,
+        bump,
+        reserved: [0u8; 64],
+    };
+
+    vote.serialize(&mut &mut vote_account.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+fn process_cast_vote(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    proposal_type: ProposalType,
+    support: bool,
+    amount: u64,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let voter = next_account_info(account_iter)?;
+    let proposal_account = next_account_info(account_iter)?;
+    let vote_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let treasury_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    // 验证签名
+    if !voter.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // 加载配置
+    let mut config = load_listing_config(program_id, config_account)?;
+
+    // 验证 Treasury
+    if treasury_account.key != &config.treasury {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    // 验证提案账户 owner
+    if proposal_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+
+    // 验证金额
+    if amount == 0 {
+        return Err(ListingError::InvalidAmount.into());
+    }
+
+    // 验证投票窗口并累加票数
+    let current_ts = get_current_timestamp()?;
+    let (votes_for, votes_against, version, stake_mode) = match proposal_type {
+        ProposalType::Token => {
+            let mut proposal = TokenProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            verify_voting_open(
+                proposal_type,
+                proposal.status,
+                proposal.objection_count,
+                proposal.objection_stake,
+                proposal.stake_amount,
+                proposal.review_deadline,
+                proposal.voting_deadline,
+                config.voting_period_seconds,
+                current_ts,
+            )?;
+            add_vote(&mut proposal.votes_for, &mut proposal.votes_against, support, amount)?;
+            proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+            (proposal.votes_for, proposal.votes_against, proposal.version, proposal.stake_mode)
+        }
+        ProposalType::SpotMarket => {
+            let mut proposal = SpotMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            verify_voting_open(
+                proposal_type,
+                proposal.status,
+                proposal.objection_count,
+                proposal.objection_stake,
+                proposal.stake_amount,
+                proposal.review_deadline,
+                proposal.voting_deadline,
+                config.voting_period_seconds,
+                current_ts,
+            )?;
+            add_vote(&mut proposal.votes_for, &mut proposal.votes_against, support, amount)?;
+            proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+            (proposal.votes_for, proposal.votes_against, proposal.version, proposal.stake_mode)
+        }
+        ProposalType::PerpMarket => {
+            let mut proposal = PerpMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            verify_voting_open(
+                proposal_type,
+                proposal.status,
+                proposal.objection_count,
+                proposal.objection_stake,
+                proposal.stake_amount,
+                proposal.review_deadline,
+                proposal.voting_deadline,
+                config.voting_period_seconds,
+                current_ts,
+            )?;
+            add_vote(&mut proposal.votes_for, &mut proposal.votes_against, support, amount)?;
+            proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+            (proposal.votes_for, proposal.votes_against, proposal.version, proposal.stake_mode)
+        }
+    };
+
+    // 检查余额
+    if stake_mode == StakeMode::Native && voter.lamports() < amount {
+        msg!("Insufficient balance for vote stake");
+        return Err(ListingError::InsufficientStake.into());
+    }
+
+    // 转移投票质押到提案托管
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
+        treasury_account,
+        version,
+        stake_mode,
+        account_iter,
+        true,
+    )?;
+    deposit_stake(
+        &mut config,
+        &vault,
+        voter,
+        system_program,
+        amount,
+    )?;
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    // 记录 Vote PDA
+    record_vote(
+        program_id,
+        voter,
+        vote_account,
+        proposal_account.key,
+        proposal_type,
+        support,
+        amount,
+        system_program,
+        current_ts,
+    )?;
+
+    msg!("Event: VoteCast");
+    msg!("Voter: {}", voter.key);
+    msg!("Support: {}", support);
+    msg!("Stake: {} N1024", amount / 1_000_000_000);
+    msg!("Votes for: {} N1024, against: {} N1024",
+         votes_for / 1_000_000_000, votes_against / 1_000_000_000);
+
+    Ok(())
+}
+
+fn process_resolve_proposal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    proposal_type: ProposalType,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let caller = next_account_info(account_iter)?;
+    let proposal_account = next_account_info(account_iter)?;
+    let listing_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+    let treasury_account = next_account_info(account_iter)?;
+    let slash_schedule_account = next_account_info(account_iter)?;
+
+    // 验证签名
+    if !caller.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // 验证提案账户 owner
+    if proposal_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+
+    // 加载配置
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_not_paused(&config, PAUSE_FINALIZATION)?;
+
+    // 验证 Treasury
+    if treasury_account.key != &config.treasury {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    let current_ts = get_current_timestamp()?;
+    let slash_bps = load_slash_schedule(program_id, slash_schedule_account)?
+        .vote_rejection_slash_bps;
+
+    let (votes_for, votes_against, status) = match proposal_type {
+        ProposalType::Token => {
+            let mut proposal = TokenProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            let (mint_index_account, symbol_index_account) =
+                next_token_index_accounts(account_iter, proposal.asset_kind)?;
+            verify_voting_ended(
+                proposal_type,
+                proposal.status,
+                proposal.objection_count,
+                proposal.objection_stake,
+                proposal.stake_amount,
+                proposal.review_deadline,
+                proposal.voting_deadline,
+                config.voting_period_seconds,
+                current_ts,
+            )?;
+            if is_vote_approved(proposal.votes_for, proposal.votes_against) {
+                // 高风险 Token-2022 扩展不能经投票上架，提案保持待审核
+                if requires_token_review(proposal.risk_flags) {
+                    msg!("Vote approved but risk flags {:#04x} require Reviewer approval",
+                         proposal.risk_flags);
+                    return Err(ListingError::ManualReviewRequired.into());
+                }
+                let token_index = register_approved_token(
+                    program_id,
+                    caller,
+                    listing_account,
+                    mint_index_account,
+                    symbol_index_account,
+                    system_program,
+                    &mut config,
+                    &mut proposal,
+                    proposal_account,
+                    current_ts,
+                )?;
+                msg!("Token index: {}", token_index);
+            } else {
+                let vault = load_stake_vault(
+                    program_id,
+                    proposal_account,
+                    treasury_account,
+                    proposal.version,
+                    proposal.stake_mode,
+                    account_iter,
+                    false,
+                )?;
+                let (slash_amount, objector_reward) = settle_rejection(
+                    &mut config,
+                    &vault,
+                    proposal.stake_amount,
+                    proposal.objection_stake,
+                    slash_bps,
+                    false,
+                )?;
+                proposal.status = ProposalStatus::Rejected;
+                proposal.slash_amount = slash_amount;
+                proposal.objector_reward = objector_reward;
+            }
+            proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+            (proposal.votes_for, proposal.votes_against, proposal.status)
+        }
+        ProposalType::SpotMarket => {
+            let (pair_index_account, current_market) = next_pair_accounts(account_iter)?;
+            let mut proposal = SpotMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            verify_voting_ended(
+                proposal_type,
+                proposal.status,
+                proposal.objection_count,
+                proposal.objection_stake,
+                proposal.stake_amount,
+                proposal.review_deadline,
+                proposal.voting_deadline,
+                config.voting_period_seconds,
+                current_ts,
+            )?;
+            if is_vote_approved(proposal.votes_for, proposal.votes_against) {
+                let market_index = list_approved_spot_market(
+                    program_id,
+                    caller,
+                    listing_account,
+                    pair_index_account,
+                    current_market,
+                    system_program,
+                    &mut config,
+                    &mut proposal,
+                    current_ts,
+                )?;
+                msg!("Market index: {}", market_index);
+            } else {
+                let vault = load_stake_vault(
+                    program_id,
+                    proposal_account,
+                    treasury_account,
+                    proposal.version,
+                    proposal.stake_mode,
+                    account_iter,
+                    false,
+                )?;
+                let (slash_amount, objector_reward) = settle_rejection(
+                    &mut config,
+                    &vault,
+                    proposal.stake_amount,
+                    proposal.objection_stake,
+                    slash_bps,
+                    false,
+                )?;
+                proposal.status = ProposalStatus::Rejected;
+                proposal.slash_amount = slash_amount;
+                proposal.objector_reward = objector_reward;
+            }
+            proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+            (proposal.votes_for, proposal.votes_against, proposal.status)
+        }
+        ProposalType::PerpMarket => {
+            let (pair_index_account, current_market) = next_pair_accounts(account_iter)?;
+            let mut proposal = PerpMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            verify_voting_ended(
+                proposal_type,
+                proposal.status,
+                proposal.objection_count,
+                proposal.objection_stake,
+                proposal.stake_amount,
+                proposal.review_deadline,
+                proposal.voting_deadline,
+                config.voting_period_seconds,
+                current_ts,
+            )?;
+            if is_vote_approved(proposal.votes_for, proposal.votes_against) {
+                let oracles = next_perp_oracle_accounts(account_iter)?;
+                let market_index = list_approved_perp_market(
+                    program_id,
+                    caller,
+                    listing_account,
+                    pair_index_account,
+                    current_market,
+                    &oracles,
+                    system_program,
+                    &mut config,
+                    &mut proposal,
+                    proposal_account,
+                    current_ts,
+                )?;
+                msg!("Market index: {}", market_index);
+            } else {
+                let vault = load_stake_vault(
+                    program_id,
+                    proposal_account,
+                    treasury_account,
+                    proposal.version,
+                    proposal.stake_mode,
+                    account_iter,
+                    false,
+                )?;
+                let (slash_amount, objector_reward) = settle_rejection(
+                    &mut config,
+                    &vault,
+                    proposal.stake_amount,
+                    proposal.objection_stake,
+                    slash_bps,
+                    false,
+                )?;
+                proposal.status = ProposalStatus::Rejected;
+                proposal.slash_amount = slash_amount;
+                proposal.objector_reward = objector_reward;
+            }
+            proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+            (proposal.votes_for, proposal.votes_against, proposal.status)
+        }
+    };
+
+    // 保存配置（提案已离开待审核状态）
+    release_pending_proposal(&mut config);
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Event: ProposalResolved");
+    msg!("Proposal type: {:?}", proposal_type);
+    msg!("Result: {:?}", status);
+    msg!("Votes for: {} N1024, against: {} N1024",
+         votes_for / 1_000_000_000, votes_against / 1_000_000_000);
+
+    Ok(())
+}
+
+fn process_claim_vote_stake(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let voter = next_account_info(account_iter)?;
+    let vote_account = next_account_info(account_iter)?;
+    let proposal_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let treasury_account = next_account_info(account_iter)?;
+
+    // 验证签名
+    if !voter.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // 加载配置
+    let mut config = load_listing_config(program_id, config_account)?;
+
+    // 验证 Treasury
+    if treasury_account.key != &config.treasury {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    // 验证账户 owner
+    if vote_account.owner != program_id || proposal_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+
+    // 加载 Vote
+    let mut vote = Vote::try_from_slice(&vote_account.data.borrow())?;
+
+    // 验证投票者和提案
+    if &vote.voter != voter.key {
+        return Err(ListingError::Unauthorized.into());
+    }
+    if &vote.proposal != proposal_account.key {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    // 验证未取回
+    if vote.claimed {
+        return Err(ListingError::StakeAlreadyClaimed.into());
+    }
+
+    // 提案结束后才能取回
+    let (status, version, stake_mode) = match vote.proposal_type {
+        ProposalType::Token => {
+            let proposal = TokenProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            (proposal.status, proposal.version, proposal.stake_mode)
+        }
+        ProposalType::SpotMarket => {
+            let proposal = SpotMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            (proposal.status, proposal.version, proposal.stake_mode)
+        }
+        ProposalType::PerpMarket => {
+            let proposal = PerpMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            (proposal.status, proposal.version, proposal.stake_mode)
+        }
+    };
+    if status == ProposalStatus::Pending {
+        return Err(ListingError::ProposalNotResolved.into());
+    }
+
+    // 从提案托管退还质押
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
+        treasury_account,
+        version,
+        stake_mode,
+        account_iter,
+        true,
+    )?;
+    release_stake(
+        &mut config,
+        &vault,
+        voter,
+        vote.amount,
+    )?;
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    // 标记已取回
+    vote.claimed = true;
+    vote.serialize(&mut &mut vote_account.data.borrow_mut()[..])?;
+
+    msg!("Vote stake claimed");
+    msg!("Proposal status: {:?}", status);
+    msg!("Refund: {} N1024", vote.amount / 1_000_000_000);
+
+    Ok(())
+}
+
+fn process_set_voting_period(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    voting_period_seconds: u32,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    config.voting_period_seconds = voting_period_seconds;
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Voting period updated to: {} seconds", voting_period_seconds);
+
+    Ok(())
+}
+
+// =============================================================================
+// 申诉指令处理
+// =============================================================================
+
+/// 校验申诉条件，返回申诉保证金并将提案置为 Appealed
+#[allow(clippy::too_many_arguments)]
+fn take_appeal(
+    proposer: &Pubkey,
+    proposal_proposer: &Pubkey,
+    status: &mut ProposalStatus,
+    rejected_at: i64,
+    appeal_bond: &mut u64,
+    stake_claimed: bool,
+    stake_amount: u64,
+    config: &ListingConfig,
+    current_ts: i64,
+) -> Result<u64, ProgramError> {
+    // 验证 Proposer
+    if proposal_proposer != proposer {
+        return Err(ListingError::NotProposer.into());
+    }
+
+    // 验证申诉窗口
+    if !is_appeal_window_open(
+        *status,
+        rejected_at,
+        *appeal_bond,
+        stake_claimed,
+        config.appeal_window_seconds,
+        current_ts,
+    ) {
+        return Err(ListingError::AppealNotAllowed.into());
+    }
+
+    let bond = stake_amount
+        .checked_mul(config.appeal_bond_percentage as u64)
+        .ok_or(ListingError::Overflow)?
+        / 100;
+    if bond == 0 {
+        return Err(ListingError::InvalidAmount.into());
+    }
+
+    *status = ProposalStatus::Appealed;
+    *appeal_bond = bond;
+
+    Ok(bond)
+}
+
+/// 撤销拒绝罚没（settle_rejection 的逆操作）
+///
+/// 罚没仍在托管中时尚未计入统计，直接清除；旧提案的罚没已转入 Treasury，
+/// 需退回提案托管并回退统计
+fn revert_rejection(
+    config: &mut ListingConfig,
+    vault: &StakeVault,
+    slash_amount: u64,
+    objector_reward: u64,
+    slash_pending: &mut bool,
+) -> ProgramResult {
+    if !std::mem::take(slash_pending) {
+        restore_stake(
+            config,
+            vault,
+            slash_amount - objector_reward,
+        )?;
+        config.total_slashed_lamports = config.total_slashed_lamports
+            .checked_sub(slash_amount)
+            .ok_or(ListingError::Underflow)?;
+    }
+    Ok(())
+}
+
+/// 取出留在提案托管中的拒绝罚没并计入统计（反对者奖励部分仍留给反对者领取），
+/// 返回应转入 Treasury 的金额
+fn take_pending_slash(
+    config: &mut ListingConfig,
+    slash_pending: &mut bool,
+    slash_amount: u64,
+    objector_reward: u64,
+) -> Result<u64, ProgramError> {
+    if !std::mem::take(slash_pending) {
+        return Ok(0);
+    }
+    config.total_slashed_lamports = config.total_slashed_lamports
+        .checked_add(slash_amount)
+        .ok_or(ListingError::Overflow)?;
+    Ok(slash_amount - objector_reward)
+}
+
+/// 验证提案处于申诉中、裁决期限未过且 Proposer 账户匹配
+fn verify_appealed(
+    proposer: &Pubkey,
+    proposal_proposer: &Pubkey,
+    status: ProposalStatus,
+    rejected_at: i64,
+    appeal_window_seconds: u32,
+    current_ts: i64,
+) -> ProgramResult {
+    if status != ProposalStatus::Appealed {
+        return Err(ListingError::ProposalNotAppealed.into());
+    }
+    if proposal_proposer != proposer {
+        return Err(ListingError::NotProposer.into());
+    }
+    if is_appeal_expired(status, rejected_at, appeal_window_seconds, current_ts) {
+        msg!("Appeal expired; the proposer can reclaim the stake via ClaimRejectedStake");
+        return Err(ListingError::AppealExpired.into());
+    }
+    Ok(())
+}
+
+fn process_file_appeal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    proposal_type: ProposalType,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let proposer = next_account_info(account_iter)?;
+    let proposal_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let treasury_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    // 验证签名
+    if !proposer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // 加载配置
+    let mut config = load_listing_config(program_id, config_account)?;
+
+    // 验证 Treasury
+    if treasury_account.key != &config.treasury {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    // 验证提案账户 owner
+    if proposal_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+
+    // 加载提案并进入申诉状态
+    let current_ts = get_current_timestamp()?;
+    let (bond, version, stake_mode) = match proposal_type {
+        ProposalType::Token => {
+            let mut proposal = TokenProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            let bond = take_appeal(
+                proposer.key,
+                &proposal.proposer,
+                &mut proposal.status,
+                proposal.rejected_at,
+                &mut proposal.appeal_bond,
+                proposal.stake_claimed,
+                proposal.stake_amount,
+                &config,
+                current_ts,
+            )?;
+            proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+            (bond, proposal.version, proposal.stake_mode)
+        }
+        ProposalType::SpotMarket => {
+            let mut proposal = SpotMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            let bond = take_appeal(
+                proposer.key,
+                &proposal.proposer,
+                &mut proposal.status,
+                proposal.rejected_at,
+                &mut proposal.appeal_bond,
+                proposal.stake_claimed,
+                proposal.stake_amount,
+                &config,
+                current_ts,
+            )?;
+            proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+            (bond, proposal.version, proposal.stake_mode)
+        }
+        ProposalType::PerpMarket => {
+            let mut proposal = PerpMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            let bond = take_appeal(
+                proposer.key,
+                &proposal.proposer,
+                &mut proposal.status,
+                proposal.rejected_at,
+                &mut proposal.appeal_bond,
+                proposal.stake_claimed,
+                proposal.stake_amount,
+                &config,
+                current_ts,
+            )?;
+            proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+            (bond, proposal.version, proposal.stake_mode)
+        }
+    };
+
+    // 检查余额
+    if stake_mode == StakeMode::Native && proposer.lamports() < bond {
+        msg!("Insufficient balance for appeal bond");
+        return Err(ListingError::InsufficientStake.into());
+    }
+
+    // 转移申诉保证金到提案托管
+    let vault = load_stake_vault(
+        program_id,
+        proposal_account,
+        treasury_account,
+        version,
+        stake_mode,
+        account_iter,
+        true,
+    )?;
+    deposit_stake(
+        &mut config,
+        &vault,
+        proposer,
+        system_program,
+        bond,
+    )?;
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Event: AppealFiled");
+    msg!("Proposal type: {:?}", proposal_type);
+    msg!("Proposer: {}", proposer.key);
+    msg!("Bond: {} N1024", bond / 1_000_000_000);
+
+    Ok(())
+}
+
+fn process_resolve_appeal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    proposal_type: ProposalType,
+    overturn: bool,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let proposal_account = next_account_info(account_iter)?;
+    let listing_account = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+    let treasury_account = next_account_info(account_iter)?;
+    let proposer_account = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    // 加载配置并验证 Admin（申诉由 Admin / 多签委员会裁决，而非作出拒绝的 Reviewer）
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    // 验证 Treasury
+    if treasury_account.key != &config.treasury {
+        return Err(ListingError::InvalidAccount.into());
+    }
+
+    // 验证提案账户 owner
+    if proposal_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+
+    // 推翻时创建上架账户（多签委员会裁决时由额外的 Payer 支付租金）
+    let payer = resolve_payer(admin, account_iter)?;
+    let current_ts = get_current_timestamp()?;
+
+    // 维持拒绝时，留在托管中的罚没随保证金一起转入 Treasury
+    let mut pending_slash = 0;
+    let (bond, vault) = match proposal_type {
+        ProposalType::Token => {
+            let mut proposal = TokenProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            let (mint_index_account, symbol_index_account) =
+                next_token_index_accounts(account_iter, proposal.asset_kind)?;
+            verify_appealed(
+                proposer_account.key,
+                &proposal.proposer,
+                proposal.status,
+                proposal.rejected_at,
+                config.appeal_window_seconds,
+                current_ts,
+            )?;
+            let vault = load_stake_vault(
+                program_id,
+                proposal_account,
+                treasury_account,
+                proposal.version,
+                proposal.stake_mode,
+                account_iter,
+                overturn,
+            )?;
+            if overturn {
+                revert_rejection(
+                    &mut config,
+                    &vault,
+                    proposal.slash_amount,
+                    proposal.objector_reward,
+                    &mut proposal.slash_pending,
+                )?;
+                proposal.slash_amount = 0;
+                proposal.objector_reward = 0;
+                let token_index = register_approved_token(
+                    program_id,
+                    payer,
+                    listing_account,
+                    mint_index_account,
+                    symbol_index_account,
+                    system_program,
+                    &mut config,
+                    &mut proposal,
+                    proposal_account,
+                    current_ts,
+                )?;
+                msg!("Token index: {}", token_index);
+            } else {
+                proposal.status = ProposalStatus::Rejected;
+                pending_slash = take_pending_slash(
+                    &mut config,
+                    &mut proposal.slash_pending,
+                    proposal.slash_amount,
+                    proposal.objector_reward,
+                )?;
+            }
+            proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+            (proposal.appeal_bond, vault)
+        }
+        ProposalType::SpotMarket => {
+            let (pair_index_account, current_market) = next_pair_accounts(account_iter)?;
+            let mut proposal = SpotMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            verify_appealed(
+                proposer_account.key,
+                &proposal.proposer,
+                proposal.status,
+                proposal.rejected_at,
+                config.appeal_window_seconds,
+                current_ts,
+            )?;
+            let vault = load_stake_vault(
+                program_id,
+                proposal_account,
+                treasury_account,
+                proposal.version,
+                proposal.stake_mode,
+                account_iter,
+                overturn,
+            )?;
+            if overturn {
+                revert_rejection(
+                    &mut config,
+                    &vault,
+                    proposal.slash_amount,
+                    proposal.objector_reward,
+                    &mut proposal.slash_pending,
+                )?;
+                proposal.slash_amount = 0;
+                proposal.objector_reward = 0;
+                let market_index = list_approved_spot_market(
+                    program_id,
+                    payer,
+                    listing_account,
+                    pair_index_account,
+                    current_market,
+                    system_program,
+                    &mut config,
+                    &mut proposal,
+                    current_ts,
+                )?;
+                msg!("Market index: {}", market_index);
+            } else {
+                proposal.status = ProposalStatus::Rejected;
+                pending_slash = take_pending_slash(
+                    &mut config,
+                    &mut proposal.slash_pending,
+                    proposal.slash_amount,
+                    proposal.objector_reward,
+                )?;
+            }
+            proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+            (proposal.appeal_bond, vault)
+        }
+        ProposalType::PerpMarket => {
+            let (pair_index_account, current_market) = next_pair_accounts(account_iter)?;
+            let oracles = if overturn {
+                Some(next_perp_oracle_accounts(account_iter)?)
+            } else {
+                None
+            };
+            let mut proposal = PerpMarketProposal::deserialize(&mut &proposal_account.data.borrow()[..])?;
+            verify_appealed(
+                proposer_account.key,
+                &proposal.proposer,
+                proposal.status,
+                proposal.rejected_at,
+                config.appeal_window_seconds,
+                current_ts,
+            )?;
+            let vault = load_stake_vault(
+                program_id,
+                proposal_account,
+                treasury_account,
+                proposal.version,
+                proposal.stake_mode,
+                account_iter,
+                overturn,
+            )?;
+            if let Some(oracles) = &oracles {
+                revert_rejection(
+                    &mut config,
+                    &vault,
+                    proposal.slash_amount,
+                    proposal.objector_reward,
+                    &mut proposal.slash_pending,
+                )?;
+                proposal.slash_amount = 0;
+                proposal.objector_reward = 0;
+                let market_index = list_approved_perp_market(
+                    program_id,
+                    payer,
+                    listing_account,
+                    pair_index_account,
+                    current_market,
+                    oracles,
+                    system_program,
+                    &mut config,
+                    &mut proposal,
+                    proposal_account,
+                    current_ts,
+                )?;
+                msg!("Market index: {}", market_index);
+            } else {
+                proposal.status = ProposalStatus::Rejected;
+                pending_slash = take_pending_slash(
+                    &mut config,
+                    &mut proposal.slash_pending,
+                    proposal.slash_amount,
+                    proposal.objector_reward,
+                )?;
+            }
+            proposal.serialize(&mut &mut proposal_account.data.borrow_mut()[..])?;
+            (proposal.appeal_bond, vault)
+        }
+    };
+
+    if overturn {
+        // 退还保证金
+        release_stake(
+            &mut config,
+            &vault,
+            proposer_account,
+            bond,
+        )?;
+    } else {
+        // 保证金计入罚没
+        let slash = bond.checked_add(pending_slash).ok_or(ListingError::Overflow)?;
+        slash_stake(
+            &mut config,
+            &vault,
+            slash,
+        )?;
+        config.total_slashed_lamports = config.total_slashed_lamports
+            .checked_add(bond)
+            .ok_or(ListingError::Overflow)?;
+    }
+
+    // 保存配置
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Event: AppealResolved");
+    msg!("Proposal type: {:?}", proposal_type);
+    msg!("Overturned: {}", overturn);
+    msg!("Bond: {} N1024", bond / 1_000_000_000);
+
+    Ok(())
+}
+
+fn process_update_appeal_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    appeal_window_seconds: Option<u32>,
+    appeal_bond_percentage: Option<u8>,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let admin = next_account_info(account_iter)?;
+    let config_account = next_account_info(account_iter)?;
+
+    let mut config = load_listing_config(program_id, config_account)?;
+    verify_admin(admin, &config, accounts)?;
+
+    if let Some(window) = appeal_window_seconds {
+        config.appeal_window_seconds = window;
+    }
+    if let Some(percentage) = appeal_bond_percentage {
+        if percentage > 100 {
+            return Err(ListingError::InvalidAmount.into());
+        }
+        config.appeal_bond_percentage = percentage;
+    }
+
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    msg!("Appeal config updated");
+
+    Ok(())
+}
@@ -6,6 +6,16 @@
 //! 使用原生 N1024 (lamports) 进行质押，不使用 SPL Token。
 //! 这样用户可以直接使用 1024Chain 原生代币进行质押，无需 wrap。
 //!
+//! 提案、反对、投票和申诉质押托管在各自提案账户的 lamports 中（超出租金豁免的部分），
+//! Treasury 只接收罚没/没收资金。`version < ESCROW_PROPOSAL_VERSION` 的旧提案
+//! 仍托管在 Treasury 并计入 `total_staked_lamports`。
+//!
+//! `ListingConfig.stake_mode = Spl` 时，新提案改用 SPL 质押代币：质押托管在
+//! 提案专属的 token 账户（Seeds: ["stake_escrow", proposal]），罚没转入
+//! Treasury 质押 token 账户（Seeds: ["treasury_stake"]），两者均由
+//! Stake Authority PDA（Seeds: ["stake_authority"]）签名转出。
+//! 提案创建时记录 `stake_mode`，之后切换模式不影响已有提案。
+//!
 //! ## PDA 列表
 //! - `ListingConfig`: 全局配置
 //! - `TokenRegistry`: 已注册 Token (PLP-1)
@@ -15,6 +25,15 @@
 //! - `PerpMarket`: Perp 市场配置 (PLP-3)
 //! - `PerpMarketProposal`: Perp 市场提案 (PLP-3)
 //! - `LiquidityPool`: 初始流动性池 (PLP-4)
+//! - `Objection`: 反对者质押记录 (PLP-1/2/3)
+//! - `AdminCouncil`: Admin 多签委员会
+//! - `PendingAction`: 多签待执行操作
+//! - `ScheduledParamChange`: Perp 风险参数时间锁变更
+//! - `Vote`: 争议提案社区投票记录 (PLP-1/2/3)
+//! - `SlashSchedule`: 罚没比例表
+//! - `StakePricing`: 动态质押定价参数
+//! - `TokenMetadata`: Token 描述性元数据 (PLP-1)
+//! - `PushOracle`: 1024 推送预言机价格
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
@@ -32,6 +51,21 @@ pub const SPOT_PROPOSAL_SEED: &[u8] = b"spot_proposal";
 pub const PERP_MARKET_SEED: &[u8] = b"perp_market";
 pub const PERP_PROPOSAL_SEED: &[u8] = b"perp_proposal";
 pub const LIQUIDITY_POOL_SEED: &[u8] = b"plp4_pool";
+pub const OBJECTION_SEED: &[u8] = b"objection";
+pub const ADMIN_COUNCIL_SEED: &[u8] = b"admin_council";
+pub const PENDING_ACTION_SEED: &[u8] = b"pending_action";
+pub const PARAM_CHANGE_SEED: &[u8] = b"param_change";
+pub const VOTE_SEED: &[u8] = b"vote";
+pub const SLASH_SCHEDULE_SEED: &[u8] = b"slash_schedule";
+pub const STAKE_AUTHORITY_SEED: &[u8] = b"stake_authority";
+pub const STAKE_ESCROW_SEED: &[u8] = b"stake_escrow";
+pub const TREASURY_STAKE_SEED: &[u8] = b"treasury_stake";
+pub const STAKE_PRICING_SEED: &[u8] = b"stake_pricing";
+pub const MINT_INDEX_SEED: &[u8] = b"mint_index";
+pub const SYMBOL_INDEX_SEED: &[u8] = b"symbol_index";
+pub const PAIR_INDEX_SEED: &[u8] = b"pair_index";
+pub const TOKEN_METADATA_SEED: &[u8] = b"token_metadata";
+pub const PUSH_ORACLE_SEED: &[u8] = b"push_oracle";
 
 // =============================================================================
 // Discriminators
@@ -45,6 +79,19 @@ pub const SPOT_PROPOSAL_DISCRIMINATOR: u64 = 0x53504F54_50524F50; // "SPOTPROP"
 pub const PERP_MARKET_DISCRIMINATOR: u64 = 0x50455250_4D4B5420; // "PERPMKT "
 pub const PERP_PROPOSAL_DISCRIMINATOR: u64 = 0x50455250_50524F50; // "PERPPROP"
 pub const LIQUIDITY_POOL_DISCRIMINATOR: u64 = 0x504C5034_504F4F4C; // "PLP4POOL"
+pub const OBJECTION_DISCRIMINATOR: u64 = 0x4F424A45_4354494E; // "OBJECTIN"
+pub const ADMIN_COUNCIL_DISCRIMINATOR: u64 = 0x41444D49_4E434E4C; // "ADMINCNL"
+pub const PENDING_ACTION_DISCRIMINATOR: u64 = 0x50454E44_4143544E; // "PENDACTN"
+pub const PARAM_CHANGE_DISCRIMINATOR: u64 = 0x50415241_4D434847; // "PARAMCHG"
+pub const VOTE_DISCRIMINATOR: u64 = 0x564F5445_5354414B; // "VOTESTAK"
+pub const SLASH_SCHEDULE_DISCRIMINATOR: u64 = 0x534C4153_48534348; // "SLASHSCH"
+pub const STAKE_PRICING_DISCRIMINATOR: u64 = 0x53544B50_52494345; // "STKPRICE"
+pub const MINT_INDEX_DISCRIMINATOR: u64 = 0x4D494E54_494E4458; // "MINTINDX"
+pub const SYMBOL_INDEX_DISCRIMINATOR: u64 = 0x53594D49_4E444558; // "SYMINDEX"
+pub const PAIR_INDEX_DISCRIMINATOR: u64 = 0x50414952_494E4458; // "PAIRINDX"
+pub const TOKEN_METADATA_DISCRIMINATOR: u64 = 0x544F4B4D_45544144; // "TOKMETAD"
+pub const PUSH_ORACLE_DISCRIMINATOR: u64 = 0x50555348_4F52434C; // "PUSHORCL"
+pub const MOCK_ORACLE_DISCRIMINATOR: u64 = 0x4D4F434B_4F52434C; // "MOCKORCL"
 
 // =============================================================================
 // 账户大小计算
@@ -53,7 +100,7 @@ pub const LIQUIDITY_POOL_DISCRIMINATOR: u64 = 0x504C5034_504F4F4C; // "PLP4POOL"
 pub const LISTING_CONFIG_SIZE: usize = 8 +  // discriminator
     1 +  // version
     32 + // admin
-    // stake_token_mint 已移除 - 使用原生 N1024 (lamports)
+    // stake_token_mint 已移除 - SPL 质押 mint 见 stake_mode
     32 + // treasury (PDA, 接收原生 N1024 质押)
     32 + // vault_program
     32 + // fund_program
@@ -71,7 +118,36 @@ pub const LISTING_CONFIG_SIZE: usize = 8 +  // discriminator
     8 +  // total_staked_lamports (统计总质押)
     1 +  // is_paused
     1 +  // bump
-    64;  // reserved
+    2 +  // objection_forfeit_bps
+    2 +  // objector_reward_bps
+    8 +  // total_slashed_lamports
+    32 + // fee_recipient
+    32 + // pending_admin
+    32 + // reviewer
+    32 + // risk_manager
+    32 + // pauser
+    32 + // treasury_manager
+    32 + // pool_operator
+    4 +  // param_change_delay_seconds
+    32 + // guardian
+    1 +  // pause_flags
+    4 +  // voting_period_seconds
+    4 +  // appeal_window_seconds
+    1 +  // appeal_bond_percentage
+    1 +  // stake_mode
+    2 +  // pending_proposals
+    32 + // insurance_vault
+    3;   // reserved
+
+/// 旧版 ListingConfig 大小（初版布局，`bump` 之后为 64 字节预留空间）
+///
+/// 新增字段（待接受 Admin、五个角色、Guardian、Fee Recipient 等多个 Pubkey）超出了
+/// 64 字节预留空间，因此当前布局的 `bump` 及之前字段与旧版一致，新增字段追加在其后，
+/// 旧账户需通过 MigrateListingConfig 扩容。
+pub const LEGACY_LISTING_CONFIG_SIZE: usize = LEGACY_LISTING_CONFIG_PREFIX + 64;
+
+/// 旧版 ListingConfig 中 discriminator 至 bump 的长度
+const LEGACY_LISTING_CONFIG_PREFIX: usize = 8 + 1 + 32 * 5 + 8 * 3 + 4 * 4 + 2 * 3 + 8 + 1 + 1;
 
 pub const TOKEN_REGISTRY_SIZE: usize = 8 +  // discriminator
     1 +  // version
@@ -84,8 +160,75 @@ pub const TOKEN_REGISTRY_SIZE: usize = 8 +  // discriminator
     32 + // proposer
     8 +  // approved_at
     1 +  // bump
+    1 +  // asset_kind
+    1 +  // risk_flags
+    32 + // metadata
+    1 +  // oracle_source
+    29;  // reserved
+
+/// Oracle 风险配置大小 (OracleRiskConfig)
+pub const ORACLE_RISK_CONFIG_SIZE: usize = 4 +  // max_staleness_seconds
+    2 +  // max_confidence_bps
+    32 + // fallback_oracle
+    1;   // fallback_source
+
+/// Oracle 喂价 ID 大小 (OracleFeed)
+pub const ORACLE_FEED_SIZE: usize = 32; // feed_id
+
+/// TokenRegistry 尾部 `OracleFeed` 偏移（位于 `OracleRiskConfig` 之后）
+pub const TOKEN_REGISTRY_FEED_OFFSET: usize = TOKEN_REGISTRY_SIZE + ORACLE_RISK_CONFIG_SIZE;
+
+/// TokenRegistry 账户尾部附带 `OracleRiskConfig` 和 `OracleFeed`（旧账户在首次设置时扩容）
+pub const TOKEN_REGISTRY_ACCOUNT_SIZE: usize = TOKEN_REGISTRY_FEED_OFFSET + ORACLE_FEED_SIZE;
+
+pub const MINT_INDEX_SIZE: usize = 8 +  // discriminator
+    32 + // mint
+    2 +  // token_index
+    1 +  // bump
+    16;  // reserved
+
+pub const SYMBOL_INDEX_SIZE: usize = 8 +  // discriminator
+    8 +  // symbol [u8; 8]
+    2 +  // token_index
+    1 +  // bump
+    16;  // reserved
+
+/// Token 元数据内容大小 (TokenMetadataContent)
+pub const TOKEN_METADATA_CONTENT_SIZE: usize = MAX_TOKEN_NAME_LEN + // name
+    MAX_TOKEN_URI_LEN + // uri
+    1 +  // category
+    32;  // metadata_hash
+
+pub const TOKEN_METADATA_SIZE: usize = 8 +  // discriminator
+    1 +  // version
+    32 + // proposal
+    32 + // proposer
+    TOKEN_METADATA_CONTENT_SIZE + // content
+    8 +  // updated_at
+    TOKEN_METADATA_CONTENT_SIZE + // pending
+    1 +  // has_pending
+    32 + // scheduled_by
+    8 +  // effective_at
+    1 +  // bump
     64;  // reserved
 
+pub const PUSH_ORACLE_SIZE: usize = 8 +  // discriminator
+    1 +  // version
+    32 + // feed_id
+    32 + // publisher
+    8 +  // price
+    8 +  // conf
+    4 +  // expo
+    8 +  // publish_time
+    1 +  // bump
+    32;  // reserved
+
+pub const MOCK_ORACLE_SIZE: usize = 8 +  // discriminator
+    8 +  // price
+    8 +  // conf
+    4 +  // expo
+    8;   // publish_time
+
 pub const TOKEN_PROPOSAL_SIZE: usize = 8 +  // discriminator
     1 +  // version
     32 + // proposer
@@ -102,7 +245,30 @@ pub const TOKEN_PROPOSAL_SIZE: usize = 8 +  // discriminator
     8 +  // objection_stake
     1 +  // stake_claimed
     1 +  // bump
-    64;  // reserved
+    8 +  // slash_amount
+    8 +  // objector_reward
+    8 +  // voting_deadline
+    8 +  // votes_for
+    8 +  // votes_against
+    8 +  // rejected_at
+    8 +  // appeal_bond
+    1 +  // stake_mode
+    1 +  // asset_kind
+    1 +  // risk_flags
+    1 +  // oracle_source
+    1 +  // slash_pending
+    3;   // reserved
+
+/// TokenProposal 账户尾部的 Mint 权限快照（固定位于 TOKEN_PROPOSAL_SIZE 偏移处，
+/// 旧提案账户没有该段）
+pub const MINT_AUTHORITIES_SIZE: usize = 33 + // mint_authority (Option<Pubkey>)
+    33;  // freeze_authority (Option<Pubkey>)
+
+/// TokenProposal 尾部 `OracleFeed` 偏移（位于 Mint 权限快照之后）
+pub const TOKEN_PROPOSAL_FEED_OFFSET: usize = TOKEN_PROPOSAL_SIZE + MINT_AUTHORITIES_SIZE;
+
+/// 新建 TokenProposal 账户大小（含 Mint 权限快照和 Oracle 喂价 ID）
+pub const TOKEN_PROPOSAL_ACCOUNT_SIZE: usize = TOKEN_PROPOSAL_FEED_OFFSET + ORACLE_FEED_SIZE;
 
 pub const SPOT_MARKET_SIZE: usize = 8 +  // discriminator
     1 +  // version
@@ -144,7 +310,16 @@ pub const SPOT_PROPOSAL_SIZE: usize = 8 +  // discriminator
     8 +  // objection_stake
     1 +  // stake_claimed
     1 +  // bump
-    64;  // reserved
+    8 +  // slash_amount
+    8 +  // objector_reward
+    8 +  // voting_deadline
+    8 +  // votes_for
+    8 +  // votes_against
+    8 +  // rejected_at
+    8 +  // appeal_bond
+    1 +  // stake_mode
+    1 +  // slash_pending
+    6;   // reserved
 
 pub const PERP_MARKET_SIZE: usize = 8 +  // discriminator
     1 +  // version
@@ -173,7 +348,20 @@ pub const PERP_MARKET_SIZE: usize = 8 +  // discriminator
     32 + // proposer
     8 +  // approved_at
     1 +  // bump
-    64;  // reserved
+    1 +  // oracle_source
+    ORACLE_RISK_CONFIG_SIZE + // oracle_config
+    8 +  // reference_price
+    4 +  // reference_expo
+    12;  // reserved
+
+pub const PAIR_INDEX_SIZE: usize = 8 +  // discriminator
+    1 +  // market_type
+    2 +  // base_token_index
+    2 +  // quote_token_index
+    2 +  // market_index
+    1 +  // replacement_allowed
+    1 +  // bump
+    16;  // reserved
 
 pub const PERP_PROPOSAL_SIZE: usize = 8 +  // discriminator
     1 +  // version
@@ -202,7 +390,20 @@ pub const PERP_PROPOSAL_SIZE: usize = 8 +  // discriminator
     8 +  // objection_stake
     1 +  // stake_claimed
     1 +  // bump
-    64;  // reserved
+    8 +  // slash_amount
+    8 +  // objector_reward
+    8 +  // voting_deadline
+    8 +  // votes_for
+    8 +  // votes_against
+    8 +  // rejected_at
+    8 +  // appeal_bond
+    1 +  // stake_mode
+    1 +  // oracle_source
+    1 +  // slash_pending
+    5;   // reserved
+
+/// 新建 PerpMarketProposal 账户大小（尾部附带 `OracleFeed`，旧提案账户没有该段）
+pub const PERP_PROPOSAL_ACCOUNT_SIZE: usize = PERP_PROPOSAL_SIZE + ORACLE_FEED_SIZE;
 
 pub const LIQUIDITY_POOL_SIZE: usize = 8 +  // discriminator
     1 +  // version
@@ -225,15 +426,154 @@ pub const LIQUIDITY_POOL_SIZE: usize = 8 +  // discriminator
     1 +  // bump
     64;  // reserved
 
+pub const OBJECTION_SIZE: usize = 8 +  // discriminator
+    1 +  // version
+    1 +  // proposal_type
+    32 + // proposal
+    32 + // objector
+    8 +  // amount
+    8 +  // created_at
+    1 +  // claimed
+    1 +  // bump
+    64;  // reserved
+
+/// 罚没比例表最大拒绝原因数
+pub const MAX_SLASH_REASONS: usize = 8;
+
+pub const SLASH_SCHEDULE_SIZE: usize = 8 +  // discriminator
+    1 +  // version
+    2 +  // cancel_slash_bps
+    2 +  // vote_rejection_slash_bps
+    2 * MAX_SLASH_REASONS + // reason_slash_bps
+    1 +  // reason_count
+    1 +  // bump
+    32;  // reserved
+
+pub const STAKE_PRICING_SIZE: usize = 8 +  // discriminator
+    1 +  // version
+    1 +  // enabled
+    2 +  // free_pending
+    2 +  // per_pending_bps
+    4 +  // max_multiplier_bps
+    4 +  // decay_seconds
+    4 +  // surge_bps
+    8 +  // surge_updated_at
+    1 +  // bump
+    32;  // reserved
+
+/// 多签委员会最大成员数
+pub const MAX_COUNCIL_MEMBERS: usize = 10;
+
+pub const ADMIN_COUNCIL_SIZE: usize = 8 +  // discriminator
+    1 +  // version
+    32 * MAX_COUNCIL_MEMBERS + // members
+    1 +  // member_count
+    1 +  // threshold
+    4 +  // epoch
+    8 +  // action_count
+    1 +  // bump
+    64;  // reserved
+
+pub const PENDING_ACTION_SIZE: usize = 8 +  // discriminator
+    1 +  // version
+    32 + // council
+    8 +  // action_id
+    32 + // proposer
+    32 + // action_hash
+    4 +  // council_epoch
+    2 +  // approvals (bitmap)
+    1 +  // approval_count
+    8 +  // created_at
+    1 +  // executed
+    1 +  // bump
+    64;  // reserved
+
+pub const PARAM_CHANGE_SIZE: usize = 8 +  // discriminator
+    1 +  // version
+    32 + // market
+    2 +  // market_index
+    2 +  // max_leverage (Option<u8>)
+    5 +  // initial_margin_rate_e6 (Option<u32>)
+    5 +  // maintenance_margin_rate_e6 (Option<u32>)
+    9 +  // max_open_interest_e6 (Option<u64>)
+    32 + // scheduled_by
+    8 +  // scheduled_at
+    8 +  // effective_at
+    1 +  // is_pending
+    1 +  // bump
+    64;  // reserved
+
+pub const VOTE_SIZE: usize = 8 +  // discriminator
+    1 +  // version
+    1 +  // proposal_type
+    32 + // proposal
+    32 + // voter
+    1 +  // support
+    8 +  // amount
+    8 +  // created_at
+    1 +  // claimed
+    1 +  // bump
+    64;  // reserved
+
+// =============================================================================
+// 暂停范围 (ListingConfig.pause_flags)
+// =============================================================================
+
+/// PLP-1 新 Token 提案
+pub const PAUSE_TOKEN_PROPOSALS: u8 = 1 << 0;
+/// PLP-2 新 Spot 市场提案
+pub const PAUSE_SPOT_PROPOSALS: u8 = 1 << 1;
+/// PLP-3 新 Perp 市场提案
+pub const PAUSE_PERP_PROPOSALS: u8 = 1 << 2;
+/// 超时自动批准 (Finalize*)
+pub const PAUSE_FINALIZATION: u8 = 1 << 3;
+/// PLP-4 流动性池操作
+pub const PAUSE_LIQUIDITY_POOLS: u8 = 1 << 4;
+/// 全部范围
+pub const PAUSE_ALL: u8 = PAUSE_TOKEN_PROPOSALS
+    | PAUSE_SPOT_PROPOSALS
+    | PAUSE_PERP_PROPOSALS
+    | PAUSE_FINALIZATION
+    | PAUSE_LIQUIDITY_POOLS;
+
+// =============================================================================
+// Token-2022 风险标记 (TokenProposal/TokenRegistry.risk_flags)
+// =============================================================================
+
+/// 转账手续费 (TransferFeeConfig)
+pub const TOKEN_RISK_TRANSFER_FEE: u8 = 1 << 0;
+/// 永久委托人，可转走或销毁任意持有人的 token (PermanentDelegate)
+pub const TOKEN_RISK_PERMANENT_DELEGATE: u8 = 1 << 1;
+/// 转账时调用外部程序 (TransferHook)
+pub const TOKEN_RISK_TRANSFER_HOOK: u8 = 1 << 2;
+/// 不可转让 (NonTransferable)
+pub const TOKEN_RISK_NON_TRANSFERABLE: u8 = 1 << 3;
+/// 新 token 账户默认冻结 (DefaultAccountState = Frozen)
+pub const TOKEN_RISK_DEFAULT_FROZEN: u8 = 1 << 4;
+/// 无法上架，ProposeToken 直接拒绝
+pub const TOKEN_RISK_REJECTED: u8 = TOKEN_RISK_NON_TRANSFERABLE;
+/// 必须由 Reviewer 批准，不允许超时自动批准或投票上架
+pub const TOKEN_RISK_REVIEW_REQUIRED: u8 = TOKEN_RISK_PERMANENT_DELEGATE
+    | TOKEN_RISK_TRANSFER_HOOK
+    | TOKEN_RISK_DEFAULT_FROZEN;
+
+// =============================================================================
+// 质押托管
+// =============================================================================
+
+/// 提案账户自身托管质押的最低提案版本（更早的版本托管在 Treasury）
+pub const ESCROW_PROPOSAL_VERSION: u8 = 2;
+
 // =============================================================================
 // 枚举类型
 // =============================================================================
 
 /// 提案状态
-#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
 #[repr(u8)]
 pub enum ProposalStatus {
     /// 待审核
+    #[default]
     Pending = 0,
     /// 已批准
     Approved = 1,
@@ -241,12 +581,8 @@ pub enum ProposalStatus {
     Rejected = 2,
     /// 已取消（申请者主动撤回）
     Cancelled = 3,
-}
-
-impl Default for ProposalStatus {
-    fn default() -> Self {
-        ProposalStatus::Pending
-    }
+    /// 申诉中（拒绝决定待 Admin 维持或推翻）
+    Appealed = 4,
 }
 
 /// 市场类型
@@ -257,6 +593,142 @@ pub enum MarketType {
     Perp = 1,
 }
 
+/// 权限角色（Admin 始终拥有全部角色权限）
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum Role {
+    /// 批准/拒绝 Token 和市场提案
+    Reviewer = 0,
+    /// 调整 Spot/Perp 市场参数
+    RiskManager = 1,
+    /// 暂停上架，更新 Token/市场状态
+    Pauser = 2,
+    /// 提取 Treasury 盈余
+    TreasuryManager = 3,
+    /// 管理 PLP-4 流动性池
+    PoolOperator = 4,
+}
+
+/// Treasury 提取目标
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum TreasuryDestination {
+    /// ListingConfig 中配置的 insurance_vault（账户 owner 必须是 fund_program）
+    InsuranceFund = 0,
+    /// ListingConfig 中配置的 fee_recipient
+    FeeRecipient = 1,
+}
+
+/// 质押资产
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[repr(u8)]
+pub enum StakeMode {
+    /// 原生 N1024 (lamports)
+    #[default]
+    Native = 0,
+    /// SPL 质押代币（mint 由 Treasury 质押 token 账户确定）
+    Spl = 1,
+}
+
+/// PLP-1 资产类型
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[repr(u8)]
+pub enum AssetKind {
+    /// SPL Token Mint
+    #[default]
+    SplMint = 0,
+    /// Token-2022 Mint
+    Token2022Mint = 1,
+    /// 无链上 Mint 的合成资产（股票、指数、贵金属等），仅跟踪 Oracle 价格，只能作为 Perp Base
+    Synthetic = 2,
+}
+
+/// Oracle 数据源，按 Oracle 账户 owner 识别
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[repr(u8)]
+pub enum OracleSource {
+    /// Pyth 推送价格账户（数据源上线前的 Token / 市场均为该值）
+    #[default]
+    PythV2 = 0,
+    /// Pyth Solana Receiver `PriceUpdateV2` 账户
+    PythPull = 1,
+    /// Switchboard On-Demand Pull Feed
+    Switchboard = 2,
+    /// 本程序托管的 1024 推送预言机 (`PushOracle`)
+    Push1024 = 3,
+    /// 本地测试用 `MockOracle`（仅 `test-oracle` 构建接受）
+    Mock = 4,
+}
+
+/// Oracle 风险配置（PerpMarket 字段 / TokenRegistry 账户尾部）
+///
+/// 0 值使用全局默认值，数据源上线前的市场和 Token 即为全默认。
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct OracleRiskConfig {
+    /// 最大陈旧时间（秒，0 = 默认）
+    pub max_staleness_seconds: u32,
+    /// 最大置信区间占价格比例（bps，0 = 默认）
+    pub max_confidence_bps: u16,
+    /// 备用 Oracle（Pubkey::default() = 未设置），主 Oracle 无效时使用
+    pub fallback_oracle: Pubkey,
+    /// 备用 Oracle 数据源
+    pub fallback_source: OracleSource,
+}
+
+impl OracleRiskConfig {
+    /// 从 TokenRegistry 账户数据读取（旧账户返回默认值）
+    pub fn load(registry_data: &[u8]) -> Self {
+        registry_data
+            .get(TOKEN_REGISTRY_SIZE..)
+            .and_then(|tail| Self::deserialize(&mut &tail[..]).ok())
+            .unwrap_or_default()
+    }
+
+    /// 写入 TokenRegistry 账户尾部
+    pub fn store(&self, registry_data: &mut [u8]) -> std::io::Result<()> {
+        let tail = registry_data
+            .get_mut(TOKEN_REGISTRY_SIZE..)
+            .ok_or(std::io::ErrorKind::UnexpectedEof)?;
+        self.serialize(&mut &mut tail[..])
+    }
+
+    /// 是否设置了备用 Oracle
+    pub fn has_fallback(&self) -> bool {
+        self.fallback_oracle != Pubkey::default()
+    }
+}
+
+/// Token 分类 (TokenMetadata)
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[repr(u8)]
+pub enum TokenCategory {
+    /// 加密资产
+    #[default]
+    Crypto = 0,
+    /// 稳定币
+    Stablecoin = 1,
+    /// 股票
+    Equity = 2,
+    /// 大宗商品 / 贵金属
+    Commodity = 3,
+    /// 指数
+    Index = 4,
+    /// 外汇
+    Forex = 5,
+}
+
+/// 提案类型
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum ProposalType {
+    /// PLP-1 Token 注册
+    Token = 0,
+    /// PLP-2 Spot 市场上架
+    SpotMarket = 1,
+    /// PLP-3 Perp 市场上架
+    PerpMarket = 2,
+}
+
 // =============================================================================
 // PDA 账户结构
 // =============================================================================
@@ -271,7 +743,7 @@ pub struct ListingConfig {
     pub version: u8,
     /// 管理员
     pub admin: Pubkey,
-    // stake_token_mint 已移除 - 使用原生 N1024 (lamports) 质押
+    // stake_token_mint 已移除 - SPL 质押 mint 由 Treasury 质押 token 账户确定 (见 stake_mode)
     /// 质押国库 PDA（存放原生 N1024 质押和罚没）
     /// Seeds: ["listing_treasury"]
     pub treasury: Pubkey,
@@ -304,14 +776,53 @@ pub struct ListingConfig {
     pub total_spot_markets: u16,
     /// Perp 市场总数
     pub total_perp_markets: u16,
-    /// 累计质押总额 (lamports)
+    /// Treasury 中托管的未结算质押 (lamports)
+    /// 仅统计旧版提案；新提案质押托管在提案账户中
     pub total_staked_lamports: u64,
-    /// 是否暂停新上架
+    /// 全局暂停（暂停所有范围）
     pub is_paused: bool,
     /// PDA bump
     pub bump: u8,
+    /// 提案被批准时反对者被没收的比例 (bps)
+    pub objection_forfeit_bps: u16,
+    /// 提案被拒绝时罚没金额分给反对者的比例 (bps)
+    pub objector_reward_bps: u16,
+    /// 累计罚没总额 (lamports)
+    pub total_slashed_lamports: u64,
+    /// 罚没资金接收地址 (Pubkey::default() = 未设置)
+    pub fee_recipient: Pubkey,
+    /// 待接受的新管理员 (Pubkey::default() = 无待处理的转移)
+    pub pending_admin: Pubkey,
+    /// 审核员：批准/拒绝提案
+    pub reviewer: Pubkey,
+    /// 风控：调整市场风险参数
+    pub risk_manager: Pubkey,
+    /// 暂停：暂停上架和市场
+    pub pauser: Pubkey,
+    /// 财务：提取 Treasury 盈余
+    pub treasury_manager: Pubkey,
+    /// 做市：管理 PLP-4 流动性池
+    pub pool_operator: Pubkey,
+    /// Perp 风险参数变更时间锁（秒）
+    pub param_change_delay_seconds: u32,
+    /// 紧急暂停 Guardian（只能暂停，不能恢复；Pubkey::default() = 未设置）
+    pub guardian: Pubkey,
+    /// 分范围暂停位 (PAUSE_*)
+    pub pause_flags: u8,
+    /// 争议提案投票期（秒，审核期结束后开始）
+    pub voting_period_seconds: u32,
+    /// 拒绝后可申诉的时间窗口（秒）
+    pub appeal_window_seconds: u32,
+    /// 申诉保证金占提案质押的比例 (%)
+    pub appeal_bond_percentage: u8,
+    /// 新提案的质押资产（原生 N1024 或 SPL 质押代币）
+    pub stake_mode: StakeMode,
+    /// 待审核提案数（Token + Spot + Perp，用于动态质押定价）
+    pub pending_proposals: u16,
+    /// Treasury 提取到保险金的接收账户（owner 须为 fund_program；Pubkey::default() = 未设置）
+    pub insurance_vault: Pubkey,
     /// 预留空间
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 3],
 }
 
 impl ListingConfig {
@@ -332,10 +843,75 @@ impl ListingConfig {
     pub const DEFAULT_PERP_REVIEW_PERIOD: u32 = 14 * 24 * 60 * 60;
     /// 默认质押锁定期 (30 天)
     pub const DEFAULT_STAKE_LOCK_PERIOD: u32 = 30 * 24 * 60 * 60;
+    /// 默认反对者没收比例 (10%)
+    pub const DEFAULT_OBJECTION_FORFEIT_BPS: u16 = 1_000;
+    /// 默认反对者罚没奖励比例 (50%)
+    pub const DEFAULT_OBJECTOR_REWARD_BPS: u16 = 5_000;
+    /// 默认 Perp 风险参数变更时间锁 (24 小时)
+    pub const DEFAULT_PARAM_CHANGE_DELAY: u32 = 24 * 60 * 60;
+    /// 默认争议提案投票期 (3 天)
+    pub const DEFAULT_VOTING_PERIOD: u32 = 3 * 24 * 60 * 60;
+    /// 默认申诉窗口 (7 天)
+    pub const DEFAULT_APPEAL_WINDOW: u32 = 7 * 24 * 60 * 60;
+    /// 申诉裁决期限：申诉窗口结束后 14 天内须由 Admin 裁决
+    pub const APPEAL_RESOLUTION_PERIOD: i64 = 14 * 24 * 60 * 60;
+    /// 默认申诉保证金比例 (10%)
+    pub const DEFAULT_APPEAL_BOND_PERCENTAGE: u8 = 10;
+
+    /// 按账户长度解析配置
+    ///
+    /// 旧版布局的新增字段取默认值：角色、Guardian、Fee Recipient、待接受 Admin 未设置，
+    /// 统计从 0 开始（旧提案不计入 pending_proposals）。
+    pub fn unpack(data: &[u8]) -> std::io::Result<Self> {
+        if data.len() != LEGACY_LISTING_CONFIG_SIZE {
+            return Self::try_from_slice(data);
+        }
+
+        let mut upgraded = [0u8; LISTING_CONFIG_SIZE];
+        upgraded[..LEGACY_LISTING_CONFIG_PREFIX]
+            .copy_from_slice(&data[..LEGACY_LISTING_CONFIG_PREFIX]);
+        let mut config = Self::try_from_slice(&upgraded)?;
+        config.objection_forfeit_bps = Self::DEFAULT_OBJECTION_FORFEIT_BPS;
+        config.objector_reward_bps = Self::DEFAULT_OBJECTOR_REWARD_BPS;
+        config.param_change_delay_seconds = Self::DEFAULT_PARAM_CHANGE_DELAY;
+        config.voting_period_seconds = Self::DEFAULT_VOTING_PERIOD;
+        config.appeal_window_seconds = Self::DEFAULT_APPEAL_WINDOW;
+        config.appeal_bond_percentage = Self::DEFAULT_APPEAL_BOND_PERCENTAGE;
+        Ok(config)
+    }
+
+    /// 指定范围是否暂停（全局暂停覆盖所有范围）
+    pub fn is_scope_paused(&self, scope: u8) -> bool {
+        self.is_paused || self.pause_flags & scope != 0
+    }
+
+    /// 获取角色对应的授权地址 (Pubkey::default() = 未分配，仅 Admin)
+    pub fn role_authority(&self, role: Role) -> &Pubkey {
+        match role {
+            Role::Reviewer => &self.reviewer,
+            Role::RiskManager => &self.risk_manager,
+            Role::Pauser => &self.pauser,
+            Role::TreasuryManager => &self.treasury_manager,
+            Role::PoolOperator => &self.pool_operator,
+        }
+    }
+
+    /// 获取角色对应授权地址的可变引用
+    pub fn role_authority_mut(&mut self, role: Role) -> &mut Pubkey {
+        match role {
+            Role::Reviewer => &mut self.reviewer,
+            Role::RiskManager => &mut self.risk_manager,
+            Role::Pauser => &mut self.pauser,
+            Role::TreasuryManager => &mut self.treasury_manager,
+            Role::PoolOperator => &mut self.pool_operator,
+        }
+    }
 }
 
 /// 已注册 Token (PLP-1)
 /// Seeds: ["token", token_index.to_le_bytes()]
+///
+/// 账户尾部附带 `OracleRiskConfig` 和 `OracleFeed`（见 TOKEN_REGISTRY_ACCOUNT_SIZE）
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct TokenRegistry {
     /// 账户类型标识符
@@ -346,11 +922,11 @@ pub struct TokenRegistry {
     pub token_index: u16,
     /// Symbol (最多 8 字符)
     pub symbol: [u8; 8],
-    /// SPL Token Mint 地址
+    /// SPL Token Mint 地址（Synthetic 资产为默认值）
     pub mint: Pubkey,
     /// 精度
     pub decimals: u8,
-    /// Oracle 地址 (Pyth/Switchboard)，Perp 必须，Spot 可选
+    /// Oracle 地址 (Pyth/Switchboard)，Perp 和 Synthetic 资产必须，Spot 可选
     pub oracle: Option<Pubkey>,
     /// 是否激活
     pub is_active: bool,
@@ -360,8 +936,16 @@ pub struct TokenRegistry {
     pub approved_at: i64,
     /// PDA bump
     pub bump: u8,
+    /// 资产类型
+    pub asset_kind: AssetKind,
+    /// Token-2022 扩展风险标记 (TOKEN_RISK_*)
+    pub risk_flags: u8,
+    /// TokenMetadata PDA（由提案地址派生，提案者未设置元数据时账户不存在）
+    pub metadata: Pubkey,
+    /// Oracle 数据源（未提供 Oracle 时为默认值）
+    pub oracle_source: OracleSource,
     /// 预留空间
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 29],
 }
 
 impl TokenRegistry {
@@ -374,8 +958,186 @@ impl TokenRegistry {
     }
 }
 
+/// Token 名称最大字节数
+pub const MAX_TOKEN_NAME_LEN: usize = 32;
+/// Token 元数据 URI 最大字节数
+pub const MAX_TOKEN_URI_LEN: usize = 200;
+
+/// Token 元数据内容（name / uri 为 UTF-8，尾部 0 填充）
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct TokenMetadataContent {
+    /// 名称
+    pub name: [u8; MAX_TOKEN_NAME_LEN],
+    /// 元数据 URI (JSON / 官网等)
+    pub uri: [u8; MAX_TOKEN_URI_LEN],
+    /// 分类
+    pub category: TokenCategory,
+    /// URI 指向内容的哈希 (如 SHA-256)，全 0 表示未提供
+    pub metadata_hash: [u8; 32],
+}
+
+impl Default for TokenMetadataContent {
+    fn default() -> Self {
+        Self {
+            name: [0u8; MAX_TOKEN_NAME_LEN],
+            uri: [0u8; MAX_TOKEN_URI_LEN],
+            category: TokenCategory::default(),
+            metadata_hash: [0u8; 32],
+        }
+    }
+}
+
+impl TokenMetadataContent {
+    /// 获取名称字符串
+    pub fn name_str(&self) -> &str {
+        padded_str(&self.name)
+    }
+
+    /// 获取 URI 字符串
+    pub fn uri_str(&self) -> &str {
+        padded_str(&self.uri)
+    }
+}
+
+/// 截取尾部 0 填充前的 UTF-8 字符串
+fn padded_str(bytes: &[u8]) -> &str {
+    let len = bytes.iter().position(|&c| c == 0).unwrap_or(bytes.len());
+    std::str::from_utf8(&bytes[..len]).unwrap_or("")
+}
+
+/// Token 描述性元数据 (PLP-1)，提案期间由提案者创建
+/// Seeds: ["token_metadata", token_proposal]
+///
+/// 批准后 TokenRegistry.metadata 指向该账户。创建后的修改需由 Admin 或提案者
+/// 发起，经 `param_change_delay_seconds` 时间锁后任何人可执行。
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct TokenMetadata {
+    /// 账户类型标识符
+    pub discriminator: u64,
+    /// 版本号
+    pub version: u8,
+    /// TokenProposal PDA
+    pub proposal: Pubkey,
+    /// 提案者（可发起修改）
+    pub proposer: Pubkey,
+    /// 当前内容
+    pub content: TokenMetadataContent,
+    /// 最近更新时间戳
+    pub updated_at: i64,
+    /// 待生效内容
+    pub pending: TokenMetadataContent,
+    /// 是否有待生效的修改
+    pub has_pending: bool,
+    /// 修改发起者
+    pub scheduled_by: Pubkey,
+    /// 生效时间戳（之后任何人可执行）
+    pub effective_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// 预留空间
+    pub reserved: [u8; 64],
+}
+
+impl TokenMetadata {
+    pub const DISCRIMINATOR: u64 = TOKEN_METADATA_DISCRIMINATOR;
+}
+
+/// 1024 推送预言机价格 (OracleSource::Push1024)
+/// Seeds: ["push_oracle", feed_id]
+///
+/// 由 Admin 创建并指定 publisher，publisher 推送价格时记录当前时间戳。
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct PushOracle {
+    /// 账户类型标识符
+    pub discriminator: u64,
+    /// 版本号
+    pub version: u8,
+    /// 价格源 ID
+    pub feed_id: [u8; 32],
+    /// 价格推送者
+    pub publisher: Pubkey,
+    /// 价格 (price x 10^expo)
+    pub price: i64,
+    /// 置信区间
+    pub conf: u64,
+    /// 价格指数
+    pub expo: i32,
+    /// 最近推送时间戳（0 = 尚未推送）
+    pub publish_time: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// 预留空间
+    pub reserved: [u8; 32],
+}
+
+impl PushOracle {
+    pub const DISCRIMINATOR: u64 = PUSH_ORACLE_DISCRIMINATOR;
+}
+
+/// 本地测试用 Oracle 价格账户 (OracleSource::Mock)
+///
+/// 仅 `test-oracle` 构建接受，owner 不限（由测试预置账户数据）。
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct MockOracle {
+    /// 账户类型标识符
+    pub discriminator: u64,
+    /// 价格 (price x 10^expo)
+    pub price: i64,
+    /// 置信区间
+    pub conf: u64,
+    /// 价格指数
+    pub expo: i32,
+    /// 发布时间戳
+    pub publish_time: i64,
+}
+
+impl MockOracle {
+    pub const DISCRIMINATOR: u64 = MOCK_ORACLE_DISCRIMINATOR;
+}
+
+/// Mint 唯一性索引，批准 Token 时与 TokenRegistry 一同创建
+/// Seeds: ["mint_index", mint]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct MintIndex {
+    /// 账户类型标识符
+    pub discriminator: u64,
+    /// SPL Token Mint 地址
+    pub mint: Pubkey,
+    /// 对应的 Token 索引
+    pub token_index: u16,
+    /// PDA bump
+    pub bump: u8,
+    /// 预留空间
+    pub reserved: [u8; 16],
+}
+
+impl MintIndex {
+    pub const DISCRIMINATOR: u64 = MINT_INDEX_DISCRIMINATOR;
+}
+
+/// Symbol 唯一性索引，批准 Token 时与 TokenRegistry 一同创建
+/// Seeds: ["symbol_index", symbol（不含尾部 0）]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct SymbolIndex {
+    /// 账户类型标识符
+    pub discriminator: u64,
+    /// Symbol
+    pub symbol: [u8; 8],
+    /// 对应的 Token 索引
+    pub token_index: u16,
+    /// PDA bump
+    pub bump: u8,
+    /// 预留空间
+    pub reserved: [u8; 16],
+}
+
+impl SymbolIndex {
+    pub const DISCRIMINATOR: u64 = SYMBOL_INDEX_DISCRIMINATOR;
+}
+
 /// Token 注册提案 (PLP-1)
 /// Seeds: ["token_proposal", proposer, nonce.to_le_bytes()]
+/// 账户尾部附带 `MintAuthorities` 和 `OracleFeed`（见 TOKEN_PROPOSAL_ACCOUNT_SIZE）
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct TokenProposal {
     /// 账户类型标识符
@@ -388,13 +1150,13 @@ pub struct TokenProposal {
     pub nonce: u64,
     /// Symbol
     pub symbol: [u8; 8],
-    /// SPL Token Mint 地址
+    /// SPL Token Mint 地址（Synthetic 资产为默认值）
     pub mint: Pubkey,
     /// 精度
     pub decimals: u8,
     /// Oracle 地址
     pub oracle: Option<Pubkey>,
-    /// 质押金额
+    /// 质押金额（创建时生效的质押，含动态定价加价）
     pub stake_amount: u64,
     /// 提案状态
     pub status: ProposalStatus,
@@ -410,14 +1172,95 @@ pub struct TokenProposal {
     pub stake_claimed: bool,
     /// PDA bump
     pub bump: u8,
+    /// 被拒绝时的罚没金额
+    pub slash_amount: u64,
+    /// 罚没金额中分给反对者的奖励总额
+    pub objector_reward: u64,
+    /// 争议投票截止时间（审核截止 + 投票期）
+    pub voting_deadline: i64,
+    /// 支持票质押总额 (lamports)
+    pub votes_for: u64,
+    /// 反对票质押总额 (lamports)
+    pub votes_against: u64,
+    /// Reject* 拒绝时间（0 = 未被 Reviewer 拒绝，不可申诉）
+    pub rejected_at: i64,
+    /// 申诉保证金 (lamports，0 = 未申诉)
+    pub appeal_bond: u64,
+    /// 质押资产（创建时取自 ListingConfig.stake_mode）
+    pub stake_mode: StakeMode,
+    /// 资产类型
+    pub asset_kind: AssetKind,
+    /// Token-2022 扩展风险标记 (TOKEN_RISK_*)
+    pub risk_flags: u8,
+    /// Oracle 数据源（未提供 Oracle 时为默认值）
+    pub oracle_source: OracleSource,
+    /// 罚没资金是否仍留在提案托管中（Reject* 拒绝后申诉窗口结束前不转入 Treasury）
+    pub slash_pending: bool,
     /// 预留空间
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 3],
 }
 
 impl TokenProposal {
     pub const DISCRIMINATOR: u64 = TOKEN_PROPOSAL_DISCRIMINATOR;
 }
 
+/// ProposeToken 时记录的 Mint 权限，供 Reviewer 判断代币能否继续增发或冻结
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Default)]
+pub struct MintAuthorities {
+    /// 增发权限（None = 已放弃，供应量固定）
+    pub mint_authority: Option<Pubkey>,
+    /// 冻结权限（None = 无法冻结持有人 token 账户）
+    pub freeze_authority: Option<Pubkey>,
+}
+
+impl MintAuthorities {
+    /// 从 TokenProposal 账户数据读取（旧提案账户返回 None，Synthetic 资产没有 Mint，该段为空）
+    pub fn load(proposal_data: &[u8]) -> Option<Self> {
+        let tail = proposal_data.get(TOKEN_PROPOSAL_SIZE..)?;
+        Self::deserialize(&mut &tail[..]).ok()
+    }
+
+    /// 写入 TokenProposal 账户尾部
+    pub fn store(&self, proposal_data: &mut [u8]) -> std::io::Result<()> {
+        let tail = proposal_data
+            .get_mut(TOKEN_PROPOSAL_SIZE..)
+            .ok_or(std::io::ErrorKind::UnexpectedEof)?;
+        self.serialize(&mut &mut tail[..])
+    }
+}
+
+/// 提案 / Registry 账户尾部记录的 Oracle 喂价 ID，读取价格时与 Oracle 账户中的 feed_id 比对
+///
+/// Pyth Pull 为 `PriceUpdateV2` 中的 feed_id，Push1024 为 PushOracle 的 feed_id；
+/// 其他数据源和旧账户为全 0，不做比对
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct OracleFeed {
+    /// 喂价 ID
+    pub feed_id: [u8; 32],
+}
+
+impl OracleFeed {
+    /// 从账户数据 `offset` 处读取（旧账户没有该段时返回默认值）
+    pub fn load(data: &[u8], offset: usize) -> Self {
+        data.get(offset..)
+            .and_then(|tail| Self::deserialize(&mut &tail[..]).ok())
+            .unwrap_or_default()
+    }
+
+    /// 写入账户数据 `offset` 处
+    pub fn store(&self, data: &mut [u8], offset: usize) -> std::io::Result<()> {
+        let tail = data
+            .get_mut(offset..)
+            .ok_or(std::io::ErrorKind::UnexpectedEof)?;
+        self.serialize(&mut &mut tail[..])
+    }
+
+    /// 是否记录了喂价 ID
+    pub fn is_set(&self) -> bool {
+        self.feed_id != [0u8; 32]
+    }
+}
+
 /// Spot 市场配置 (PLP-2)
 /// Seeds: ["spot_market", market_index.to_le_bytes()]
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
@@ -500,7 +1343,7 @@ pub struct SpotMarketProposal {
     pub min_order_size_e6: u64,
     /// 最大订单大小 (e6)
     pub max_order_size_e6: u64,
-    /// 质押金额
+    /// 质押金额（创建时生效的质押，含动态定价加价）
     pub stake_amount: u64,
     /// 提案状态
     pub status: ProposalStatus,
@@ -516,8 +1359,26 @@ pub struct SpotMarketProposal {
     pub stake_claimed: bool,
     /// PDA bump
     pub bump: u8,
+    /// 被拒绝时的罚没金额
+    pub slash_amount: u64,
+    /// 罚没金额中分给反对者的奖励总额
+    pub objector_reward: u64,
+    /// 争议投票截止时间（审核截止 + 投票期）
+    pub voting_deadline: i64,
+    /// 支持票质押总额 (lamports)
+    pub votes_for: u64,
+    /// 反对票质押总额 (lamports)
+    pub votes_against: u64,
+    /// Reject* 拒绝时间（0 = 未被 Reviewer 拒绝，不可申诉）
+    pub rejected_at: i64,
+    /// 申诉保证金 (lamports，0 = 未申诉)
+    pub appeal_bond: u64,
+    /// 质押资产（创建时取自 ListingConfig.stake_mode）
+    pub stake_mode: StakeMode,
+    /// 罚没资金是否仍留在提案托管中（Reject* 拒绝后申诉窗口结束前不转入 Treasury）
+    pub slash_pending: bool,
     /// 预留空间
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 6],
 }
 
 impl SpotMarketProposal {
@@ -582,8 +1443,17 @@ pub struct PerpMarket {
     pub approved_at: i64,
     /// PDA bump
     pub bump: u8,
+    /// Oracle 数据源
+    pub oracle_source: OracleSource,
+    /// Oracle 风险配置
+    pub oracle_config: OracleRiskConfig,
+    /// 上线时的 Oracle 价格 (reference_price x 10^reference_expo)，
+    /// 作为 PLP-4 流动性池和价格带的参考价（参考价上线前的市场为 0）
+    pub reference_price: i64,
+    /// 参考价格指数
+    pub reference_expo: i32,
     /// 预留空间
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 12],
 }
 
 impl PerpMarket {
@@ -596,6 +1466,32 @@ impl PerpMarket {
     }
 }
 
+/// 交易对唯一性索引，指向该交易对当前的 Spot/Perp 市场
+/// Seeds: ["pair_index", market_type, base_token_index.to_le_bytes(), quote_token_index.to_le_bytes()]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct PairIndex {
+    /// 账户类型标识符
+    pub discriminator: u64,
+    /// 市场类型
+    pub market_type: MarketType,
+    /// Base Token 索引
+    pub base_token_index: u16,
+    /// Quote Token 索引
+    pub quote_token_index: u16,
+    /// 当前市场索引
+    pub market_index: u16,
+    /// Admin 允许新提案替换仍激活的当前市场（替换完成后清除）
+    pub replacement_allowed: bool,
+    /// PDA bump
+    pub bump: u8,
+    /// 预留空间
+    pub reserved: [u8; 16],
+}
+
+impl PairIndex {
+    pub const DISCRIMINATOR: u64 = PAIR_INDEX_DISCRIMINATOR;
+}
+
 /// Perp 市场上架提案 (PLP-3)
 /// Seeds: ["perp_proposal", proposer, nonce.to_le_bytes()]
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
@@ -638,7 +1534,7 @@ pub struct PerpMarketProposal {
     pub max_open_interest_e6: u64,
     /// 保险金存款 (e6)
     pub insurance_fund_deposit_e6: u64,
-    /// 质押金额
+    /// 质押金额（创建时生效的质押，含动态定价加价）
     pub stake_amount: u64,
     /// 提案状态
     pub status: ProposalStatus,
@@ -654,8 +1550,28 @@ pub struct PerpMarketProposal {
     pub stake_claimed: bool,
     /// PDA bump
     pub bump: u8,
+    /// 被拒绝时的罚没金额
+    pub slash_amount: u64,
+    /// 罚没金额中分给反对者的奖励总额
+    pub objector_reward: u64,
+    /// 争议投票截止时间（审核截止 + 投票期）
+    pub voting_deadline: i64,
+    /// 支持票质押总额 (lamports)
+    pub votes_for: u64,
+    /// 反对票质押总额 (lamports)
+    pub votes_against: u64,
+    /// Reject* 拒绝时间（0 = 未被 Reviewer 拒绝，不可申诉）
+    pub rejected_at: i64,
+    /// 申诉保证金 (lamports，0 = 未申诉)
+    pub appeal_bond: u64,
+    /// 质押资产（创建时取自 ListingConfig.stake_mode）
+    pub stake_mode: StakeMode,
+    /// Oracle 数据源（提案时按 Oracle 账户 owner 识别）
+    pub oracle_source: OracleSource,
+    /// 罚没资金是否仍留在提案托管中（Reject* 拒绝后申诉窗口结束前不转入 Treasury）
+    pub slash_pending: bool,
     /// 预留空间
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 5],
 }
 
 impl PerpMarketProposal {
@@ -712,6 +1628,329 @@ impl LiquidityPool {
     pub const DISCRIMINATOR: u64 = LIQUIDITY_POOL_DISCRIMINATOR;
 }
 
+/// 反对者质押记录 (PLP-1/2/3)
+/// Seeds: ["objection", proposal, objector]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct Objection {
+    /// 账户类型标识符
+    pub discriminator: u64,
+    /// 版本号
+    pub version: u8,
+    /// 提案类型
+    pub proposal_type: ProposalType,
+    /// 提案 PDA
+    pub proposal: Pubkey,
+    /// 反对者
+    pub objector: Pubkey,
+    /// 反对质押金额 (lamports)
+    pub amount: u64,
+    /// 首次反对时间戳
+    pub created_at: i64,
+    /// 质押是否已取回
+    pub claimed: bool,
+    /// PDA bump
+    pub bump: u8,
+    /// 预留空间
+    pub reserved: [u8; 64],
+}
+
+impl Objection {
+    pub const DISCRIMINATOR: u64 = OBJECTION_DISCRIMINATOR;
+}
+
+/// Admin 多签委员会 (PDA)
+/// Seeds: ["admin_council"]
+///
+/// 启用后 ListingConfig.admin 指向该 PDA，Admin 操作需 M-of-N 成员授权。
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct AdminCouncil {
+    /// 账户类型标识符
+    pub discriminator: u64,
+    /// 版本号
+    pub version: u8,
+    /// 成员列表（前 member_count 个有效）
+    pub members: [Pubkey; MAX_COUNCIL_MEMBERS],
+    /// 有效成员数
+    pub member_count: u8,
+    /// 授权所需签名数 (M)
+    pub threshold: u8,
+    /// 成员变更计数（变更后旧的 PendingAction 失效）
+    pub epoch: u32,
+    /// 已创建的 PendingAction 数量（用作下一个 action_id）
+    pub action_count: u64,
+    /// PDA bump
+    pub bump: u8,
+    /// 预留空间
+    pub reserved: [u8; 64],
+}
+
+impl AdminCouncil {
+    pub const DISCRIMINATOR: u64 = ADMIN_COUNCIL_DISCRIMINATOR;
+
+    /// 有效成员
+    pub fn active_members(&self) -> &[Pubkey] {
+        &self.members[..self.member_count as usize]
+    }
+
+    /// 成员在列表中的位置
+    pub fn member_index(&self, key: &Pubkey) -> Option<usize> {
+        self.active_members().iter().position(|m| m == key)
+    }
+}
+
+/// 多签待执行操作 (PDA)
+/// Seeds: ["pending_action", council, action_id.to_le_bytes()]
+///
+/// 记录一条 Listing Program 指令（指令数据 + 账户列表）的哈希，
+/// 达到阈值后由 ExecutePendingAction 以委员会 PDA 签名执行。
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct PendingAction {
+    /// 账户类型标识符
+    pub discriminator: u64,
+    /// 版本号
+    pub version: u8,
+    /// 所属委员会 PDA
+    pub council: Pubkey,
+    /// 操作编号
+    pub action_id: u64,
+    /// 发起成员
+    pub proposer: Pubkey,
+    /// hash(instruction_data || account keys)
+    pub action_hash: [u8; 32],
+    /// 创建时的委员会 epoch
+    pub council_epoch: u32,
+    /// 已批准成员位图（按成员位置）
+    pub approvals: u16,
+    /// 已批准数量
+    pub approval_count: u8,
+    /// 创建时间戳
+    pub created_at: i64,
+    /// 是否已执行
+    pub executed: bool,
+    /// PDA bump
+    pub bump: u8,
+    /// 预留空间
+    pub reserved: [u8; 64],
+}
+
+impl PendingAction {
+    pub const DISCRIMINATOR: u64 = PENDING_ACTION_DISCRIMINATOR;
+}
+
+/// Perp 风险参数时间锁变更 (PDA)
+/// Seeds: ["param_change", market]
+///
+/// 每个市场同时只有一个待生效的变更；执行或取消后可复用该账户。
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct ScheduledParamChange {
+    /// 账户类型标识符
+    pub discriminator: u64,
+    /// 版本号
+    pub version: u8,
+    /// PerpMarket PDA
+    pub market: Pubkey,
+    /// 市场索引
+    pub market_index: u16,
+    /// 新最大杠杆
+    pub max_leverage: Option<u8>,
+    /// 新初始保证金率 (e6)
+    pub initial_margin_rate_e6: Option<u32>,
+    /// 新维持保证金率 (e6)
+    pub maintenance_margin_rate_e6: Option<u32>,
+    /// 新最大持仓量 (e6)
+    pub max_open_interest_e6: Option<u64>,
+    /// 发起者
+    pub scheduled_by: Pubkey,
+    /// 发起时间戳
+    pub scheduled_at: i64,
+    /// 生效时间戳（之后任何人可执行）
+    pub effective_at: i64,
+    /// 是否待生效
+    pub is_pending: bool,
+    /// PDA bump
+    pub bump: u8,
+    /// 预留空间
+    pub reserved: [u8; 64],
+}
+
+impl ScheduledParamChange {
+    pub const DISCRIMINATOR: u64 = PARAM_CHANGE_DISCRIMINATOR;
+}
+
+/// 争议提案投票记录 (PLP-1/2/3)
+/// Seeds: ["vote", proposal, voter]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct Vote {
+    /// 账户类型标识符
+    pub discriminator: u64,
+    /// 版本号
+    pub version: u8,
+    /// 提案类型
+    pub proposal_type: ProposalType,
+    /// 提案 PDA
+    pub proposal: Pubkey,
+    /// 投票者
+    pub voter: Pubkey,
+    /// true = 支持上架, false = 反对上架
+    pub support: bool,
+    /// 锁定质押金额 (lamports)
+    pub amount: u64,
+    /// 首次投票时间戳
+    pub created_at: i64,
+    /// 质押是否已取回
+    pub claimed: bool,
+    /// PDA bump
+    pub bump: u8,
+    /// 预留空间
+    pub reserved: [u8; 64],
+}
+
+impl Vote {
+    pub const DISCRIMINATOR: u64 = VOTE_DISCRIMINATOR;
+}
+
+/// 拒绝原因代码（SlashSchedule.reason_slash_bps 的索引）
+pub const REJECT_REASON_MINOR: u8 = 0;
+pub const REJECT_REASON_MALICIOUS: u8 = 1;
+pub const REJECT_REASON_FRAUD: u8 = 2;
+
+/// 罚没比例表（全局单例，未初始化时使用默认值）
+/// Seeds: ["slash_schedule"]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct SlashSchedule {
+    /// 账户类型标识符
+    pub discriminator: u64,
+    /// 版本号
+    pub version: u8,
+    /// Proposer 自行取消的罚没比例 (bps)
+    pub cancel_slash_bps: u16,
+    /// 社区投票否决的罚没比例 (bps)
+    pub vote_rejection_slash_bps: u16,
+    /// Reject* 罚没比例 (bps)，索引为 reason_code
+    pub reason_slash_bps: [u16; MAX_SLASH_REASONS],
+    /// 有效的拒绝原因数量
+    pub reason_count: u8,
+    /// PDA bump
+    pub bump: u8,
+    /// 预留空间
+    pub reserved: [u8; 32],
+}
+
+impl SlashSchedule {
+    pub const DISCRIMINATOR: u64 = SLASH_SCHEDULE_DISCRIMINATOR;
+
+    /// 默认自行取消罚没 (5%)
+    pub const DEFAULT_CANCEL_SLASH_BPS: u16 = 500;
+    /// 默认投票否决罚没 (10%)
+    pub const DEFAULT_VOTE_REJECTION_SLASH_BPS: u16 = 1_000;
+    /// 默认拒绝罚没：轻微违规 10% / 恶意行为 50% / 欺诈 100%
+    pub const DEFAULT_REASON_SLASH_BPS: [u16; 3] = [1_000, 5_000, 10_000];
+
+    /// 默认罚没比例表（与 README 罚没规则一致）
+    pub fn default_schedule(bump: u8) -> Self {
+        let mut reason_slash_bps = [0u16; MAX_SLASH_REASONS];
+        reason_slash_bps[..Self::DEFAULT_REASON_SLASH_BPS.len()]
+            .copy_from_slice(&Self::DEFAULT_REASON_SLASH_BPS);
+        Self {
+            discriminator: Self::DISCRIMINATOR,
+            version: 1,
+            cancel_slash_bps: Self::DEFAULT_CANCEL_SLASH_BPS,
+            vote_rejection_slash_bps: Self::DEFAULT_VOTE_REJECTION_SLASH_BPS,
+            reason_slash_bps,
+            reason_count: Self::DEFAULT_REASON_SLASH_BPS.len() as u8,
+            bump,
+            reserved: [0u8; 32],
+        }
+    }
+
+    /// 拒绝原因对应的罚没比例 (None = 未定义的 reason_code)
+    pub fn reason_slash_bps(&self, reason_code: u8) -> Option<u16> {
+        if reason_code >= self.reason_count {
+            return None;
+        }
+        self.reason_slash_bps.get(reason_code as usize).copied()
+    }
+}
+
+
+/// 动态质押定价（全局单例，未初始化时不启用）
+/// Seeds: ["stake_pricing"]
+///
+/// 新提案质押 = 基础质押 × (10000 + surge_bps) / 10000，上限 max_multiplier_bps。
+/// surge_bps 取「超出 free_pending 的待审提案数 × per_pending_bps」与
+/// 上次加价按 decay_seconds 线性衰减后的较大值。
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct StakePricing {
+    /// 账户类型标识符
+    pub discriminator: u64,
+    /// 版本号
+    pub version: u8,
+    /// 是否启用动态定价（false = 使用 ListingConfig 中的固定质押）
+    pub enabled: bool,
+    /// 不加价的待审提案数
+    pub free_pending: u16,
+    /// 每个超出的待审提案加价 (bps)
+    pub per_pending_bps: u16,
+    /// 质押倍数上限 (bps，10000 = 1x)
+    pub max_multiplier_bps: u32,
+    /// 加价线性衰减到 0 的时间（秒）
+    pub decay_seconds: u32,
+    /// 最近一次提案时的加价 (bps)
+    pub surge_bps: u32,
+    /// surge_bps 更新时间
+    pub surge_updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// 预留空间
+    pub reserved: [u8; 32],
+}
+
+impl StakePricing {
+    pub const DISCRIMINATOR: u64 = STAKE_PRICING_DISCRIMINATOR;
+
+    /// 默认不加价的待审提案数
+    pub const DEFAULT_FREE_PENDING: u16 = 10;
+    /// 默认每个超出的待审提案加价 5%
+    pub const DEFAULT_PER_PENDING_BPS: u16 = 500;
+    /// 默认质押倍数上限 5x
+    pub const DEFAULT_MAX_MULTIPLIER_BPS: u32 = 50_000;
+    /// 默认衰减时间 7 天
+    pub const DEFAULT_DECAY_SECONDS: u32 = 7 * 24 * 60 * 60;
+
+    /// 默认定价参数（未启用）
+    pub fn default_pricing(bump: u8) -> Self {
+        Self {
+            discriminator: Self::DISCRIMINATOR,
+            version: 1,
+            enabled: false,
+            free_pending: Self::DEFAULT_FREE_PENDING,
+            per_pending_bps: Self::DEFAULT_PER_PENDING_BPS,
+            max_multiplier_bps: Self::DEFAULT_MAX_MULTIPLIER_BPS,
+            decay_seconds: Self::DEFAULT_DECAY_SECONDS,
+            surge_bps: 0,
+            surge_updated_at: 0,
+            bump,
+            reserved: [0u8; 32],
+        }
+    }
+}
+// =============================================================================
+// 返回数据
+// =============================================================================
+
+/// AuditTreasury 返回数据
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct TreasuryAudit {
+    /// Treasury 当前余额 (lamports)
+    pub treasury_lamports: u64,
+    /// Treasury 中托管的未结算质押 (lamports)
+    pub total_staked_lamports: u64,
+    /// 租金豁免最低余额 (lamports)
+    pub rent_minimum: u64,
+    /// 余额 - 未结算质押 - 租金豁免最低余额（负数表示资不抵债）
+    pub surplus_lamports: i64,
+}
+
 // =============================================================================
 // 测试
 // =============================================================================
@@ -740,7 +1979,11 @@ mod tests {
             proposer: Pubkey::default(),
             approved_at: 0,
             bump: 255,
-            reserved: [0u8; 64],
+            asset_kind: AssetKind::SplMint,
+            risk_flags: 0,
+            metadata: Pubkey::default(),
+            oracle_source: OracleSource::PythV2,
+            reserved: [0u8; 29],
         };
         
         // Set symbol to "BTC"
@@ -776,6 +2019,111 @@ mod tests {
         assert_eq!(market.symbol_str(), "BTC/USDC");
     }
 
+    #[test]
+    fn test_default_slash_schedule() {
+        let schedule = SlashSchedule::default_schedule(255);
+        assert_eq!(schedule.cancel_slash_bps, 500);
+        assert_eq!(schedule.reason_slash_bps(REJECT_REASON_MINOR), Some(1_000));
+        assert_eq!(schedule.reason_slash_bps(REJECT_REASON_MALICIOUS), Some(5_000));
+        assert_eq!(schedule.reason_slash_bps(REJECT_REASON_FRAUD), Some(10_000));
+        assert_eq!(schedule.reason_slash_bps(3), None);
+
+        let data = schedule.try_to_vec().unwrap();
+        assert_eq!(data.len(), SLASH_SCHEDULE_SIZE);
+    }
+
+    #[test]
+    fn test_default_stake_pricing() {
+        let pricing = StakePricing::default_pricing(255);
+        assert!(!pricing.enabled);
+
+        let data = pricing.try_to_vec().unwrap();
+        assert_eq!(data.len(), STAKE_PRICING_SIZE);
+    }
+
+    #[test]
+    fn test_mint_authorities_tail() {
+        let authorities = MintAuthorities {
+            mint_authority: Some(Pubkey::new_unique()),
+            freeze_authority: Some(Pubkey::new_unique()),
+        };
+        assert_eq!(authorities.try_to_vec().unwrap().len(), MINT_AUTHORITIES_SIZE);
+
+        let mut data = vec![0u8; TOKEN_PROPOSAL_ACCOUNT_SIZE];
+        authorities.store(&mut data).unwrap();
+        assert_eq!(MintAuthorities::load(&data), Some(authorities));
+
+        // 旧提案账户没有权限快照
+        let legacy = vec![0u8; TOKEN_PROPOSAL_SIZE];
+        assert_eq!(MintAuthorities::load(&legacy), None);
+        assert!(MintAuthorities::default().store(&mut legacy.clone()).is_err());
+    }
+
+    #[test]
+    fn test_oracle_risk_config_tail() {
+        let oracle_config = OracleRiskConfig {
+            max_staleness_seconds: 3600,
+            max_confidence_bps: 200,
+            fallback_oracle: Pubkey::new_unique(),
+            fallback_source: OracleSource::Switchboard,
+        };
+        assert_eq!(oracle_config.try_to_vec().unwrap().len(), ORACLE_RISK_CONFIG_SIZE);
+
+        let mut data = vec![0u8; TOKEN_REGISTRY_ACCOUNT_SIZE];
+        oracle_config.store(&mut data).unwrap();
+        assert_eq!(OracleRiskConfig::load(&data), oracle_config);
+
+        // 旧 Registry 账户没有尾部，使用默认配置
+        let legacy = vec![0u8; TOKEN_REGISTRY_SIZE];
+        assert_eq!(OracleRiskConfig::load(&legacy), OracleRiskConfig::default());
+        assert!(!OracleRiskConfig::load(&legacy).has_fallback());
+        assert!(oracle_config.store(&mut legacy.clone()).is_err());
+    }
+
+    #[test]
+    fn test_oracle_feed_tail() {
+        let feed = OracleFeed { feed_id: [7u8; 32] };
+        assert_eq!(feed.try_to_vec().unwrap().len(), ORACLE_FEED_SIZE);
+
+        // 喂价 ID 位于其他尾部数据之后，互不覆盖
+        let authorities = MintAuthorities {
+            mint_authority: Some(Pubkey::new_unique()),
+            freeze_authority: Some(Pubkey::new_unique()),
+        };
+        let mut data = vec![0u8; TOKEN_PROPOSAL_ACCOUNT_SIZE];
+        authorities.store(&mut data).unwrap();
+        feed.store(&mut data, TOKEN_PROPOSAL_FEED_OFFSET).unwrap();
+        assert_eq!(MintAuthorities::load(&data), Some(authorities));
+        assert_eq!(OracleFeed::load(&data, TOKEN_PROPOSAL_FEED_OFFSET), feed);
+
+        let mut data = vec![0u8; TOKEN_REGISTRY_ACCOUNT_SIZE];
+        OracleRiskConfig::default().store(&mut data).unwrap();
+        feed.store(&mut data, TOKEN_REGISTRY_FEED_OFFSET).unwrap();
+        assert_eq!(OracleFeed::load(&data, TOKEN_REGISTRY_FEED_OFFSET), feed);
+
+        // 旧账户没有喂价 ID，不做比对
+        let legacy = vec![0u8; PERP_PROPOSAL_SIZE];
+        assert!(!OracleFeed::load(&legacy, PERP_PROPOSAL_SIZE).is_set());
+        assert!(feed.store(&mut legacy.clone(), PERP_PROPOSAL_SIZE).is_err());
+        let mut data = vec![0u8; PERP_PROPOSAL_ACCOUNT_SIZE];
+        feed.store(&mut data, PERP_PROPOSAL_SIZE).unwrap();
+        assert_eq!(OracleFeed::load(&data, PERP_PROPOSAL_SIZE), feed);
+    }
+
+    #[test]
+    fn test_stake_mode_from_reserved() {
+        // 旧账户的预留字节为 0，解析为原生质押
+        assert_eq!(StakeMode::try_from_slice(&[0]).unwrap(), StakeMode::Native);
+        assert_eq!(StakeMode::try_from_slice(&[1]).unwrap(), StakeMode::Spl);
+        assert!(StakeMode::try_from_slice(&[2]).is_err());
+    }
+
+    #[test]
+    fn test_pause_scopes() {
+        assert_eq!(PAUSE_ALL, 0x1F);
+        assert_eq!(PAUSE_ALL & PAUSE_FINALIZATION, PAUSE_FINALIZATION);
+    }
+
     #[test]
     fn test_listing_config_defaults() {
         // N1024 has 9 decimals, so 1,000 N1024 = 1e12 lamports
@@ -785,6 +2133,81 @@ mod tests {
         assert_eq!(ListingConfig::DEFAULT_TOKEN_REVIEW_PERIOD, 604_800);
         assert_eq!(ListingConfig::DEFAULT_PERP_REVIEW_PERIOD, 1_209_600);
         assert_eq!(ListingConfig::DEFAULT_STAKE_LOCK_PERIOD, 2_592_000);
+        assert_eq!(ListingConfig::DEFAULT_OBJECTION_FORFEIT_BPS, 1_000);
+        assert_eq!(ListingConfig::DEFAULT_OBJECTOR_REWARD_BPS, 5_000);
+        assert_eq!(ListingConfig::DEFAULT_PARAM_CHANGE_DELAY, 86_400);
+        assert_eq!(ListingConfig::DEFAULT_VOTING_PERIOD, 259_200);
+        assert_eq!(ListingConfig::DEFAULT_APPEAL_WINDOW, 604_800);
+    }
+
+    #[test]
+    fn test_listing_config_legacy_layout() {
+        // 初版布局：bump 之后为 64 字节预留空间
+        let admin = Pubkey::new_unique();
+        let treasury = Pubkey::new_unique();
+        let mut legacy = Vec::new();
+        (LISTING_CONFIG_DISCRIMINATOR, 1u8, admin, treasury).serialize(&mut legacy).unwrap();
+        (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()).serialize(&mut legacy).unwrap();
+        (1_000u64, 2_000u64, 5_000u64).serialize(&mut legacy).unwrap();
+        (604_800u32, 604_800u32, 1_209_600u32, 2_592_000u32).serialize(&mut legacy).unwrap();
+        (3u16, 1u16, 2u16, 42u64, false, 254u8, [0u8; 64]).serialize(&mut legacy).unwrap();
+        assert_eq!(legacy.len(), LEGACY_LISTING_CONFIG_SIZE);
+        assert!(ListingConfig::try_from_slice(&legacy).is_err());
+
+        let config = ListingConfig::unpack(&legacy).unwrap();
+        assert_eq!((config.admin, config.treasury), (admin, treasury));
+        assert_eq!((config.total_perp_markets, config.total_staked_lamports, config.bump), (2, 42, 254));
+
+        // 角色、Guardian、待接受 Admin 未设置（角色权限回落到 Admin）
+        for role in [Role::Reviewer, Role::RiskManager, Role::Pauser, Role::TreasuryManager, Role::PoolOperator] {
+            assert_eq!(config.role_authority(role), &Pubkey::default());
+        }
+        assert_eq!(config.pending_admin, Pubkey::default());
+        assert_eq!(config.guardian, Pubkey::default());
+        assert_eq!(config.voting_period_seconds, ListingConfig::DEFAULT_VOTING_PERIOD);
+        assert_eq!(config.appeal_bond_percentage, ListingConfig::DEFAULT_APPEAL_BOND_PERCENTAGE);
+
+        // 迁移后按当前布局写回
+        let migrated = config.try_to_vec().unwrap();
+        assert_eq!(migrated.len(), LISTING_CONFIG_SIZE);
+        assert_eq!(ListingConfig::unpack(&migrated).unwrap().admin, admin);
     }
-}
 
+    #[test]
+    fn test_asset_kind_layout() {
+        // asset_kind 沿用原 is_token_2022 字节：false/true 分别对应 SplMint/Token2022Mint
+        assert_eq!(AssetKind::try_from_slice(&[0]).unwrap(), AssetKind::SplMint);
+        assert_eq!(AssetKind::try_from_slice(&[1]).unwrap(), AssetKind::Token2022Mint);
+        assert_eq!(AssetKind::try_from_slice(&[2]).unwrap(), AssetKind::Synthetic);
+        assert!(AssetKind::try_from_slice(&[3]).is_err());
+    }
+
+    #[test]
+    fn test_token_metadata_layout() {
+        let mut content = TokenMetadataContent {
+            category: TokenCategory::Equity,
+            ..Default::default()
+        };
+        content.name[..10].copy_from_slice(b"Apple Inc.");
+        content.uri[..19].copy_from_slice(b"https://example.com");
+        assert_eq!(content.name_str(), "Apple Inc.");
+        assert_eq!(content.uri_str(), "https://example.com");
+
+        let metadata = TokenMetadata {
+            discriminator: TokenMetadata::DISCRIMINATOR,
+            version: 1,
+            proposal: Pubkey::default(),
+            proposer: Pubkey::default(),
+            content: content.clone(),
+            updated_at: 0,
+            pending: TokenMetadataContent::default(),
+            has_pending: false,
+            scheduled_by: Pubkey::default(),
+            effective_at: 0,
+            bump: 255,
+            reserved: [0u8; 64],
+        };
+        assert_eq!(content.try_to_vec().unwrap().len(), TOKEN_METADATA_CONTENT_SIZE);
+        assert_eq!(metadata.try_to_vec().unwrap().len(), TOKEN_METADATA_SIZE);
+    }
+}
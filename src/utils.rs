@@ -1,30 +1,31 @@
 //! Listing Program Utility Functions
 
+use borsh::BorshDeserialize;
 use solana_program::{
-    account_info::AccountInfo,
+    account_info::{next_account_info, AccountInfo},
     clock::Clock,
     entrypoint::ProgramResult,
+    hash::Hasher,
     msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
+    program_pack::Pack,
     pubkey::Pubkey,
     rent::Rent,
     system_instruction,
     sysvar::Sysvar,
 };
-use pyth_sdk_solana::state::SolanaPriceAccount;
+use spl_token_2022::extension::{
+    default_account_state::DefaultAccountState, BaseStateWithExtensions, ExtensionType,
+    StateWithExtensions,
+};
 
 use crate::error::ListingError;
 use crate::state::*;
 
-/// Pyth Program ID on Mainnet
-pub const PYTH_MAINNET_PROGRAM_ID: &str = "FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH";
-/// Pyth Program ID on Devnet
-pub const PYTH_DEVNET_PROGRAM_ID: &str = "gSbePebfvPy7tRqimPoVecS2UsBvYv46ynrzWocc92s";
 
-/// Oracle 验证配置
-pub const ORACLE_MAX_CONFIDENCE_RATIO: u32 = 5; // 5% 最大置信区间比率
-pub const ORACLE_MAX_STALENESS_SECONDS: i64 = 60; // 60秒最大陈旧时间
+/// 基点分母 (10000 bps = 100%)
+pub const BPS_DENOMINATOR: u64 = 10_000;
 
 /// 验证 PDA 地址
 pub fn verify_pda(
@@ -49,6 +50,37 @@ pub fn derive_listing_config_pda(program_id: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[LISTING_CONFIG_SEED], program_id)
 }
 
+/// 加载并验证 ListingConfig PDA
+///
+/// 验证 owner、discriminator 及地址（按存储的 bump 重新派生），
+/// 拒绝伪造的配置账户（其中的 admin / 角色 / treasury 均不可信）。
+/// 旧版布局可以读取，但写回前须先执行 MigrateListingConfig。
+pub fn load_listing_config(
+    program_id: &Pubkey,
+    config_account: &AccountInfo,
+) -> Result<ListingConfig, ProgramError> {
+    if config_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let config = ListingConfig::unpack(&config_account.data.borrow())?;
+    if config.discriminator != ListingConfig::DISCRIMINATOR {
+        return Err(ListingError::InvalidAccount.into());
+    }
+    if config_account.data_len() == LEGACY_LISTING_CONFIG_SIZE {
+        msg!("ListingConfig uses the legacy layout; run MigrateListingConfig before updating it");
+    }
+    let expected_config = Pubkey::create_program_address(
+        &[LISTING_CONFIG_SEED, &[config.bump]],
+        program_id,
+    )
+    .map_err(|_| ListingError::InvalidPda)?;
+    if config_account.key != &expected_config {
+        msg!("Invalid ListingConfig account: {}", config_account.key);
+        return Err(ListingError::InvalidPda.into());
+    }
+    Ok(config)
+}
+
 /// 验证并派生 Treasury PDA
 /// 存放原生 N1024 质押
 pub fn derive_treasury_pda(program_id: &Pubkey) -> (Pubkey, u8) {
@@ -66,6 +98,59 @@ pub fn derive_token_registry_pda(
     )
 }
 
+/// 加载并验证 token_index 对应的 TokenRegistry
+///
+/// 验证 owner、discriminator 及地址（按存储的 bump 重新派生），
+/// 拒绝伪造的 Registry（其中的 asset_kind 等字段均不可信）。
+pub fn load_token_registry(
+    program_id: &Pubkey,
+    registry_account: &AccountInfo,
+    token_index: u16,
+) -> Result<TokenRegistry, ProgramError> {
+    if registry_account.owner != program_id {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    let registry = TokenRegistry::deserialize(&mut &registry_account.data.borrow()[..])?;
+    if registry.discriminator != TokenRegistry::DISCRIMINATOR {
+        return Err(ListingError::InvalidAccount.into());
+    }
+    let expected_registry = Pubkey::create_program_address(
+        &[TOKEN_REGISTRY_SEED, &token_index.to_le_bytes(), &[registry.bump]],
+        program_id,
+    )
+    .map_err(|_| ListingError::InvalidPda)?;
+    if registry_account.key != &expected_registry {
+        msg!("Invalid TokenRegistry account: {}", registry_account.key);
+        return Err(ListingError::InvalidPda.into());
+    }
+    Ok(registry)
+}
+
+/// 验证 ListingConfig 仍为旧版布局（MigrateListingConfig 只处理旧版账户）
+pub fn verify_legacy_listing_config(config_account: &AccountInfo) -> ProgramResult {
+    if config_account.data_len() != LEGACY_LISTING_CONFIG_SIZE {
+        msg!("ListingConfig already uses the current layout");
+        return Err(ListingError::AlreadyInitialized.into());
+    }
+    Ok(())
+}
+
+/// 派生 MintIndex PDA
+pub fn derive_mint_index_pda(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MINT_INDEX_SEED, mint.as_ref()], program_id)
+}
+
+/// 派生 SymbolIndex PDA（seed 为去掉尾部 0 的 symbol，保证同一 symbol 只有一个索引）
+pub fn derive_symbol_index_pda(symbol: &[u8; 8], program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SYMBOL_INDEX_SEED, symbol_seed(symbol)], program_id)
+}
+
+/// Symbol 的 PDA seed（去掉尾部 0）
+pub fn symbol_seed(symbol: &[u8; 8]) -> &[u8] {
+    let len = symbol.iter().position(|&c| c == 0).unwrap_or(8);
+    &symbol[..len]
+}
+
 /// 验证并派生 TokenProposal PDA
 pub fn derive_token_proposal_pda(
     proposer: &Pubkey,
@@ -89,6 +174,46 @@ pub fn derive_spot_market_pda(
     )
 }
 
+/// 派生 PairIndex PDA
+pub fn derive_pair_index_pda(
+    market_type: MarketType,
+    base_token_index: u16,
+    quote_token_index: u16,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PAIR_INDEX_SEED,
+            &[market_type as u8],
+            &base_token_index.to_le_bytes(),
+            &quote_token_index.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// 验证并派生 TokenMetadata PDA
+pub fn derive_token_metadata_pda(
+    proposal: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[TOKEN_METADATA_SEED, proposal.as_ref()],
+        program_id,
+    )
+}
+
+/// 验证并派生 PushOracle PDA
+pub fn derive_push_oracle_pda(
+    feed_id: &[u8; 32],
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PUSH_ORACLE_SEED, feed_id],
+        program_id,
+    )
+}
+
 /// 验证并派生 SpotMarketProposal PDA
 pub fn derive_spot_proposal_pda(
     proposer: &Pubkey,
@@ -124,6 +249,63 @@ pub fn derive_perp_proposal_pda(
     )
 }
 
+/// 验证并派生 Objection PDA
+pub fn derive_objection_pda(
+    proposal: &Pubkey,
+    objector: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[OBJECTION_SEED, proposal.as_ref(), objector.as_ref()],
+        program_id,
+    )
+}
+
+/// 验证并派生 AdminCouncil PDA
+pub fn derive_admin_council_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ADMIN_COUNCIL_SEED], program_id)
+}
+
+/// 验证并派生 PendingAction PDA
+pub fn derive_pending_action_pda(
+    council: &Pubkey,
+    action_id: u64,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PENDING_ACTION_SEED, council.as_ref(), &action_id.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// 验证并派生 ScheduledParamChange PDA
+pub fn derive_param_change_pda(
+    market: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PARAM_CHANGE_SEED, market.as_ref()],
+        program_id,
+    )
+}
+
+/// 验证并派生 Vote PDA
+pub fn derive_vote_pda(
+    proposal: &Pubkey,
+    voter: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VOTE_SEED, proposal.as_ref(), voter.as_ref()],
+        program_id,
+    )
+}
+
+/// 验证并派生 SlashSchedule PDA
+pub fn derive_slash_schedule_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SLASH_SCHEDULE_SEED], program_id)
+}
+
 /// 验证并派生 LiquidityPool PDA
 pub fn derive_liquidity_pool_pda(
     market: &Pubkey,
@@ -160,19 +342,208 @@ pub fn create_account<'a>(
     )
 }
 
+/// 扩容程序账户（由 payer 补足租金，新增空间清零）
+pub fn resize_account<'a>(
+    payer: &AccountInfo<'a>,
+    account: &AccountInfo<'a>,
+    new_size: usize,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    let rent = Rent::get()?;
+    let shortfall = rent.minimum_balance(new_size).saturating_sub(account.lamports());
+    if shortfall > 0 {
+        transfer_native_lamports(payer, account, shortfall, system_program, None)?;
+    }
+    account.realloc(new_size, true)
+}
+
 /// 验证 Admin
+///
+/// - 单签: admin 签名且等于 config.admin
+/// - 多签: config.admin 为 AdminCouncil PDA 时，`accounts` 中需有 threshold 个成员签名；
+///   或由 ExecutePendingAction 执行已批准的 PendingAction（委员会 PDA 签名）
 pub fn verify_admin(
     admin: &AccountInfo,
     config: &ListingConfig,
+    accounts: &[AccountInfo],
 ) -> ProgramResult {
-    if !admin.is_signer {
-        msg!("Admin must be a signer");
-        return Err(ProgramError::MissingRequiredSignature);
-    }
     if admin.key != &config.admin {
         msg!("Invalid admin");
         return Err(ListingError::InvalidAdmin.into());
     }
+    if admin.is_signer {
+        return Ok(());
+    }
+    match load_admin_council(admin) {
+        Some(council) => verify_council_signers(&council, accounts),
+        None => {
+            msg!("Admin must be a signer");
+            Err(ProgramError::MissingRequiredSignature)
+        }
+    }
+}
+
+/// 加载 AdminCouncil（账户不是本程序的委员会 PDA 时返回 None）
+pub fn load_admin_council(account: &AccountInfo) -> Option<AdminCouncil> {
+    if account.data_len() != ADMIN_COUNCIL_SIZE {
+        return None;
+    }
+    let council = AdminCouncil::try_from_slice(&account.data.borrow()).ok()?;
+    if council.discriminator != AdminCouncil::DISCRIMINATOR {
+        return None;
+    }
+    // 委员会数据只能由 owner 程序写入，且地址必须是 owner 的 ["admin_council"] PDA
+    verify_pda(&[ADMIN_COUNCIL_SEED], council.bump, account.owner, account.key).ok()?;
+    Some(council)
+}
+
+/// 统计 accounts 中签名的委员会成员数（每个成员只计一次）
+pub fn count_council_signers(council: &AdminCouncil, accounts: &[AccountInfo]) -> usize {
+    council
+        .active_members()
+        .iter()
+        .filter(|member| accounts.iter().any(|a| a.is_signer && a.key == *member))
+        .count()
+}
+
+/// 验证委员会成员签名数达到阈值
+pub fn verify_council_signers(
+    council: &AdminCouncil,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let signers = count_council_signers(council, accounts);
+    if signers < council.threshold as usize {
+        msg!("Council signers: {} / {}", signers, council.threshold);
+        return Err(ListingError::InsufficientApprovals.into());
+    }
+    Ok(())
+}
+
+/// 验证委员会成员列表和阈值
+/// - 1 到 MAX_COUNCIL_MEMBERS 个成员，不可重复，不可为默认地址
+/// - 1 <= threshold <= 成员数
+pub fn validate_council(members: &[Pubkey], threshold: u8) -> ProgramResult {
+    if members.is_empty() || members.len() > MAX_COUNCIL_MEMBERS {
+        return Err(ListingError::InvalidCouncilConfig.into());
+    }
+    if threshold == 0 || threshold as usize > members.len() {
+        return Err(ListingError::InvalidCouncilConfig.into());
+    }
+    for (i, member) in members.iter().enumerate() {
+        if *member == Pubkey::default() || members[..i].contains(member) {
+            return Err(ListingError::InvalidCouncilConfig.into());
+        }
+    }
+    Ok(())
+}
+
+/// 验证暂停权限（Guardian 只能暂停；恢复需 Pauser 或 Admin）
+pub fn verify_pause_authority(
+    authority: &AccountInfo,
+    config: &ListingConfig,
+    pausing: bool,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if pausing
+        && authority.is_signer
+        && config.guardian != Pubkey::default()
+        && authority.key == &config.guardian
+    {
+        return Ok(());
+    }
+    verify_role(authority, config, Role::Pauser, accounts)
+}
+
+/// 检查指定范围未暂停
+pub fn verify_not_paused(config: &ListingConfig, scope: u8) -> ProgramResult {
+    if config.is_scope_paused(scope) {
+        msg!("Paused scope: {:#04x}", scope);
+        return Err(ListingError::ListingPaused.into());
+    }
+    Ok(())
+}
+
+/// 获取租金支付账户
+///
+/// 授权账户为多签委员会 PDA 时（带数据，不能作为 System Program 转账来源），
+/// 由紧随其后的额外签名账户支付。
+pub fn resolve_payer<'a, 'b, I: Iterator<Item = &'a AccountInfo<'b>>>(
+    authority: &'a AccountInfo<'b>,
+    account_iter: &mut I,
+) -> Result<&'a AccountInfo<'b>, ProgramError> {
+    if authority.data_is_empty() {
+        return Ok(authority);
+    }
+    let payer = next_account_info(account_iter)?;
+    if !payer.is_signer {
+        msg!("Payer must be a signer");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(payer)
+}
+
+/// 计算 PendingAction 哈希: hash(instruction_data || account keys)
+pub fn calculate_action_hash<'a>(
+    instruction_data: &[u8],
+    account_keys: impl Iterator<Item = &'a Pubkey>,
+) -> [u8; 32] {
+    let mut hasher = Hasher::default();
+    hasher.hash(instruction_data);
+    for key in account_keys {
+        hasher.hash(key.as_ref());
+    }
+    hasher.result().to_bytes()
+}
+
+/// 检查签名者是否拥有指定角色（Admin 拥有全部角色）
+pub fn has_role(
+    authority: &AccountInfo,
+    config: &ListingConfig,
+    role: Role,
+) -> bool {
+    if !authority.is_signer {
+        return false;
+    }
+    let role_authority = config.role_authority(role);
+    authority.key == &config.admin
+        || (role_authority != &Pubkey::default() && authority.key == role_authority)
+}
+
+/// 验证角色权限（Admin 按 verify_admin 规则验证，支持多签）
+pub fn verify_role(
+    authority: &AccountInfo,
+    config: &ListingConfig,
+    role: Role,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if authority.key == &config.admin {
+        return verify_admin(authority, config, accounts);
+    }
+    if !authority.is_signer {
+        msg!("{:?} must be a signer", role);
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !has_role(authority, config, role) {
+        msg!("Signer does not hold role {:?}", role);
+        return Err(ListingError::Unauthorized.into());
+    }
+    Ok(())
+}
+
+/// 验证 PLP-4 流动性池操作权限：Pool 创建者签名，或按 verify_role 验证 PoolOperator
+pub fn verify_pool_authority(
+    authority: &AccountInfo,
+    pool_creator: &Pubkey,
+    config: &ListingConfig,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if authority.key != pool_creator {
+        return verify_role(authority, config, Role::PoolOperator, accounts);
+    }
+    if !authority.is_signer {
+        msg!("Pool creator must be a signer");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
     Ok(())
 }
 
@@ -188,7 +559,7 @@ pub fn validate_symbol(symbol: &[u8; 8]) -> ProgramResult {
     }
     
     for &c in &symbol[..len] {
-        if !((c >= b'A' && c <= b'Z') || (c >= b'0' && c <= b'9')) {
+        if !(c.is_ascii_uppercase() || c.is_ascii_digit()) {
             msg!("Invalid symbol character: {}", c as char);
             return Err(ListingError::InvalidSymbol.into());
         }
@@ -197,6 +568,33 @@ pub fn validate_symbol(symbol: &[u8; 8]) -> ProgramResult {
     Ok(())
 }
 
+/// 验证 Token 元数据
+/// - name 非空，name / uri 为 UTF-8
+/// - 0 只能作为尾部填充出现
+pub fn validate_token_metadata(content: &TokenMetadataContent) -> ProgramResult {
+    match padded_utf8_len(&content.name) {
+        Some(len) if len > 0 => {}
+        _ => {
+            msg!("Invalid token name");
+            return Err(ListingError::InvalidTokenMetadata.into());
+        }
+    }
+    if padded_utf8_len(&content.uri).is_none() {
+        msg!("Invalid token metadata URI");
+        return Err(ListingError::InvalidTokenMetadata.into());
+    }
+    Ok(())
+}
+
+/// 尾部 0 填充字段的有效长度（内容含 0 或非 UTF-8 时返回 None）
+fn padded_utf8_len(bytes: &[u8]) -> Option<usize> {
+    let len = bytes.iter().position(|&c| c == 0).unwrap_or(bytes.len());
+    if bytes[len..].iter().any(|&c| c != 0) || std::str::from_utf8(&bytes[..len]).is_err() {
+        return None;
+    }
+    Some(len)
+}
+
 /// 验证 Market Symbol 格式
 /// - Spot: 必须包含 '/'，如 "BTC/USDC"
 /// - Perp: 必须包含 '-'，如 "BTC-USDC"
@@ -236,7 +634,7 @@ pub fn validate_fee_rates(taker_fee_bps: u16, maker_fee_bps: i16) -> ProgramResu
         msg!("Taker fee too high: {} bps (max 1000)", taker_fee_bps);
         return Err(ListingError::InvalidFeeRate.into());
     }
-    if maker_fee_bps < -500 || maker_fee_bps > 500 {
+    if !(-500..=500).contains(&maker_fee_bps) {
         msg!("Maker fee out of range: {} bps (-500 ~ 500)", maker_fee_bps);
         return Err(ListingError::InvalidFeeRate.into());
     }
@@ -275,6 +673,89 @@ pub fn validate_margin_rates(
     Ok(())
 }
 
+/// 已上线过的 Perp 市场（approved_at != 0，停用后同样适用）的风险参数变更须走时间锁，
+/// 仅下调 OI 上限可立即生效
+pub fn is_perp_param_change_timelocked(
+    market: &PerpMarket,
+    max_leverage: Option<u8>,
+    initial_margin_rate_e6: Option<u32>,
+    maintenance_margin_rate_e6: Option<u32>,
+    max_open_interest_e6: Option<u64>,
+) -> bool {
+    if market.approved_at == 0 {
+        return false;
+    }
+    let raises_oi = max_open_interest_e6
+        .is_some_and(|max_oi| max_oi > market.max_open_interest_e6);
+    max_leverage.is_some()
+        || initial_margin_rate_e6.is_some()
+        || maintenance_margin_rate_e6.is_some()
+        || raises_oi
+}
+
+/// 校验并写入 Perp 风险参数（杠杆、保证金率、OI 上限）
+///
+/// 保证金率按写入后的 (初始, 维持) 组合校验，未提供的一侧取市场当前值。
+pub fn apply_perp_risk_params(
+    market: &mut PerpMarket,
+    max_leverage: Option<u8>,
+    initial_margin_rate_e6: Option<u32>,
+    maintenance_margin_rate_e6: Option<u32>,
+    max_open_interest_e6: Option<u64>,
+) -> ProgramResult {
+    if let Some(leverage) = max_leverage {
+        validate_leverage(leverage)?;
+    }
+    if initial_margin_rate_e6.is_some() || maintenance_margin_rate_e6.is_some() {
+        validate_margin_rates(
+            initial_margin_rate_e6.unwrap_or(market.initial_margin_rate_e6),
+            maintenance_margin_rate_e6.unwrap_or(market.maintenance_margin_rate_e6),
+        )?;
+    }
+
+    if let Some(leverage) = max_leverage {
+        market.max_leverage = leverage;
+        msg!("Max leverage updated to: {}x", leverage);
+    }
+    if let Some(rate) = initial_margin_rate_e6 {
+        market.initial_margin_rate_e6 = rate;
+        msg!("Initial margin rate updated to: {} (e6)", rate);
+    }
+    if let Some(rate) = maintenance_margin_rate_e6 {
+        market.maintenance_margin_rate_e6 = rate;
+        msg!("Maintenance margin rate updated to: {} (e6)", rate);
+    }
+    if let Some(max_oi) = max_open_interest_e6 {
+        market.max_open_interest_e6 = max_oi;
+        msg!("Max open interest updated to: {} (e6)", max_oi);
+    }
+    Ok(())
+}
+
+/// 执行已到期的 ScheduledParamChange：按执行时的市场参数重新校验并写入
+pub fn apply_scheduled_param_change(
+    market: &mut PerpMarket,
+    change: &mut ScheduledParamChange,
+    current_ts: i64,
+) -> ProgramResult {
+    if !change.is_pending {
+        return Err(ListingError::NoScheduledParamChange.into());
+    }
+    if current_ts < change.effective_at {
+        msg!("Effective at: {}, now: {}", change.effective_at, current_ts);
+        return Err(ListingError::ParamChangeNotEffective.into());
+    }
+    apply_perp_risk_params(
+        market,
+        change.max_leverage,
+        change.initial_margin_rate_e6,
+        change.maintenance_margin_rate_e6,
+        change.max_open_interest_e6,
+    )?;
+    change.is_pending = false;
+    Ok(())
+}
+
 /// 验证 tick_size 和 lot_size
 pub fn validate_sizes(tick_size_e6: u64, lot_size_e6: u64) -> ProgramResult {
     if tick_size_e6 == 0 {
@@ -301,152 +782,415 @@ pub fn validate_price_range(price_lower_e6: u64, price_upper_e6: u64) -> Program
     Ok(())
 }
 
-/// 获取当前时间戳
-pub fn get_current_timestamp() -> Result<i64, ProgramError> {
-    let clock = Clock::get()?;
-    Ok(clock.unix_timestamp)
+/// 验证基点参数 (0-10000)
+pub fn validate_bps(bps: u16) -> ProgramResult {
+    if bps as u64 > BPS_DENOMINATOR {
+        msg!("Invalid bps: {} (max 10000)", bps);
+        return Err(ListingError::InvalidAmount.into());
+    }
+    Ok(())
 }
 
-/// 验证 Pyth Oracle 账户
-/// 
-/// 验证流程:
-/// 1. 验证账户 owner 是 Pyth Program ID
-/// 2. 验证 Price Feed 可解析
-/// 3. 验证价格有效性 (价格 > 0, 置信区间合理, 时间戳新鲜)
-pub fn validate_pyth_oracle(
-    oracle_account: &AccountInfo,
-    current_timestamp: i64,
-) -> ProgramResult {
-    // 1. 验证账户 owner 是 Pyth Program
-    let pyth_mainnet = PYTH_MAINNET_PROGRAM_ID.parse::<Pubkey>()
-        .map_err(|_| ListingError::InvalidOracle)?;
-    let pyth_devnet = PYTH_DEVNET_PROGRAM_ID.parse::<Pubkey>()
-        .map_err(|_| ListingError::InvalidOracle)?;
-    
-    if oracle_account.owner != &pyth_mainnet && oracle_account.owner != &pyth_devnet {
-        msg!("Oracle account owner is not Pyth Program");
-        msg!("Expected: {} or {}", PYTH_MAINNET_PROGRAM_ID, PYTH_DEVNET_PROGRAM_ID);
-        msg!("Got: {}", oracle_account.owner);
-        return Err(ListingError::InvalidOracle.into());
-    }
-
-    // 2. 解析 Price Feed
-    let price_account = SolanaPriceAccount::account_info_to_feed(oracle_account)
-        .map_err(|e| {
-            msg!("Failed to parse Pyth price account: {:?}", e);
-            ListingError::InvalidOracle
-        })?;
-
-    // 3. 获取价格
-    let price = price_account.get_price_no_older_than(
-        current_timestamp,
-        ORACLE_MAX_STALENESS_SECONDS as u64,
-    );
+/// 计算 Treasury 可提取的盈余
+/// 盈余 = 余额 - 未结算质押 - 租金豁免最低余额
+pub fn calculate_treasury_surplus(
+    treasury_lamports: u64,
+    total_staked_lamports: u64,
+    rent_minimum: u64,
+) -> u64 {
+    treasury_lamports
+        .saturating_sub(total_staked_lamports)
+        .saturating_sub(rent_minimum)
+}
 
-    match price {
-        Some(p) => {
-            // 验证价格 > 0
-            if p.price <= 0 {
-                msg!("Oracle price is non-positive: {}", p.price);
-                return Err(ListingError::InvalidOracle.into());
-            }
+/// 计算 Treasury 偿付差额 = 余额 - 未结算质押 - 租金豁免最低余额
+/// 负数表示资不抵债
+pub fn calculate_treasury_delta(
+    treasury_lamports: u64,
+    total_staked_lamports: u64,
+    rent_minimum: u64,
+) -> i128 {
+    treasury_lamports as i128 - total_staked_lamports as i128 - rent_minimum as i128
+}
 
-            // 验证置信区间不能太大 (< 5% of price)
-            let max_conf = (p.price.unsigned_abs() as u64) * ORACLE_MAX_CONFIDENCE_RATIO as u64 / 100;
-            if p.conf > max_conf {
-                msg!("Oracle confidence interval too large: {} (max: {})", p.conf, max_conf);
-                return Err(ListingError::InvalidOracle.into());
+/// 验证 Treasury 提取接收方：须为 ListingConfig 中配置的 insurance_vault
+/// （owner 须为 fund_program）或 fee_recipient
+pub fn verify_treasury_destination(
+    config: &ListingConfig,
+    destination: TreasuryDestination,
+    recipient: &AccountInfo,
+) -> ProgramResult {
+    match destination {
+        TreasuryDestination::InsuranceFund => {
+            if config.insurance_vault == Pubkey::default()
+                || recipient.key != &config.insurance_vault
+            {
+                msg!("Destination is not the configured insurance vault");
+                return Err(ListingError::InvalidAccount.into());
             }
-
-            // 验证 exponent 在合理范围内
-            if p.expo < -18 || p.expo > 18 {
-                msg!("Oracle price exponent out of range: {}", p.expo);
-                return Err(ListingError::InvalidOracle.into());
+            if recipient.owner != &config.fund_program {
+                msg!("Insurance fund account must be owned by fund program");
+                return Err(ListingError::InvalidAccountOwner.into());
             }
-
-            msg!("Pyth Oracle validated successfully");
-            msg!("Price: {} x 10^{}", p.price, p.expo);
-            msg!("Confidence: {}", p.conf);
-
-            Ok(())
         }
-        None => {
-            msg!("Oracle price is stale or unavailable");
-            Err(ListingError::InvalidOracle.into())
+        TreasuryDestination::FeeRecipient => {
+            if config.fee_recipient == Pubkey::default()
+                || recipient.key != &config.fee_recipient
+            {
+                msg!("Destination is not the configured fee recipient");
+                return Err(ListingError::InvalidAccount.into());
+            }
         }
     }
+    Ok(())
 }
 
-/// 简化的 Oracle 存在性验证（不验证价格）
-/// 用于市场上架时仅验证 Oracle 账户有效
-pub fn validate_oracle_exists(
-    oracle_account: &AccountInfo,
+/// 验证 Treasury 偿付能力：余额必须覆盖未结算质押和租金豁免最低余额
+pub fn verify_treasury_solvency(
+    treasury_account: &AccountInfo,
+    config: &ListingConfig,
 ) -> ProgramResult {
-    // 验证账户 owner 是 Pyth Program
-    let pyth_mainnet = PYTH_MAINNET_PROGRAM_ID.parse::<Pubkey>()
-        .map_err(|_| ListingError::InvalidOracle)?;
-    let pyth_devnet = PYTH_DEVNET_PROGRAM_ID.parse::<Pubkey>()
-        .map_err(|_| ListingError::InvalidOracle)?;
-    
-    // 也允许 system_program owner（空账户，用于测试）
-    let system_program = solana_program::system_program::id();
-    
-    if oracle_account.owner != &pyth_mainnet 
-        && oracle_account.owner != &pyth_devnet 
-        && oracle_account.owner != &system_program {
-        msg!("Invalid Oracle account owner");
-        return Err(ListingError::InvalidOracle.into());
+    let rent_minimum = Rent::get()?.minimum_balance(treasury_account.data_len());
+    let delta = calculate_treasury_delta(
+        treasury_account.lamports(),
+        config.total_staked_lamports,
+        rent_minimum,
+    );
+    if delta < 0 {
+        msg!("Treasury shortfall: {} lamports", -delta);
+        return Err(ListingError::TreasuryInsolvent.into());
     }
+    Ok(())
+}
 
-    // 尝试解析验证格式
-    if oracle_account.owner != &system_program {
-        let _price_account = SolanaPriceAccount::account_info_to_feed(oracle_account)
-            .map_err(|e| {
-                msg!("Failed to parse Pyth price account: {:?}", e);
-                ListingError::InvalidOracle
-            })?;
-    }
+/// 按 bps 计算罚没金额
+pub fn calculate_slash_amount(stake_amount: u64, slash_bps: u16) -> u64 {
+    (stake_amount as u128 * slash_bps as u128 / BPS_DENOMINATOR as u128) as u64
+}
 
-    msg!("Oracle account verified");
+/// 验证罚没比例表：每项不超过 100%，拒绝原因数量不超过 MAX_SLASH_REASONS
+pub fn validate_slash_schedule(
+    cancel_slash_bps: u16,
+    vote_rejection_slash_bps: u16,
+    reason_slash_bps: &[u16],
+) -> ProgramResult {
+    if reason_slash_bps.is_empty() || reason_slash_bps.len() > MAX_SLASH_REASONS {
+        return Err(ListingError::InvalidSlashSchedule.into());
+    }
+    let max_bps = BPS_DENOMINATOR as u16;
+    if cancel_slash_bps > max_bps
+        || vote_rejection_slash_bps > max_bps
+        || reason_slash_bps.iter().any(|bps| *bps > max_bps)
+    {
+        return Err(ListingError::InvalidSlashSchedule.into());
+    }
     Ok(())
 }
 
-/// SPL Token 转账
-pub fn spl_token_transfer<'a>(
-    source: &AccountInfo<'a>,
-    destination: &AccountInfo<'a>,
-    authority: &AccountInfo<'a>,
-    token_program: &AccountInfo<'a>,
+/// 计算罚没金额中分给反对者的奖励总额
+/// 无人反对时奖励为 0，罚没全部留在 treasury
+pub fn calculate_objector_reward_pool(
+    slash_amount: u64,
+    total_objection_stake: u64,
+    reward_bps: u16,
+) -> u64 {
+    if total_objection_stake == 0 {
+        return 0;
+    }
+    (slash_amount as u128 * reward_bps as u128 / BPS_DENOMINATOR as u128) as u64
+}
+
+/// 计算反对者可取回的金额
+///
+/// - Rejected: 全额退还 + 按反对质押比例分得 reward_pool
+/// - Cancelled: 全额退还
+/// - Approved: 没收 forfeit_bps 后退还
+///
+/// 返回 (退还金额, 奖励金额)
+pub fn calculate_objection_payout(
+    status: ProposalStatus,
     amount: u64,
-    signer_seeds: Option<&[&[&[u8]]]>,
-) -> ProgramResult {
-    let ix = spl_token::instruction::transfer(
-        token_program.key,
-        source.key,
-        destination.key,
-        authority.key,
-        &[],
-        amount,
-    )?;
+    total_objection_stake: u64,
+    reward_pool: u64,
+    forfeit_bps: u16,
+) -> Result<(u64, u64), ProgramError> {
+    match status {
+        ProposalStatus::Pending | ProposalStatus::Appealed => {
+            Err(ListingError::ProposalNotResolved.into())
+        }
+        ProposalStatus::Cancelled => Ok((amount, 0)),
+        ProposalStatus::Rejected => {
+            if total_objection_stake == 0 {
+                return Ok((amount, 0));
+            }
+            let reward = reward_pool as u128 * amount as u128 / total_objection_stake as u128;
+            Ok((amount, reward as u64))
+        }
+        ProposalStatus::Approved => {
+            let forfeit = (amount as u128 * forfeit_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+            Ok((amount - forfeit, 0))
+        }
+    }
+}
 
-    let account_infos = &[
-        source.clone(),
-        destination.clone(),
-        authority.clone(),
-        token_program.clone(),
-    ];
+/// 提案是否存在争议（不能自动批准，需社区投票）
+/// - Token: 有任何反对
+/// - Spot/Perp: 反对质押超过提案质押的 50%
+pub fn is_proposal_contested(
+    proposal_type: ProposalType,
+    objection_count: u16,
+    objection_stake: u64,
+    stake_amount: u64,
+) -> bool {
+    match proposal_type {
+        ProposalType::Token => objection_count > 0,
+        ProposalType::SpotMarket | ProposalType::PerpMarket => {
+            objection_count > 0 && objection_stake > stake_amount / 2
+        }
+    }
+}
 
-    if let Some(seeds) = signer_seeds {
-        invoke_signed(&ix, account_infos, seeds)
+/// 争议提案的实际投票截止时间
+///
+/// 旧版提案未记录 voting_deadline（为 0），按 review_deadline + 当前投票期计算
+pub fn effective_voting_deadline(
+    review_deadline: i64,
+    voting_deadline: i64,
+    voting_period_seconds: u32,
+) -> i64 {
+    if voting_deadline == 0 {
+        review_deadline.saturating_add(voting_period_seconds as i64)
     } else {
-        invoke(&ix, account_infos)
+        voting_deadline
     }
 }
 
-/// 原生 N1024 (lamports) 转账
-/// 用于 PLP 质押
-pub fn transfer_native_lamports<'a>(
-    from: &AccountInfo<'a>,
+/// 争议提案投票是否已结束：审核期与投票期都必须已过
+pub fn is_voting_ended(
+    review_deadline: i64,
+    voting_deadline: i64,
+    voting_period_seconds: u32,
+    current_ts: i64,
+) -> bool {
+    current_ts > review_deadline
+        && current_ts > effective_voting_deadline(review_deadline, voting_deadline, voting_period_seconds)
+}
+
+/// 被拒绝提案是否仍可申诉
+///
+/// 仅 Reject* 拒绝（rejected_at != 0）可申诉；每个提案只能申诉一次，
+/// Proposer 取回被拒绝质押后视为放弃申诉
+pub fn is_appeal_window_open(
+    status: ProposalStatus,
+    rejected_at: i64,
+    appeal_bond: u64,
+    stake_claimed: bool,
+    appeal_window_seconds: u32,
+    current_ts: i64,
+) -> bool {
+    status == ProposalStatus::Rejected
+        && rejected_at != 0
+        && appeal_bond == 0
+        && !stake_claimed
+        && current_ts <= rejected_at + appeal_window_seconds as i64
+}
+
+/// 申诉是否已逾期未裁决
+///
+/// 裁决期限为拒绝时间 + 申诉窗口 + `ListingConfig::APPEAL_RESOLUTION_PERIOD`，
+/// 逾期后 Admin 不能再裁决，Proposer 可取回剩余质押和申诉保证金
+pub fn is_appeal_expired(
+    status: ProposalStatus,
+    rejected_at: i64,
+    appeal_window_seconds: u32,
+    current_ts: i64,
+) -> bool {
+    status == ProposalStatus::Appealed
+        && current_ts
+            > rejected_at
+                .saturating_add(appeal_window_seconds as i64)
+                .saturating_add(ListingConfig::APPEAL_RESOLUTION_PERIOD)
+}
+
+/// 投票结果：支持票严格多于反对票才批准（平票或无人投票则拒绝）
+pub fn is_vote_approved(votes_for: u64, votes_against: u64) -> bool {
+    votes_for > votes_against
+}
+
+/// 将 Oracle 价格 (price x 10^expo) 换算为 e6 定点数，非正或溢出时返回 None
+pub fn oracle_price_to_e6(price: i64, expo: i32) -> Option<u64> {
+    if price <= 0 {
+        return None;
+    }
+    let shift = expo.checked_add(6)?;
+    let value = if shift >= 0 {
+        (price as u128).checked_mul(10u128.checked_pow(shift as u32)?)?
+    } else {
+        (price as u128) / 10u128.checked_pow(shift.unsigned_abs())?
+    };
+    u64::try_from(value).ok()
+}
+
+/// 验证价格区间 (e6) 包含 Oracle 参考价 (reference_price x 10^reference_expo)
+///
+/// 参考价上线前的市场记录为 0，不检查；参考价无法换算为 e6 时拒绝
+pub fn verify_price_range_contains_reference(
+    price_lower_e6: u64,
+    price_upper_e6: u64,
+    reference_price: i64,
+    reference_expo: i32,
+) -> ProgramResult {
+    if reference_price == 0 {
+        return Ok(());
+    }
+    let reference_e6 = oracle_price_to_e6(reference_price, reference_expo)
+        .ok_or(ListingError::InvalidPriceRange)?;
+    if reference_e6 < price_lower_e6 || reference_e6 > price_upper_e6 {
+        msg!("Price range must contain the market reference price: {} (e6)", reference_e6);
+        return Err(ListingError::InvalidPriceRange.into());
+    }
+    Ok(())
+}
+
+/// 永续市场上线使用的 Oracle 风险配置：取基础 Token Registry 的配置，
+/// 备用 Oracle 与主 Oracle 相同时视为未设置
+pub fn perp_listing_oracle_config(registry_data: &[u8], primary_oracle: &Pubkey) -> OracleRiskConfig {
+    let mut oracle_config = OracleRiskConfig::load(registry_data);
+    if oracle_config.fallback_oracle == *primary_oracle {
+        oracle_config.fallback_oracle = Pubkey::default();
+        oracle_config.fallback_source = OracleSource::default();
+    }
+    oracle_config
+}
+
+/// 获取当前时间戳
+pub fn get_current_timestamp() -> Result<i64, ProgramError> {
+    let clock = Clock::get()?;
+    Ok(clock.unix_timestamp)
+}
+
+/// 解析后的 Mint 信息（SPL Token 或 Token-2022）
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedMint {
+    /// 增发/冻结权限
+    pub authorities: MintAuthorities,
+    /// 资产类型（SplMint / Token2022Mint）
+    pub asset_kind: AssetKind,
+    /// Token-2022 扩展风险标记 (TOKEN_RISK_*)
+    pub risk_flags: u8,
+}
+
+/// 验证 Mint 账户（SPL Token 或 Token-2022）并校验精度，
+/// Token-2022 Mint 会解析扩展并计算风险标记
+pub fn verify_spl_mint(
+    mint_account: &AccountInfo,
+    decimals: u8,
+) -> Result<VerifiedMint, ProgramError> {
+    let data = mint_account.data.borrow();
+    let (mint_authority, freeze_authority, mint_decimals, asset_kind, risk_flags) =
+        if mint_account.owner == &spl_token::id() {
+            let mint = spl_token::state::Mint::unpack(&data)
+                .map_err(|_| ListingError::InvalidMint)?;
+            (mint.mint_authority, mint.freeze_authority, mint.decimals, AssetKind::SplMint, 0)
+        } else if mint_account.owner == &spl_token_2022::id() {
+            let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)
+                .map_err(|_| ListingError::InvalidMint)?;
+            let extensions = state.get_extension_types()
+                .map_err(|_| ListingError::InvalidMint)?;
+            let default_frozen = state.get_extension::<DefaultAccountState>()
+                .map(|ext| ext.state == spl_token_2022::state::AccountState::Frozen as u8)
+                .unwrap_or(false);
+            let mint = state.base;
+            (
+                mint.mint_authority,
+                mint.freeze_authority,
+                mint.decimals,
+                AssetKind::Token2022Mint,
+                token_2022_risk_flags(&extensions, default_frozen),
+            )
+        } else {
+            msg!("Mint not owned by SPL Token or Token-2022 program");
+            return Err(ListingError::InvalidAccountOwner.into());
+        };
+    if mint_decimals != decimals {
+        msg!("Decimals mismatch: mint {} != {}", mint_decimals, decimals);
+        return Err(ListingError::DecimalsMismatch.into());
+    }
+    Ok(VerifiedMint {
+        authorities: MintAuthorities {
+            mint_authority: mint_authority.into(),
+            freeze_authority: freeze_authority.into(),
+        },
+        asset_kind,
+        risk_flags,
+    })
+}
+
+/// 根据 Token-2022 Mint 扩展计算风险标记（扩展存在即标记，不看其当前参数，
+/// 因为权限方随时可修改；DefaultAccountState 仅在默认冻结时标记）
+pub fn token_2022_risk_flags(extensions: &[ExtensionType], default_frozen: bool) -> u8 {
+    extensions.iter().fold(0u8, |flags, extension| {
+        flags | match extension {
+            ExtensionType::TransferFeeConfig => TOKEN_RISK_TRANSFER_FEE,
+            ExtensionType::PermanentDelegate => TOKEN_RISK_PERMANENT_DELEGATE,
+            ExtensionType::TransferHook => TOKEN_RISK_TRANSFER_HOOK,
+            ExtensionType::NonTransferable => TOKEN_RISK_NON_TRANSFERABLE,
+            ExtensionType::DefaultAccountState if default_frozen => TOKEN_RISK_DEFAULT_FROZEN,
+            _ => 0,
+        }
+    })
+}
+
+/// 验证风险标记允许提交上架申请
+pub fn validate_token_risk_flags(risk_flags: u8) -> ProgramResult {
+    if risk_flags & TOKEN_RISK_REJECTED != 0 {
+        msg!("Unsupported Token-2022 extension, risk flags: {:#04x}", risk_flags);
+        return Err(ListingError::UnsupportedMintExtension.into());
+    }
+    Ok(())
+}
+
+/// 检查 Token 是否必须由 Reviewer 批准（不可超时自动批准或投票上架）
+pub fn requires_token_review(risk_flags: u8) -> bool {
+    risk_flags & TOKEN_RISK_REVIEW_REQUIRED != 0
+}
+
+/// SPL Token 转账
+pub fn spl_token_transfer<'a>(
+    source: &AccountInfo<'a>,
+    destination: &AccountInfo<'a>,
+    authority: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    amount: u64,
+    signer_seeds: Option<&[&[&[u8]]]>,
+) -> ProgramResult {
+    let ix = spl_token::instruction::transfer(
+        token_program.key,
+        source.key,
+        destination.key,
+        authority.key,
+        &[],
+        amount,
+    )?;
+
+    let account_infos = &[
+        source.clone(),
+        destination.clone(),
+        authority.clone(),
+        token_program.clone(),
+    ];
+
+    if let Some(seeds) = signer_seeds {
+        invoke_signed(&ix, account_infos, seeds)
+    } else {
+        invoke(&ix, account_infos)
+    }
+}
+
+/// 原生 N1024 (lamports) 转账
+/// 用于 PLP 质押
+pub fn transfer_native_lamports<'a>(
+    from: &AccountInfo<'a>,
     to: &AccountInfo<'a>,
     amount: u64,
     system_program: &AccountInfo<'a>,
@@ -475,11 +1219,369 @@ pub fn transfer_lamports_from_pda<'a>(
     amount: u64,
 ) -> ProgramResult {
     // 直接操作 lamports，不需要 system_program
-    **from_pda.try_borrow_mut_lamports()? -= amount;
-    **to.try_borrow_mut_lamports()? += amount;
+    let from_balance = from_pda.lamports()
+        .checked_sub(amount)
+        .ok_or(ListingError::InsufficientBalance)?;
+    let to_balance = to.lamports()
+        .checked_add(amount)
+        .ok_or(ListingError::Overflow)?;
+    **from_pda.try_borrow_mut_lamports()? = from_balance;
+    **to.try_borrow_mut_lamports()? = to_balance;
     Ok(())
 }
 
+// =============================================================================
+// 动态质押定价
+// =============================================================================
+
+/// 验证并派生 StakePricing PDA
+pub fn derive_stake_pricing_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STAKE_PRICING_SEED], program_id)
+}
+
+/// 加价按 decay_seconds 线性衰减
+pub fn calculate_decayed_surge(surge_bps: u32, elapsed_seconds: i64, decay_seconds: u32) -> u32 {
+    if decay_seconds == 0 || elapsed_seconds >= decay_seconds as i64 {
+        return 0;
+    }
+    let remaining = (decay_seconds as i64 - elapsed_seconds.max(0)) as u64;
+    (surge_bps as u64 * remaining / decay_seconds as u64) as u32
+}
+
+/// 当前加价 (bps)：待审提案加价与衰减后的历史加价取较大值
+pub fn calculate_stake_surge(pricing: &StakePricing, pending_proposals: u16, now: i64) -> u32 {
+    let excess_pending = pending_proposals.saturating_sub(pricing.free_pending) as u32;
+    let pending_surge = excess_pending * pricing.per_pending_bps as u32;
+    let decayed_surge = calculate_decayed_surge(
+        pricing.surge_bps,
+        now.saturating_sub(pricing.surge_updated_at),
+        pricing.decay_seconds,
+    );
+    pending_surge.max(decayed_surge)
+}
+
+/// 加价后的质押金额，倍数不超过 max_multiplier_bps
+pub fn calculate_dynamic_stake(
+    base_stake: u64,
+    surge_bps: u32,
+    max_multiplier_bps: u32,
+) -> Result<u64, ProgramError> {
+    let multiplier = (BPS_DENOMINATOR + surge_bps as u64).min(max_multiplier_bps as u64);
+    let stake = base_stake as u128 * multiplier as u128 / BPS_DENOMINATOR as u128;
+    u64::try_from(stake).map_err(|_| ListingError::Overflow.into())
+}
+
+/// 验证动态定价参数（倍数上限不低于 1x，衰减时间非零）
+pub fn validate_stake_pricing(max_multiplier_bps: u32, decay_seconds: u32) -> ProgramResult {
+    if max_multiplier_bps < BPS_DENOMINATOR as u32 || decay_seconds == 0 {
+        return Err(ListingError::InvalidStakePricing.into());
+    }
+    Ok(())
+}
+
+// =============================================================================
+// 质押托管
+// =============================================================================
+
+/// 托管余额 = 提案账户余额 - 租金豁免最低余额
+pub fn calculate_escrow_balance(lamports: u64, rent_minimum: u64) -> u64 {
+    lamports.saturating_sub(rent_minimum)
+}
+
+/// 提案是否由自身账户托管质押
+pub fn is_escrowed_proposal(version: u8) -> bool {
+    version >= ESCROW_PROPOSAL_VERSION
+}
+
+/// 从提案托管中转出，托管余额不足时失败（不会动用租金或其他提案的资金）
+fn transfer_from_escrow<'a>(
+    proposal_account: &AccountInfo<'a>,
+    to: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+    let rent_minimum = Rent::get()?.minimum_balance(proposal_account.data_len());
+    let escrow_balance = calculate_escrow_balance(proposal_account.lamports(), rent_minimum);
+    if amount > escrow_balance {
+        msg!("Escrow balance insufficient: {} > {}", amount, escrow_balance);
+        return Err(ListingError::InsufficientBalance.into());
+    }
+    transfer_lamports_from_pda(proposal_account, to, amount)
+}
+
+/// SPL 质押账户（提案 stake_mode = Spl 时由指令尾部传入）
+pub struct SplStakeAccounts<'r, 'a> {
+    pub token_program: &'r AccountInfo<'a>,
+    /// Stake Authority PDA（托管和 Treasury 质押 token 账户的 authority）
+    pub stake_authority: &'r AccountInfo<'a>,
+    pub stake_authority_bump: u8,
+    /// 提案质押托管 token 账户
+    pub escrow_token: &'r AccountInfo<'a>,
+    /// Treasury 质押 token 账户（接收罚没）
+    pub treasury_token: &'r AccountInfo<'a>,
+    /// 存入方/接收方的质押 token 账户（仅罚没的指令不需要）
+    pub user_token: Option<&'r AccountInfo<'a>>,
+}
+
+/// 提案质押的托管位置
+pub enum StakeVault<'r, 'a> {
+    /// 旧版提案：原生 N1024 托管在 Treasury，计入 total_staked_lamports
+    Treasury {
+        treasury: &'r AccountInfo<'a>,
+    },
+    /// 原生 N1024 托管在提案账户自身
+    Proposal {
+        proposal: &'r AccountInfo<'a>,
+        treasury: &'r AccountInfo<'a>,
+    },
+    /// SPL 质押代币托管在提案专属 token 账户
+    Token(SplStakeAccounts<'r, 'a>),
+}
+
+/// 验证并派生 Stake Authority PDA
+pub fn derive_stake_authority_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STAKE_AUTHORITY_SEED], program_id)
+}
+
+/// 验证并派生提案质押托管 token 账户 PDA
+pub fn derive_stake_escrow_pda(proposal: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STAKE_ESCROW_SEED, proposal.as_ref()], program_id)
+}
+
+/// 验证并派生 Treasury 质押 token 账户 PDA
+pub fn derive_treasury_stake_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TREASURY_STAKE_SEED], program_id)
+}
+
+/// 解析 SPL token 账户
+pub fn unpack_token_account(account: &AccountInfo) -> Result<spl_token::state::Account, ProgramError> {
+    if account.owner != &spl_token::id() {
+        return Err(ListingError::InvalidAccountOwner.into());
+    }
+    spl_token::state::Account::unpack(&account.data.borrow())
+}
+
+/// 创建由 Stake Authority 控制的质押 token 账户（PDA）
+pub fn create_stake_token_account<'a>(
+    payer: &AccountInfo<'a>,
+    token_account: &AccountInfo<'a>,
+    stake_mint: &AccountInfo<'a>,
+    stake_authority: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    seeds: &[&[u8]],
+) -> ProgramResult {
+    create_account(
+        payer,
+        token_account,
+        spl_token::state::Account::LEN,
+        token_program.key,
+        system_program,
+        seeds,
+    )?;
+    invoke(
+        &spl_token::instruction::initialize_account3(
+            token_program.key,
+            token_account.key,
+            stake_mint.key,
+            stake_authority.key,
+        )?,
+        &[token_account.clone(), stake_mint.clone()],
+    )
+}
+
+/// 按提案版本和质押模式确定托管位置
+///
+/// SPL 提案从 `account_iter` 依次读取：Token Program、Stake Authority PDA、
+/// 提案托管 token 账户、Treasury 质押 token 账户，
+/// `with_user_token` 时再读取存入方/接收方的质押 token 账户。
+pub fn load_stake_vault<'r, 'a, I>(
+    program_id: &Pubkey,
+    proposal_account: &'r AccountInfo<'a>,
+    treasury_account: &'r AccountInfo<'a>,
+    version: u8,
+    stake_mode: StakeMode,
+    account_iter: &mut I,
+    with_user_token: bool,
+) -> Result<StakeVault<'r, 'a>, ProgramError>
+where
+    I: Iterator<Item = &'r AccountInfo<'a>>,
+{
+    if stake_mode == StakeMode::Native {
+        if is_escrowed_proposal(version) {
+            return Ok(StakeVault::Proposal {
+                proposal: proposal_account,
+                treasury: treasury_account,
+            });
+        }
+        return Ok(StakeVault::Treasury { treasury: treasury_account });
+    }
+
+    let token_program = next_account_info(account_iter)?;
+    let stake_authority = next_account_info(account_iter)?;
+    let escrow_token = next_account_info(account_iter)?;
+    let treasury_token = next_account_info(account_iter)?;
+    let user_token = if with_user_token {
+        Some(next_account_info(account_iter)?)
+    } else {
+        None
+    };
+
+    if token_program.key != &spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let (expected_authority, stake_authority_bump) = derive_stake_authority_pda(program_id);
+    if stake_authority.key != &expected_authority {
+        return Err(ListingError::InvalidPda.into());
+    }
+    let (expected_escrow, _) = derive_stake_escrow_pda(proposal_account.key, program_id);
+    if escrow_token.key != &expected_escrow {
+        return Err(ListingError::InvalidPda.into());
+    }
+    let (expected_treasury_token, _) = derive_treasury_stake_pda(program_id);
+    if treasury_token.key != &expected_treasury_token {
+        return Err(ListingError::InvalidPda.into());
+    }
+
+    Ok(StakeVault::Token(SplStakeAccounts {
+        token_program,
+        stake_authority,
+        stake_authority_bump,
+        escrow_token,
+        treasury_token,
+        user_token,
+    }))
+}
+
+impl<'r, 'a> SplStakeAccounts<'r, 'a> {
+    fn user_token(&self) -> Result<&'r AccountInfo<'a>, ProgramError> {
+        self.user_token.ok_or_else(|| ListingError::InvalidAccount.into())
+    }
+
+    /// 由 Stake Authority PDA 签名转出
+    fn transfer_signed(
+        &self,
+        source: &AccountInfo<'a>,
+        destination: &AccountInfo<'a>,
+        amount: u64,
+    ) -> ProgramResult {
+        spl_token_transfer(
+            source,
+            destination,
+            self.stake_authority,
+            self.token_program,
+            amount,
+            Some(&[&[STAKE_AUTHORITY_SEED, &[self.stake_authority_bump]]]),
+        )
+    }
+}
+
+/// 存入质押（`from` 为存入方钱包，SPL 模式下作为 token 转账 authority）
+pub fn deposit_stake<'a>(
+    config: &mut ListingConfig,
+    vault: &StakeVault<'_, 'a>,
+    from: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+    match vault {
+        StakeVault::Treasury { treasury } => {
+            transfer_native_lamports(from, treasury, amount, system_program, None)?;
+            config.total_staked_lamports = config.total_staked_lamports
+                .checked_add(amount)
+                .ok_or(ListingError::Overflow)?;
+            verify_treasury_solvency(treasury, config)
+        }
+        StakeVault::Proposal { proposal, .. } => {
+            transfer_native_lamports(from, proposal, amount, system_program, None)
+        }
+        StakeVault::Token(spl) => {
+            spl_token_transfer(
+                spl.user_token()?,
+                spl.escrow_token,
+                from,
+                spl.token_program,
+                amount,
+                None,
+            )
+        }
+    }
+}
+
+/// 从托管中退还质押（`to` 为接收方钱包，SPL 模式下须为接收 token 账户的 owner）
+pub fn release_stake<'a>(
+    config: &mut ListingConfig,
+    vault: &StakeVault<'_, 'a>,
+    to: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+    match vault {
+        StakeVault::Treasury { treasury } => {
+            transfer_lamports_from_pda(treasury, to, amount)?;
+            config.total_staked_lamports = config.total_staked_lamports
+                .checked_sub(amount)
+                .ok_or(ListingError::Underflow)?;
+            verify_treasury_solvency(treasury, config)
+        }
+        StakeVault::Proposal { proposal, .. } => {
+            transfer_from_escrow(proposal, to, amount)
+        }
+        StakeVault::Token(spl) => {
+            let user_token = spl.user_token()?;
+            if unpack_token_account(user_token)?.owner != *to.key {
+                msg!("Stake token account not owned by recipient");
+                return Err(ListingError::InvalidAccount.into());
+            }
+            spl.transfer_signed(spl.escrow_token, user_token, amount)
+        }
+    }
+}
+
+/// 罚没/没收质押：转入 Treasury 成为可提取盈余
+pub fn slash_stake(
+    config: &mut ListingConfig,
+    vault: &StakeVault,
+    amount: u64,
+) -> ProgramResult {
+    match vault {
+        StakeVault::Treasury { treasury } => {
+            config.total_staked_lamports = config.total_staked_lamports
+                .checked_sub(amount)
+                .ok_or(ListingError::Underflow)?;
+            verify_treasury_solvency(treasury, config)
+        }
+        StakeVault::Proposal { proposal, treasury } => {
+            transfer_from_escrow(proposal, treasury, amount)?;
+            verify_treasury_solvency(treasury, config)
+        }
+        StakeVault::Token(spl) => {
+            spl.transfer_signed(spl.escrow_token, spl.treasury_token, amount)
+        }
+    }
+}
+
+/// 撤销罚没（slash_stake 的逆操作），Treasury 盈余不足时失败
+pub fn restore_stake(
+    config: &mut ListingConfig,
+    vault: &StakeVault,
+    amount: u64,
+) -> ProgramResult {
+    match vault {
+        StakeVault::Treasury { treasury } => {
+            config.total_staked_lamports = config.total_staked_lamports
+                .checked_add(amount)
+                .ok_or(ListingError::Overflow)?;
+            verify_treasury_solvency(treasury, config)
+        }
+        StakeVault::Proposal { proposal, treasury } => {
+            transfer_lamports_from_pda(treasury, proposal, amount)?;
+            verify_treasury_solvency(treasury, config)
+        }
+        StakeVault::Token(spl) => {
+            spl.transfer_signed(spl.treasury_token, spl.escrow_token, amount)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -549,6 +1651,244 @@ mod tests {
         assert!(validate_leverage(101).is_err());
     }
 
+    #[test]
+    fn test_calculate_objection_payout() {
+        // Pending: not claimable
+        assert!(calculate_objection_payout(ProposalStatus::Pending, 100, 100, 0, 1_000).is_err());
+        assert!(calculate_objection_payout(ProposalStatus::Appealed, 100, 100, 0, 1_000).is_err());
+
+        // Cancelled: full refund
+        assert_eq!(
+            calculate_objection_payout(ProposalStatus::Cancelled, 100, 400, 0, 1_000).unwrap(),
+            (100, 0)
+        );
+
+        // Rejected: full refund + 1/4 of the 500 reward pool
+        assert_eq!(
+            calculate_objection_payout(ProposalStatus::Rejected, 100, 400, 500, 1_000).unwrap(),
+            (100, 125)
+        );
+
+        // Approved: 10% forfeited
+        assert_eq!(
+            calculate_objection_payout(ProposalStatus::Approved, 100, 400, 0, 1_000).unwrap(),
+            (90, 0)
+        );
+    }
+
+    #[test]
+    fn test_calculate_treasury_surplus() {
+        assert_eq!(calculate_treasury_surplus(1_000, 600, 100), 300);
+        // Locked stakes are never withdrawable
+        assert_eq!(calculate_treasury_surplus(1_000, 950, 100), 0);
+        assert_eq!(calculate_treasury_surplus(1_000, 1_200, 100), 0);
+    }
+
+    #[test]
+    fn test_calculate_treasury_delta() {
+        assert_eq!(calculate_treasury_delta(1_000, 600, 100), 300);
+        assert_eq!(calculate_treasury_delta(1_000, 1_000, 100), -100);
+        assert_eq!(calculate_treasury_delta(u64::MAX, 0, 0), u64::MAX as i128);
+    }
+
+    #[test]
+    fn test_calculate_escrow_balance() {
+        assert_eq!(calculate_escrow_balance(1_000, 100), 900);
+        // Rent is never part of the escrow
+        assert_eq!(calculate_escrow_balance(50, 100), 0);
+        assert!(!is_escrowed_proposal(1));
+        assert!(is_escrowed_proposal(ESCROW_PROPOSAL_VERSION));
+    }
+
+    #[test]
+    fn test_calculate_slash_amount() {
+        assert_eq!(calculate_slash_amount(1_000, 500), 50);
+        assert_eq!(calculate_slash_amount(1_000, 10_000), 1_000);
+        assert_eq!(calculate_slash_amount(u64::MAX, 10_000), u64::MAX);
+    }
+
+    #[test]
+    fn test_validate_slash_schedule() {
+        assert!(validate_slash_schedule(500, 1_000, &[1_000, 5_000, 10_000]).is_ok());
+        assert!(validate_slash_schedule(500, 1_000, &[]).is_err());
+        assert!(validate_slash_schedule(500, 1_000, &[0; MAX_SLASH_REASONS + 1]).is_err());
+        assert!(validate_slash_schedule(10_001, 1_000, &[1_000]).is_err());
+        assert!(validate_slash_schedule(500, 1_000, &[10_001]).is_err());
+    }
+
+    #[test]
+    fn test_calculate_objector_reward_pool() {
+        // 50% of slash goes to objectors
+        assert_eq!(calculate_objector_reward_pool(1_000, 400, 5_000), 500);
+
+        // No objectors: nothing reserved
+        assert_eq!(calculate_objector_reward_pool(1_000, 0, 5_000), 0);
+    }
+
+    #[test]
+    fn test_is_proposal_contested() {
+        // Token: any objection
+        assert!(is_proposal_contested(ProposalType::Token, 1, 1, 1_000));
+        assert!(!is_proposal_contested(ProposalType::Token, 0, 0, 1_000));
+
+        // Markets: objection stake must exceed half of proposal stake
+        assert!(!is_proposal_contested(ProposalType::PerpMarket, 3, 500, 1_000));
+        assert!(is_proposal_contested(ProposalType::SpotMarket, 3, 501, 1_000));
+    }
+
+    #[test]
+    fn test_is_vote_approved() {
+        assert!(is_vote_approved(2, 1));
+        assert!(!is_vote_approved(1, 1));
+        assert!(!is_vote_approved(0, 0));
+    }
+
+    #[test]
+    fn test_is_voting_ended() {
+        let review_deadline = 1_000;
+        assert_eq!(effective_voting_deadline(review_deadline, 1_500, 300), 1_500);
+        assert!(!is_voting_ended(review_deadline, 1_500, 300, 1_500));
+        assert!(is_voting_ended(review_deadline, 1_500, 300, 1_501));
+
+        // Contested legacy proposal without a recorded voting deadline:
+        // still inside the review period, then the voting period
+        assert!(is_proposal_contested(ProposalType::Token, 1, 1, 1_000));
+        assert_eq!(effective_voting_deadline(review_deadline, 0, 300), 1_300);
+        assert!(!is_voting_ended(review_deadline, 0, 300, 900));
+        assert!(!is_voting_ended(review_deadline, 0, 300, 1_300));
+        assert!(is_voting_ended(review_deadline, 0, 300, 1_301));
+
+        // Never resolvable before the review period ends
+        assert!(!is_voting_ended(review_deadline, 0, 0, 1_000));
+        assert!(is_voting_ended(review_deadline, 0, 0, 1_001));
+    }
+
+    #[test]
+    fn test_oracle_price_to_e6() {
+        // 65_000.00000000 (expo -8)
+        assert_eq!(oracle_price_to_e6(6_500_000_000_000, -8), Some(65_000_000_000));
+        assert_eq!(oracle_price_to_e6(185_250_000, -6), Some(185_250_000));
+        assert_eq!(oracle_price_to_e6(3, 2), Some(300_000_000));
+        // 精度低于 e6 的部分截断
+        assert_eq!(oracle_price_to_e6(1, -18), Some(0));
+        // 非正价格 / 溢出
+        assert_eq!(oracle_price_to_e6(0, -6), None);
+        assert_eq!(oracle_price_to_e6(i64::MAX, 18), None);
+    }
+
+    #[test]
+    fn test_verify_price_range_contains_reference() {
+        // 65_000.00000000 (expo -8) = 65_000_000_000 (e6)
+        assert!(verify_price_range_contains_reference(60_000_000_000, 70_000_000_000, 6_500_000_000_000, -8).is_ok());
+        // 边界包含在内
+        assert!(verify_price_range_contains_reference(65_000_000_000, 70_000_000_000, 6_500_000_000_000, -8).is_ok());
+        assert!(verify_price_range_contains_reference(60_000_000_000, 65_000_000_000, 6_500_000_000_000, -8).is_ok());
+        assert!(verify_price_range_contains_reference(66_000_000_000, 70_000_000_000, 6_500_000_000_000, -8).is_err());
+        assert!(verify_price_range_contains_reference(60_000_000_000, 64_000_000_000, 6_500_000_000_000, -8).is_err());
+
+        // 指数不同的同一价格换算结果一致：185.25 (expo -2 / -6 / -10)
+        for (price, expo) in [(18_525i64, -2), (185_250_000, -6), (1_852_500_000_000, -10)] {
+            assert!(verify_price_range_contains_reference(185_000_000, 186_000_000, price, expo).is_ok());
+            assert!(verify_price_range_contains_reference(186_000_000, 190_000_000, price, expo).is_err());
+        }
+        // 正指数：3 x 10^2 = 300
+        assert!(verify_price_range_contains_reference(299_000_000, 301_000_000, 3, 2).is_ok());
+        // 未按指数换算时（把 expo -8 的原始值当作 e6）会落在区间外
+        assert!(verify_price_range_contains_reference(6_000_000_000_000, 7_000_000_000_000, 6_500_000_000_000, -8).is_err());
+
+        // 参考价为 0 的旧市场不检查
+        assert!(verify_price_range_contains_reference(1, 2, 0, -8).is_ok());
+        assert!(verify_price_range_contains_reference(1, 2, 0, 0).is_ok());
+        // 负价格 / 无法换算的参考价
+        assert!(verify_price_range_contains_reference(1, u64::MAX, -5, -8).is_err());
+        assert!(verify_price_range_contains_reference(1, u64::MAX, i64::MAX, 18).is_err());
+    }
+
+    #[test]
+    fn test_perp_listing_oracle_config() {
+        let primary = Pubkey::new_unique();
+        let fallback = Pubkey::new_unique();
+        let stored = OracleRiskConfig {
+            max_staleness_seconds: 120,
+            max_confidence_bps: 300,
+            fallback_oracle: fallback,
+            fallback_source: OracleSource::Switchboard,
+        };
+        let mut registry_data = vec![0u8; TOKEN_REGISTRY_ACCOUNT_SIZE];
+        stored.store(&mut registry_data).unwrap();
+
+        // 使用基础 Token 配置的阈值和备用 Oracle
+        assert_eq!(perp_listing_oracle_config(&registry_data, &primary), stored);
+
+        // 备用 Oracle 与提案主 Oracle 相同时清除，阈值保留
+        let config = perp_listing_oracle_config(&registry_data, &fallback);
+        assert!(!config.has_fallback());
+        assert_eq!(config.fallback_source, OracleSource::default());
+        assert_eq!((config.max_staleness_seconds, config.max_confidence_bps), (120, 300));
+
+        // 旧 Registry 没有风险配置，使用默认值
+        let legacy = vec![0u8; TOKEN_REGISTRY_SIZE];
+        assert_eq!(perp_listing_oracle_config(&legacy, &primary), OracleRiskConfig::default());
+    }
+
+    #[test]
+    fn test_is_appeal_window_open() {
+        let rejected = ProposalStatus::Rejected;
+        assert!(is_appeal_window_open(rejected, 1_000, 0, false, 100, 1_100));
+        // Window closed
+        assert!(!is_appeal_window_open(rejected, 1_000, 0, false, 100, 1_101));
+        // Rejected by vote (no rejected_at)
+        assert!(!is_appeal_window_open(rejected, 0, 0, false, 100, 50));
+        // Already appealed once
+        assert!(!is_appeal_window_open(rejected, 1_000, 5, false, 100, 1_050));
+        // Stake already claimed
+        assert!(!is_appeal_window_open(rejected, 1_000, 0, true, 100, 1_050));
+        assert!(!is_appeal_window_open(ProposalStatus::Approved, 1_000, 0, false, 100, 1_050));
+    }
+
+    #[test]
+    fn test_is_appeal_expired() {
+        let appealed = ProposalStatus::Appealed;
+        let deadline = 1_000 + 100 + ListingConfig::APPEAL_RESOLUTION_PERIOD;
+        assert!(!is_appeal_expired(appealed, 1_000, 100, deadline));
+        assert!(is_appeal_expired(appealed, 1_000, 100, deadline + 1));
+        // 未申诉或已裁决
+        assert!(!is_appeal_expired(ProposalStatus::Rejected, 1_000, 100, deadline + 1));
+        assert!(!is_appeal_expired(ProposalStatus::Approved, 1_000, 100, deadline + 1));
+    }
+
+    #[test]
+    fn test_validate_council() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let c = Pubkey::new_unique();
+
+        // Valid: 2-of-3
+        assert!(validate_council(&[a, b, c], 2).is_ok());
+
+        // Invalid: threshold out of range
+        assert!(validate_council(&[a, b, c], 0).is_err());
+        assert!(validate_council(&[a, b, c], 4).is_err());
+
+        // Invalid: empty, duplicate or default member
+        assert!(validate_council(&[], 1).is_err());
+        assert!(validate_council(&[a, a], 1).is_err());
+        assert!(validate_council(&[a, Pubkey::default()], 1).is_err());
+    }
+
+    #[test]
+    fn test_calculate_action_hash() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+
+        let hash = calculate_action_hash(&[1, 2, 3], [a, b].iter());
+        assert_eq!(hash, calculate_action_hash(&[1, 2, 3], [a, b].iter()));
+
+        // Different data or account order must not match
+        assert_ne!(hash, calculate_action_hash(&[1, 2, 4], [a, b].iter()));
+        assert_ne!(hash, calculate_action_hash(&[1, 2, 3], [b, a].iter()));
+    }
+
     #[test]
     fn test_validate_margin_rates() {
         // Valid: 10% initial, 5% maintenance
@@ -558,5 +1898,438 @@ mod tests {
         assert!(validate_margin_rates(100_000, 100_000).is_err());
         assert!(validate_margin_rates(100_000, 150_000).is_err());
     }
-}
 
+    fn test_perp_market(approved_at: i64, is_active: bool) -> PerpMarket {
+        PerpMarket {
+            discriminator: PerpMarket::DISCRIMINATOR,
+            version: 1,
+            market_index: 0,
+            symbol: [0u8; 16],
+            base_token_index: 1,
+            quote_token_index: 0,
+            oracle: Pubkey::new_unique(),
+            tick_size_e6: 1,
+            lot_size_e6: 1,
+            max_leverage: 20,
+            initial_margin_rate_e6: 50_000,
+            maintenance_margin_rate_e6: 25_000,
+            taker_fee_bps: 5,
+            maker_fee_bps: 2,
+            min_order_size_e6: 1,
+            max_order_size_e6: 1_000_000,
+            max_open_interest_e6: 1_000_000_000,
+            current_open_interest_long_e6: 0,
+            current_open_interest_short_e6: 0,
+            insurance_fund_deposit_e6: 0,
+            funding_rate_e9: 0,
+            last_funding_ts: 0,
+            is_active,
+            is_paused: false,
+            proposer: Pubkey::new_unique(),
+            approved_at,
+            bump: 255,
+            oracle_source: OracleSource::default(),
+            oracle_config: OracleRiskConfig::default(),
+            reference_price: 0,
+            reference_expo: 0,
+            reserved: [0u8; 12],
+        }
+    }
+
+    #[test]
+    fn test_perp_param_change_timelock() {
+        let live = test_perp_market(1_000, true);
+        assert!(is_perp_param_change_timelocked(&live, Some(10), None, None, None));
+        assert!(is_perp_param_change_timelocked(&live, None, Some(60_000), None, None));
+        assert!(is_perp_param_change_timelocked(&live, None, None, Some(20_000), None));
+        assert!(is_perp_param_change_timelocked(&live, None, None, None, Some(2_000_000_000)));
+        // 下调或维持 OI 上限不受时间锁限制
+        assert!(!is_perp_param_change_timelocked(&live, None, None, None, Some(500_000_000)));
+        assert!(!is_perp_param_change_timelocked(&live, None, None, None, Some(1_000_000_000)));
+        assert!(!is_perp_param_change_timelocked(&live, None, None, None, None));
+
+        // 停用后的已上线市场同样受时间锁限制
+        let deactivated = test_perp_market(1_000, false);
+        assert!(is_perp_param_change_timelocked(&deactivated, Some(50), None, None, None));
+        assert!(!is_perp_param_change_timelocked(&deactivated, None, None, None, Some(1)));
+
+        let unlisted = test_perp_market(0, false);
+        assert!(!is_perp_param_change_timelocked(&unlisted, Some(50), Some(60_000), None, None));
+    }
+
+    #[test]
+    fn test_apply_perp_risk_params() {
+        let mut market = test_perp_market(1_000, true);
+        apply_perp_risk_params(&mut market, Some(10), Some(100_000), Some(50_000), Some(1)).unwrap();
+        assert_eq!(market.max_leverage, 10);
+        assert_eq!(market.initial_margin_rate_e6, 100_000);
+        assert_eq!(market.maintenance_margin_rate_e6, 50_000);
+        assert_eq!(market.max_open_interest_e6, 1);
+
+        // 单独下调初始保证金率至维持保证金率以下
+        let mut market = test_perp_market(1_000, true);
+        assert!(apply_perp_risk_params(&mut market, None, Some(25_000), None, None).is_err());
+        assert!(apply_perp_risk_params(&mut market, None, Some(20_000), None, None).is_err());
+        // 单独上调维持保证金率至初始保证金率以上
+        assert!(apply_perp_risk_params(&mut market, None, None, Some(50_000), None).is_err());
+        // 同时调整时按最终组合校验
+        apply_perp_risk_params(&mut market, None, Some(20_000), Some(10_000), None).unwrap();
+        assert_eq!(market.initial_margin_rate_e6, 20_000);
+        assert_eq!(market.maintenance_margin_rate_e6, 10_000);
+
+        // 校验失败时不写入任何字段
+        let mut market = test_perp_market(1_000, true);
+        assert!(apply_perp_risk_params(&mut market, Some(10), Some(10_000), None, None).is_err());
+        assert_eq!(market.max_leverage, 20);
+        assert!(apply_perp_risk_params(&mut market, Some(0), None, None, None).is_err());
+        assert!(apply_perp_risk_params(&mut market, Some(101), None, None, None).is_err());
+    }
+
+    #[test]
+    fn test_apply_scheduled_param_change() {
+        let mut market = test_perp_market(1_000, true);
+        let mut change = ScheduledParamChange {
+            discriminator: ScheduledParamChange::DISCRIMINATOR,
+            version: 1,
+            market: Pubkey::new_unique(),
+            market_index: 0,
+            max_leverage: Some(10),
+            initial_margin_rate_e6: Some(100_000),
+            maintenance_margin_rate_e6: None,
+            max_open_interest_e6: Some(2_000_000_000),
+            scheduled_by: Pubkey::new_unique(),
+            scheduled_at: 1_000,
+            effective_at: 87_400,
+            is_pending: true,
+            bump: 255,
+            reserved: [0u8; 64],
+        };
+
+        // 时间锁未到
+        assert!(apply_scheduled_param_change(&mut market, &mut change, 87_399).is_err());
+        assert!(change.is_pending);
+        assert_eq!(market.max_leverage, 20);
+
+        apply_scheduled_param_change(&mut market, &mut change, 87_400).unwrap();
+        assert!(!change.is_pending);
+        assert_eq!(market.max_leverage, 10);
+        assert_eq!(market.initial_margin_rate_e6, 100_000);
+        assert_eq!(market.maintenance_margin_rate_e6, 25_000);
+        assert_eq!(market.max_open_interest_e6, 2_000_000_000);
+
+        // 已执行的变更不能重复执行
+        assert!(apply_scheduled_param_change(&mut market, &mut change, 90_000).is_err());
+
+        // 计划后维持保证金率被改高时，按执行时的市场参数重新校验
+        let mut market = test_perp_market(1_000, true);
+        market.maintenance_margin_rate_e6 = 40_000;
+        change.is_pending = true;
+        change.initial_margin_rate_e6 = Some(30_000);
+        assert!(apply_scheduled_param_change(&mut market, &mut change, 90_000).is_err());
+        assert!(change.is_pending);
+    }
+
+    #[test]
+    fn test_dynamic_stake_pricing() {
+        let mut pricing = StakePricing::default_pricing(255);
+        let base = 1_000_000_000_000u64;
+
+        // 未超出 free_pending 不加价
+        assert_eq!(calculate_stake_surge(&pricing, 10, 0), 0);
+        assert_eq!(calculate_dynamic_stake(base, 0, pricing.max_multiplier_bps).unwrap(), base);
+
+        // 每个超出的待审提案加价 5%
+        assert_eq!(calculate_stake_surge(&pricing, 14, 0), 2_000);
+        assert_eq!(calculate_dynamic_stake(base, 2_000, pricing.max_multiplier_bps).unwrap(), base * 12 / 10);
+
+        // 倍数上限 5x
+        assert_eq!(calculate_dynamic_stake(base, 100_000, pricing.max_multiplier_bps).unwrap(), base * 5);
+
+        // 待审提案减少后，历史加价线性衰减
+        pricing.surge_bps = 2_000;
+        pricing.surge_updated_at = 0;
+        let half = pricing.decay_seconds as i64 / 2;
+        assert_eq!(calculate_stake_surge(&pricing, 0, half), 1_000);
+        assert_eq!(calculate_stake_surge(&pricing, 0, pricing.decay_seconds as i64), 0);
+
+        assert!(validate_stake_pricing(9_999, 1).is_err());
+        assert!(validate_stake_pricing(10_000, 0).is_err());
+        assert!(validate_stake_pricing(10_000, 1).is_ok());
+    }
+
+    #[test]
+    fn test_token_2022_risk_flags() {
+        // 元数据等扩展不计风险
+        let benign = [ExtensionType::MetadataPointer, ExtensionType::TokenMetadata];
+        assert_eq!(token_2022_risk_flags(&benign, false), 0);
+
+        // DefaultAccountState 仅在默认冻结时标记
+        let default_state = [ExtensionType::DefaultAccountState];
+        assert_eq!(token_2022_risk_flags(&default_state, false), 0);
+        assert_eq!(token_2022_risk_flags(&default_state, true), TOKEN_RISK_DEFAULT_FROZEN);
+
+        // 转账手续费仅记录，不强制人工审核
+        let fee = token_2022_risk_flags(&[ExtensionType::TransferFeeConfig], false);
+        assert_eq!(fee, TOKEN_RISK_TRANSFER_FEE);
+        assert!(validate_token_risk_flags(fee).is_ok());
+        assert!(!requires_token_review(fee));
+
+        // 永久委托人 / 转账钩子需 Reviewer 批准
+        let risky = token_2022_risk_flags(
+            &[ExtensionType::PermanentDelegate, ExtensionType::TransferHook],
+            false,
+        );
+        assert_eq!(risky, TOKEN_RISK_PERMANENT_DELEGATE | TOKEN_RISK_TRANSFER_HOOK);
+        assert!(validate_token_risk_flags(risky).is_ok());
+        assert!(requires_token_review(risky));
+
+        // 不可转让直接拒绝
+        let soulbound = token_2022_risk_flags(&[ExtensionType::NonTransferable], false);
+        assert!(validate_token_risk_flags(soulbound).is_err());
+    }
+
+    #[test]
+    fn test_symbol_index_seed() {
+        let program_id = Pubkey::new_unique();
+        let mut btc = [0u8; 8];
+        btc[..3].copy_from_slice(b"BTC");
+        assert_eq!(symbol_seed(&btc), b"BTC");
+
+        // 尾部 0 之后的字节不影响索引地址
+        let mut padded = btc;
+        padded[5] = b'X';
+        assert_eq!(
+            derive_symbol_index_pda(&btc, &program_id),
+            derive_symbol_index_pda(&padded, &program_id),
+        );
+
+        let mut eth = [0u8; 8];
+        eth[..3].copy_from_slice(b"ETH");
+        assert_ne!(
+            derive_symbol_index_pda(&btc, &program_id).0,
+            derive_symbol_index_pda(&eth, &program_id).0,
+        );
+        assert_eq!(symbol_seed(b"LONGNAME"), b"LONGNAME");
+    }
+
+    #[test]
+    fn test_pair_index_pda() {
+        let program_id = Pubkey::new_unique();
+        let spot = derive_pair_index_pda(MarketType::Spot, 1, 0, &program_id).0;
+
+        // 同一交易对在 Spot / Perp 下各自独立
+        assert_ne!(spot, derive_pair_index_pda(MarketType::Perp, 1, 0, &program_id).0);
+        // Base / Quote 有方向
+        assert_ne!(spot, derive_pair_index_pda(MarketType::Spot, 0, 1, &program_id).0);
+        assert_eq!(spot, derive_pair_index_pda(MarketType::Spot, 1, 0, &program_id).0);
+    }
+
+    #[test]
+    fn test_validate_token_metadata() {
+        let mut content = TokenMetadataContent::default();
+        // 名称不能为空
+        assert!(validate_token_metadata(&content).is_err());
+
+        content.name[..7].copy_from_slice(b"Bitcoin");
+        assert!(validate_token_metadata(&content).is_ok());
+
+        // 填充后不能再出现内容
+        content.uri[5] = b'x';
+        assert!(validate_token_metadata(&content).is_err());
+        content.uri[5] = 0;
+
+        // 非 UTF-8
+        content.name[7] = 0xFF;
+        assert!(validate_token_metadata(&content).is_err());
+        content.name[..9].copy_from_slice("Société".as_bytes());
+        assert!(validate_token_metadata(&content).is_ok());
+    }
+
+    #[test]
+    fn test_verify_pool_authority() {
+        use borsh::BorshSerialize;
+
+        let program_id = Pubkey::new_unique();
+        let (council_key, council_bump) = derive_admin_council_pda(&program_id);
+        let members = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut council = AdminCouncil::try_from_slice(&[0u8; ADMIN_COUNCIL_SIZE]).unwrap();
+        council.discriminator = AdminCouncil::DISCRIMINATOR;
+        council.members[..3].copy_from_slice(&members);
+        council.member_count = 3;
+        council.threshold = 2;
+        council.bump = council_bump;
+        let council_data = council.try_to_vec().unwrap();
+
+        let mut config = ListingConfig::try_from_slice(&[0u8; LISTING_CONFIG_SIZE]).unwrap();
+        config.admin = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+        let stranger = Pubkey::new_unique();
+
+        // (key, is_signer) 列表：第一个账户为调用者，其余为附加签名者
+        let check = |config: &ListingConfig, signers: &[(Pubkey, bool)]| {
+            let mut lamports = vec![0u64; signers.len()];
+            let mut data: Vec<Vec<u8>> = signers
+                .iter()
+                .map(|(key, _)| if key == &council_key { council_data.clone() } else { vec![] })
+                .collect();
+            let owner = program_id;
+            let accounts: Vec<AccountInfo> = signers
+                .iter()
+                .zip(lamports.iter_mut())
+                .zip(data.iter_mut())
+                .map(|(((key, is_signer), lamports), data)| {
+                    AccountInfo::new(key, *is_signer, false, lamports, data, &owner, false, 0)
+                })
+                .collect();
+            verify_pool_authority(&accounts[0], &creator, config, &accounts)
+        };
+
+        // 创建者须签名
+        assert!(check(&config, &[(creator, true)]).is_ok());
+        assert!(check(&config, &[(creator, false)]).is_err());
+
+        // PoolOperator 未分配时只有 Admin
+        assert!(check(&config, &[(operator, true)]).is_err());
+        assert!(check(&config, &[(config.admin, true)]).is_ok());
+        config.pool_operator = operator;
+        assert!(check(&config, &[(operator, true)]).is_ok());
+        assert!(check(&config, &[(operator, false)]).is_err());
+        assert!(check(&config, &[(stranger, true)]).is_err());
+
+        // Admin 为多签委员会 PDA 时，达到阈值的成员签名可代行 PoolOperator
+        config.admin = council_key;
+        assert!(check(&config, &[(council_key, false), (members[0], true), (members[2], true)]).is_ok());
+        assert!(check(&config, &[(council_key, false), (members[0], true)]).is_err());
+        assert!(check(&config, &[(council_key, false), (members[0], true), (stranger, true)]).is_err());
+    }
+
+    #[test]
+    fn test_verify_treasury_destination() {
+        let mut config = ListingConfig::try_from_slice(&[0u8; LISTING_CONFIG_SIZE]).unwrap();
+        config.fund_program = Pubkey::new_unique();
+
+        let check = |config: &ListingConfig, destination, key: &Pubkey, owner: &Pubkey| {
+            let mut data = [];
+            let mut lamports = 0u64;
+            let account = AccountInfo::new(key, false, true, &mut lamports, &mut data, owner, false, 0);
+            verify_treasury_destination(config, destination, &account)
+        };
+        let fund_account = Pubkey::new_unique();
+        let fund_program = config.fund_program;
+        // insurance_vault 未设置时不能提取
+        assert!(check(&config, TreasuryDestination::InsuranceFund, &fund_account, &fund_program).is_err());
+        config.insurance_vault = fund_account;
+        assert!(check(&config, TreasuryDestination::InsuranceFund, &fund_account, &fund_program).is_ok());
+        assert!(check(&config, TreasuryDestination::InsuranceFund, &fund_account, &Pubkey::new_unique()).is_err());
+        // fund_program 的其他账户
+        assert!(check(&config, TreasuryDestination::InsuranceFund, &Pubkey::new_unique(), &fund_program).is_err());
+
+        // fee_recipient 未设置时不能提取
+        let recipient = Pubkey::new_unique();
+        let system = solana_program::system_program::id();
+        assert!(check(&config, TreasuryDestination::FeeRecipient, &Pubkey::default(), &system).is_err());
+        config.fee_recipient = recipient;
+        assert!(check(&config, TreasuryDestination::FeeRecipient, &recipient, &system).is_ok());
+        assert!(check(&config, TreasuryDestination::FeeRecipient, &Pubkey::new_unique(), &system).is_err());
+    }
+
+    #[test]
+    fn test_load_listing_config_rejects_forged_account() {
+        use borsh::BorshSerialize;
+
+        let program_id = Pubkey::new_unique();
+        let (config_key, bump) = derive_listing_config_pda(&program_id);
+        let mut config = ListingConfig::try_from_slice(&[0u8; LISTING_CONFIG_SIZE]).unwrap();
+        config.discriminator = ListingConfig::DISCRIMINATOR;
+        config.bump = bump;
+        let data = config.try_to_vec().unwrap();
+
+        let load = |key: &Pubkey, owner: &Pubkey| {
+            let mut data = data.clone();
+            let mut lamports = 0u64;
+            let account = AccountInfo::new(key, false, true, &mut lamports, &mut data, owner, false, 0);
+            load_listing_config(&program_id, &account).map(|config| config.bump)
+        };
+        assert_eq!(load(&config_key, &program_id), Ok(bump));
+
+        // 伪造的配置账户：非 PDA 地址 / 非本程序 owner
+        assert!(load(&Pubkey::new_unique(), &program_id).is_err());
+        assert!(load(&config_key, &Pubkey::new_unique()).is_err());
+    }
+
+    #[test]
+    fn test_migrate_listing_config() {
+        use borsh::BorshSerialize;
+
+        let program_id = Pubkey::new_unique();
+        let (config_key, bump) = derive_listing_config_pda(&program_id);
+        let admin = Pubkey::new_unique();
+        let treasury = Pubkey::new_unique();
+
+        // 初版布局：bump 之后为 64 字节预留空间
+        let mut legacy = Vec::new();
+        (LISTING_CONFIG_DISCRIMINATOR, 1u8, admin, treasury).serialize(&mut legacy).unwrap();
+        (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()).serialize(&mut legacy).unwrap();
+        (1_000u64, 2_000u64, 5_000u64).serialize(&mut legacy).unwrap();
+        (604_800u32, 604_800u32, 1_209_600u32, 2_592_000u32).serialize(&mut legacy).unwrap();
+        (3u16, 1u16, 2u16, 42u64, false, bump, [0u8; 64]).serialize(&mut legacy).unwrap();
+        assert_eq!(legacy.len(), LEGACY_LISTING_CONFIG_SIZE);
+
+        // 旧版账户可加载，且可迁移
+        let mut lamports = 0u64;
+        let account = AccountInfo::new(&config_key, false, true, &mut lamports, &mut legacy, &program_id, false, 0);
+        let config = load_listing_config(&program_id, &account).unwrap();
+        assert!(verify_legacy_listing_config(&account).is_ok());
+        assert_eq!((config.admin, config.treasury, config.bump), (admin, treasury, bump));
+        assert_eq!(config.pause_flags, 0);
+        assert_eq!(config.param_change_delay_seconds, ListingConfig::DEFAULT_PARAM_CHANGE_DELAY);
+
+        // 扩容后按当前布局写回，再次加载字段不变，且不能重复迁移
+        let mut migrated = vec![0u8; LISTING_CONFIG_SIZE];
+        config.serialize(&mut &mut migrated[..]).unwrap();
+        let mut lamports = 0u64;
+        let account = AccountInfo::new(&config_key, false, true, &mut lamports, &mut migrated, &program_id, false, 0);
+        let reloaded = load_listing_config(&program_id, &account).unwrap();
+        assert!(verify_legacy_listing_config(&account).is_err());
+        assert_eq!((reloaded.admin, reloaded.treasury, reloaded.bump), (admin, treasury, bump));
+        assert_eq!((reloaded.total_tokens, reloaded.total_staked_lamports), (3, 42));
+        assert_eq!(reloaded.pending_admin, Pubkey::default());
+        assert_eq!(reloaded.appeal_window_seconds, ListingConfig::DEFAULT_APPEAL_WINDOW);
+        assert_eq!(reloaded.objector_reward_bps, ListingConfig::DEFAULT_OBJECTOR_REWARD_BPS);
+
+        // 迁移后的账户可以写入新增字段
+        let mut updated = reloaded.clone();
+        updated.pending_admin = Pubkey::new_unique();
+        updated.serialize(&mut &mut account.data.borrow_mut()[..]).unwrap();
+        assert_eq!(load_listing_config(&program_id, &account).unwrap().pending_admin, updated.pending_admin);
+    }
+
+    #[test]
+    fn test_load_token_registry_rejects_forged_account() {
+        use borsh::BorshSerialize;
+
+        let program_id = Pubkey::new_unique();
+        let (registry_key, bump) = derive_token_registry_pda(3, &program_id);
+        let mut registry = TokenRegistry::deserialize(&mut &[0u8; TOKEN_REGISTRY_SIZE][..]).unwrap();
+        registry.discriminator = TokenRegistry::DISCRIMINATOR;
+        registry.token_index = 3;
+        registry.bump = bump;
+        registry.asset_kind = AssetKind::SplMint;
+
+        let load = |registry: &TokenRegistry, key: &Pubkey, owner: &Pubkey, token_index: u16| {
+            let mut data = registry.try_to_vec().unwrap();
+            let mut lamports = 0u64;
+            let account = AccountInfo::new(key, false, false, &mut lamports, &mut data, owner, false, 0);
+            load_token_registry(&program_id, &account, token_index).map(|registry| registry.asset_kind)
+        };
+        assert_eq!(load(&registry, &registry_key, &program_id, 3), Ok(AssetKind::SplMint));
+
+        // 伪造的 Registry：非 PDA 地址 / 非本程序 owner / 其他 index 的 PDA / discriminator 不符
+        assert!(load(&registry, &Pubkey::new_unique(), &program_id, 3).is_err());
+        assert!(load(&registry, &registry_key, &Pubkey::new_unique(), 3).is_err());
+        assert!(load(&registry, &registry_key, &program_id, 4).is_err());
+        registry.discriminator = 0;
+        assert!(load(&registry, &registry_key, &program_id, 3).is_err());
+    }
+}